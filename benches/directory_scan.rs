@@ -0,0 +1,76 @@
+//! Measures the cost of getting a family name out of a font, the hot path
+//! when scanning every font installed on a system.
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use std::{hint::black_box, io::Cursor};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use vero_type::{
+    buffer::VeroBufReader,
+    font::Font,
+    tables::{Tables, name::Name},
+};
+
+/// A name table holding a single English Windows family name
+fn name_table() -> Vec<u8> {
+    let family = "Vero Sans"
+        .encode_utf16()
+        .flat_map(u16::to_be_bytes)
+        .collect::<Vec<u8>>();
+
+    let mut name = Vec::new();
+    name.extend_from_slice(&[0, 0, 0, 1, 0, 18]);
+    name.extend_from_slice(&[0, 3, 0, 1, 0x04, 0x09, 0, 1]);
+    name.extend_from_slice(&(family.len() as u16).to_be_bytes());
+    name.extend_from_slice(&[0, 0]);
+    name.extend_from_slice(&family);
+    name
+}
+
+/// The tables `Font` needs, with a name table holding a family name
+fn font() -> Vec<u8> {
+    let cmap = common::cmap_table(&[(0x41, 0x43, 1)]);
+    let head = common::head_table();
+    let hhea = common::hhea_table(2);
+    let hmtx = common::hmtx_table(&[(500, 10), (600, 20)], &[30, 40]);
+    let maxp = common::maxp_table(4);
+    let name = name_table();
+
+    common::build_font(&[
+        (b"cmap", &cmap),
+        (b"head", &head),
+        (b"hhea", &hhea),
+        (b"hmtx", &hmtx),
+        (b"maxp", &maxp),
+        (b"name", &name),
+    ])
+}
+
+fn family_name(c: &mut Criterion) {
+    let font = font();
+    let parsed = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(&font))).unwrap();
+    assert_eq!(parsed.family_name().as_deref(), Some("Vero Sans"));
+
+    c.bench_function("directory then name", |b| {
+        b.iter(|| {
+            let mut reader = VeroBufReader::from_buffer(Cursor::new(black_box(&font)));
+            let tables = Tables::directory_from_reader(&mut reader).unwrap();
+            let name = tables.load::<Name, _>(&mut reader).unwrap();
+
+            name.find_preferred(1)
+        })
+    });
+
+    c.bench_function("full font", |b| {
+        b.iter(|| {
+            let mut reader = VeroBufReader::from_buffer(Cursor::new(black_box(&font)));
+
+            Font::from_reader(&mut reader).unwrap().family_name()
+        })
+    });
+}
+
+criterion_group!(benches, family_name);
+criterion_main!(benches);
@@ -1,16 +1,45 @@
-use std::io::{self, BufReader, Read, Seek};
+#[cfg(feature = "bytes")]
+use std::io::Cursor;
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
 
+#[cfg(feature = "bytes")]
+use bytes::Bytes;
 use thiserror::Error;
 
+use crate::tables::decode_utf16be;
+
+/// Every multi-byte value in an sfnt file is stored in big-endian
+/// (network) byte order, the numeric readers decode exclusively through
+/// this trait so the byte order is decided in a single place and a
+/// little-endian read can't sneak in.
+pub(crate) trait BigEndian: Sized {
+    /// The raw bytes of the value, as they appear in the file
+    type Bytes: Default + AsMut<[u8]>;
+
+    /// Decodes the value from it's big-endian bytes
+    fn from_be(bytes: Self::Bytes) -> Self;
+}
+
+macro_rules! impl_big_endian {
+    ($($typ:ty),*) => {
+        $(
+            impl BigEndian for $typ {
+                type Bytes = [u8; size_of::<$typ>()];
+
+                fn from_be(bytes: Self::Bytes) -> Self {
+                    <$typ>::from_be_bytes(bytes)
+                }
+            }
+        )*
+    };
+}
+
+impl_big_endian!(u8, i8, u16, i16, u32, i32, u64, i64);
+
 macro_rules! impl_read {
     ($fn_name:ident, $typ:ty) => {
         pub fn $fn_name(&mut self) -> Result<$typ, VeroBufReaderError> {
-            let size = size_of::<$typ>();
-            let mut buf = vec![0; size];
-
-            self.inner.read_exact(&mut buf)?;
-
-            Ok(<$typ>::from_be_bytes(buf.try_into().unwrap()))
+            self.read_be::<$typ>()
         }
     };
 }
@@ -27,6 +56,11 @@ pub enum VeroBufReaderError {
     /// This variant contains the `std::io::Error` that caused the seek failure.
     #[error("Failed to seek, error context: {0}")]
     FailedToSeek(io::Error),
+
+    /// A WOFF2 UIntBase128 value is malformed, it has a leading zero,
+    /// doesn't fit in 32 bits or is longer than 5 bytes.
+    #[error("Malformed UIntBase128 value, {0}")]
+    MalformedUintBase128(&'static str),
 }
 
 /// A Struct which encapsulates and provides a robust API
@@ -34,6 +68,10 @@ pub enum VeroBufReaderError {
 #[derive(Debug)]
 pub struct VeroBufReader<B: Read + Seek> {
     inner: BufReader<B>,
+
+    /// The total length of the underlying stream, computed on first need
+    /// since the length of files and in-memory buffers doesn't change while parsing
+    len: Option<u64>,
 }
 
 impl<B> VeroBufReader<B>
@@ -47,9 +85,73 @@ where
     pub fn from_buffer(buffer: B) -> Self {
         Self {
             inner: BufReader::new(buffer),
+            len: None,
+        }
+    }
+
+    /// Returns a new buf reader like `from_buffer` whose internal buffer holds
+    /// `capacity` bytes instead of the default 8 KiB, a bigger buffer takes fewer
+    /// reads of the underlying reader when streaming through large tables
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use vero_type::buffer::VeroBufReader;
+    ///
+    /// let mut reader = VeroBufReader::with_capacity(Cursor::new(vec![0, 0, 0, 10]), 64 * 1024);
+    ///
+    /// assert_eq!(reader.capacity(), 64 * 1024);
+    /// assert_eq!(reader.read_u32().unwrap(), 10);
+    /// ```
+    pub fn with_capacity(buffer: B, capacity: usize) -> Self {
+        Self {
+            inner: BufReader::with_capacity(capacity, buffer),
+            len: None,
         }
     }
 
+    /// Returns the number of bytes the internal buffer can hold
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Returns a reference to the underlying reader
+    pub fn get_ref(&self) -> &B {
+        self.inner.get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying reader
+    ///
+    /// Reading from or seeking the underlying reader directly desyncs it
+    /// from the internal buffer, so seek with `seek_to` before reading again.
+    /// The cached length (see `VeroBufReader::len`) isn't invalidated either,
+    /// so don't change the length of the stream through this reference.
+    pub fn get_mut(&mut self) -> &mut B {
+        self.inner.get_mut()
+    }
+
+    /// Unwraps the reader, returning the underlying reader
+    ///
+    /// Any data left in the internal buffer is lost, the underlying reader's
+    /// position is wherever the last buffered read left it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use vero_type::buffer::VeroBufReader;
+    ///
+    /// let mut reader = VeroBufReader::from_buffer(Cursor::new(vec![0, 0, 0, 10]));
+    /// assert_eq!(reader.read_u32().unwrap(), 10);
+    ///
+    /// let cursor = reader.into_inner();
+    /// assert_eq!(cursor.into_inner(), vec![0, 0, 0, 10]);
+    /// ```
+    pub fn into_inner(self) -> B {
+        self.inner.into_inner()
+    }
+
     /// Seeks to a specifc place in the buffer
     /// from the start of the file
     ///
@@ -57,7 +159,7 @@ where
     ///
     /// ```
     /// use std::io::Cursor;
-    /// use vero_buf_reader::VeroBufReader;
+    /// use vero_type::buffer::VeroBufReader;
     ///
     /// let data = vec![0, 0, 0, 10, 0, 0, 0, 20]; // Represents two u32 values: 10 and 20 in big-endian
     /// let cursor = Cursor::new(data);
@@ -69,11 +171,15 @@ where
     /// assert_eq!(second_value, 20);
     /// ```
     pub fn seek_to(&mut self, pos: u64) -> Result<(), VeroBufReaderError> {
-        self.inner
-            .seek(std::io::SeekFrom::Start(pos))
-            .map_err(|ctx| VeroBufReaderError::FailedToSeek(ctx))?;
+        // Seeking relatively keeps the internal buffer when the target is already
+        // buffered, so tables close to each other are served by a single read
+        let offset = pos as i128 - i128::from(self.position()?);
 
-        Ok(())
+        match i64::try_from(offset) {
+            Ok(offset) => self.inner.seek_relative(offset),
+            Err(_) => self.inner.seek(SeekFrom::Start(pos)).map(|_| ()),
+        }
+        .map_err(VeroBufReaderError::FailedToSeek)
     }
 
     /// Skips n bytes from the CURRENT cursor positon
@@ -82,7 +188,7 @@ where
     ///
     /// ```
     /// use std::io::Cursor;
-    /// use vero_buf_reader::VeroBufReader;
+    /// use vero_type::buffer::VeroBufReader;
     ///
     /// let data = vec![0, 0, 0, 10, 0, 0, 0, 20]; // Represents two u32 values: 10 and 20 in big-endian
     /// let cursor = Cursor::new(data);
@@ -100,8 +206,106 @@ where
     /// ```
     pub fn skip(&mut self, n: i64) -> Result<(), VeroBufReaderError> {
         self.inner
-            .seek(std::io::SeekFrom::Current(n))
-            .map_err(|ctx| VeroBufReaderError::FailedToSeek(ctx))?;
+            .seek(SeekFrom::Current(n))
+            .map_err(VeroBufReaderError::FailedToSeek)?;
+
+        Ok(())
+    }
+
+    /// Returns the current cursor position from the start of the file
+    pub fn position(&mut self) -> Result<u64, VeroBufReaderError> {
+        self.inner
+            .stream_position()
+            .map_err(VeroBufReaderError::FailedToSeek)
+    }
+
+    /// Returns the total length of the underlying stream in bytes.
+    ///
+    /// The length is computed once, by seeking to the end and back, and cached
+    /// so repeated bounds checks are plain arithmetic. This assumes the stream
+    /// doesn't grow or shrink while it's being parsed, which holds for files
+    /// and in-memory buffers.
+    pub fn len(&mut self) -> Result<u64, VeroBufReaderError> {
+        if let Some(len) = self.len {
+            return Ok(len);
+        }
+
+        let position = self.position()?;
+        let len = self
+            .inner
+            .seek(SeekFrom::End(0))
+            .map_err(VeroBufReaderError::FailedToSeek)?;
+        self.seek_to(position)?;
+
+        self.len = Some(len);
+        Ok(len)
+    }
+
+    /// Checks if the underlying stream is empty
+    pub fn is_empty(&mut self) -> Result<bool, VeroBufReaderError> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Returns the amount of bytes between the cursor and the end of the stream
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use vero_type::buffer::VeroBufReader;
+    ///
+    /// let mut reader = VeroBufReader::from_buffer(Cursor::new(vec![0u8; 10]));
+    /// reader.skip(4).unwrap();
+    ///
+    /// assert_eq!(reader.len().unwrap(), 10);
+    /// assert_eq!(reader.bytes_remaining().unwrap(), 6);
+    /// ```
+    pub fn bytes_remaining(&mut self) -> Result<u64, VeroBufReaderError> {
+        let len = self.len()?;
+
+        Ok(len.saturating_sub(self.position()?))
+    }
+
+    /// Advances the cursor to the next multiple of `boundary` bytes
+    /// from the start of the file, sfnt tables for example are padded
+    /// to 4 byte boundaries.
+    ///
+    /// If the cursor is already aligned nothing happens, a `boundary` of
+    /// 0 or 1 is treated as "always aligned".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use vero_type::buffer::VeroBufReader;
+    ///
+    /// let data = vec![0xAA, 0, 0, 0, 0, 0, 0, 20];
+    /// let cursor = Cursor::new(data);
+    /// let mut reader = VeroBufReader::from_buffer(cursor);
+    ///
+    /// // Read a single byte, leaving the cursor unaligned
+    /// assert_eq!(reader.read_u8().unwrap(), 0xAA);
+    ///
+    /// // Pad to the next word boundary (byte 4)
+    /// reader.align_to(4).unwrap();
+    /// assert_eq!(reader.position().unwrap(), 4);
+    ///
+    /// // Already aligned, so this is a no-op
+    /// reader.align_to(4).unwrap();
+    /// assert_eq!(reader.read_u32().unwrap(), 20);
+    /// ```
+    pub fn align_to(&mut self, boundary: u64) -> Result<(), VeroBufReaderError> {
+        if boundary <= 1 {
+            return Ok(());
+        }
+
+        let remainder = self.position()? % boundary;
+
+        if remainder != 0 {
+            self.inner
+                .seek(SeekFrom::Current((boundary - remainder) as i64))
+                .map_err(VeroBufReaderError::FailedToSeek)?;
+        }
 
         Ok(())
     }
@@ -122,7 +326,7 @@ where
     ///
     /// ```
     /// use std::io::Cursor;
-    /// use vero_buf_reader::VeroBufReader;
+    /// use vero_type::buffer::VeroBufReader;
     ///
     /// let data = vec![10, 20, 30, 40, 50];
     /// let cursor = Cursor::new(data);
@@ -151,6 +355,148 @@ where
         Ok(())
     }
 
+    /// Reads `byte_len` bytes and decodes them as a UTF-16BE string, the encoding of
+    /// the Unicode and Microsoft name records. Unpaired surrogates are replaced
+    /// with U+FFFD and a trailing odd byte is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use vero_type::buffer::VeroBufReader;
+    ///
+    /// // "Hi" followed by an unpaired high surrogate
+    /// let data = [0x00, 0x48, 0x00, 0x69, 0xD8, 0x00];
+    /// let mut reader = VeroBufReader::from_buffer(Cursor::new(data));
+    ///
+    /// assert_eq!(reader.read_string_utf16be(6).unwrap(), "Hi\u{FFFD}");
+    /// // nothing is left to read
+    /// assert!(reader.read_string_utf16be(2).is_err());
+    /// ```
+    pub fn read_string_utf16be(&mut self, byte_len: usize) -> Result<String, VeroBufReaderError> {
+        let mut bytes = vec![0; byte_len];
+        self.inner.read_exact(&mut bytes)?;
+
+        Ok(decode_utf16be(&bytes))
+    }
+
+    /// Reads a big-endian `u16` length followed by that many bytes,
+    /// the shape of the length prefixed blobs of several OpenType structures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use vero_type::buffer::VeroBufReader;
+    ///
+    /// let data = [0x00, 0x03, b'a', b'b', b'c', 0x00, 0x02, b'd'];
+    /// let mut reader = VeroBufReader::from_buffer(Cursor::new(data));
+    ///
+    /// assert_eq!(reader.read_vec_u16_len().unwrap(), b"abc");
+    /// // the second blob declares 2 bytes but only 1 is left
+    /// assert!(reader.read_vec_u16_len().is_err());
+    /// ```
+    pub fn read_vec_u16_len(&mut self) -> Result<Vec<u8>, VeroBufReaderError> {
+        let mut bytes = vec![0; usize::from(self.read_u16()?)];
+        self.inner.read_exact(&mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Reads a big-endian value, every numeric read goes through here
+    fn read_be<T: BigEndian>(&mut self) -> Result<T, VeroBufReaderError> {
+        let mut bytes = T::Bytes::default();
+        self.inner.read_exact(bytes.as_mut())?;
+
+        Ok(T::from_be(bytes))
+    }
+
+    /// Reads a WOFF2 UIntBase128, a `u32` stored in 1 to 5 bytes of 7 bits each,
+    /// most significant group first, where the high bit of a byte marks
+    /// that another byte follows.
+    ///
+    /// # Errors
+    ///
+    /// Errors if reading fails, or with `VeroBufReaderError::MalformedUintBase128`
+    /// if the value starts with a zero group, overflows 32 bits or isn't
+    /// terminated within 5 bytes, as the WOFF2 spec requires.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use vero_type::buffer::VeroBufReader;
+    ///
+    /// let mut reader = VeroBufReader::from_buffer(Cursor::new([0x3F, 0x81, 0x00, 0x80, 0x01]));
+    ///
+    /// assert_eq!(reader.read_uint_base128().unwrap(), 63);
+    /// assert_eq!(reader.read_uint_base128().unwrap(), 128);
+    /// // a leading zero group is rejected
+    /// assert!(reader.read_uint_base128().is_err());
+    /// ```
+    pub fn read_uint_base128(&mut self) -> Result<u32, VeroBufReaderError> {
+        let mut value = 0u32;
+
+        for index in 0..5 {
+            let byte = self.read_u8()?;
+
+            if index == 0 && byte == 0x80 {
+                return Err(VeroBufReaderError::MalformedUintBase128(
+                    "it has a leading zero",
+                ));
+            }
+
+            // The 7 bits about to be shifted in would push set bits out of the value
+            if value & 0xFE00_0000 != 0 {
+                return Err(VeroBufReaderError::MalformedUintBase128(
+                    "it overflows 32 bits",
+                ));
+            }
+
+            value = (value << 7) | u32::from(byte & 0x7F);
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(VeroBufReaderError::MalformedUintBase128(
+            "it's longer than 5 bytes",
+        ))
+    }
+
+    /// Reads a WOFF2 255UInt16, a `u16` stored in 1 to 3 bytes. Values below 253
+    /// are a single byte, 253 is followed by a full `u16`, 254 and 255 are
+    /// followed by a byte which is offset by 506 and 253 respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use vero_type::buffer::VeroBufReader;
+    ///
+    /// // 506 in each of it's possible encodings
+    /// let mut reader = VeroBufReader::from_buffer(Cursor::new([255, 253, 254, 0, 253, 1, 250]));
+    ///
+    /// assert_eq!(reader.read_255_uint16().unwrap(), 506);
+    /// assert_eq!(reader.read_255_uint16().unwrap(), 506);
+    /// assert_eq!(reader.read_255_uint16().unwrap(), 506);
+    /// ```
+    pub fn read_255_uint16(&mut self) -> Result<u16, VeroBufReaderError> {
+        // The codes which tell how the value continues
+        const WORD_CODE: u8 = 253;
+        const ONE_MORE_BYTE_CODE_2: u8 = 254;
+        const ONE_MORE_BYTE_CODE_1: u8 = 255;
+        const LOWEST_UCODE: u16 = 253;
+
+        Ok(match self.read_u8()? {
+            WORD_CODE => self.read_u16()?,
+            ONE_MORE_BYTE_CODE_2 => u16::from(self.read_u8()?) + LOWEST_UCODE * 2,
+            ONE_MORE_BYTE_CODE_1 => u16::from(self.read_u8()?) + LOWEST_UCODE,
+            code => u16::from(code),
+        })
+    }
+
     impl_read!(read_i32, i32);
     impl_read!(read_u32, u32);
     impl_read!(read_i16, i16);
@@ -158,3 +504,130 @@ where
     impl_read!(read_i8, i8);
     impl_read!(read_u8, u8);
 }
+
+#[cfg(feature = "bytes")]
+impl VeroBufReader<Cursor<Bytes>> {
+    /// Returns a new buf reader over a reference counted `Bytes` buffer,
+    /// cloning the `Bytes` for every reader shares the font data instead of copying it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use vero_type::buffer::VeroBufReader;
+    ///
+    /// let font = Bytes::from_static(&[0, 0, 0, 10, 0, 0, 0, 20]);
+    ///
+    /// let mut first = VeroBufReader::from_bytes(font.clone());
+    /// let mut second = VeroBufReader::from_bytes(font);
+    ///
+    /// assert_eq!(first.read_u32().unwrap(), 10);
+    /// second.seek_to(4).unwrap();
+    /// assert_eq!(second.read_u32().unwrap(), 20);
+    /// ```
+    pub fn from_bytes(bytes: Bytes) -> Self {
+        Self::from_buffer(Cursor::new(bytes))
+    }
+}
+
+/// A bounded view over a single table of a font, see `Tables::table_reader`.
+///
+/// Positions are relative to the start of the table and reads stop at the
+/// end of it, so nothing spills into the adjacent tables.
+#[derive(Debug)]
+pub struct TableReader<B: Read + Seek> {
+    inner: B,
+
+    /// The offset of the table from the start of the underlying reader
+    start: u64,
+
+    /// The length of the table in bytes
+    len: u64,
+
+    /// The cursor position, relative to `start`
+    pos: u64,
+}
+
+impl<B> TableReader<B>
+where
+    B: Read + Seek,
+{
+    /// Returns a view over `len` bytes of `inner` starting at `start`
+    pub fn new(inner: B, start: u64, len: u64) -> Self {
+        Self {
+            inner,
+            start,
+            len,
+            pos: 0,
+        }
+    }
+
+    /// Returns the length of the table in bytes
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Checks if the table is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Unwraps the view, returning the underlying reader
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B> Read for TableReader<B>
+where
+    B: Read + Seek,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.len.saturating_sub(self.pos);
+
+        if remaining == 0 || buf.is_empty() {
+            return Ok(0);
+        }
+
+        let max = buf
+            .len()
+            .min(usize::try_from(remaining).unwrap_or(usize::MAX));
+
+        // The underlying reader might be shared, so always seek before reading
+        self.inner.seek(SeekFrom::Start(self.start + self.pos))?;
+        let read = self.inner.read(&mut buf[..max])?;
+        self.pos += read as u64;
+
+        Ok(read)
+    }
+}
+
+impl<B> Seek for TableReader<B>
+where
+    B: Read + Seek,
+{
+    /// Seeks within the table, `SeekFrom::End` is relative to the end of the table.
+    ///
+    /// Like `Cursor`, seeking past the end is allowed and reads there return 0 bytes.
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(pos) => {
+                self.pos = pos;
+                return Ok(pos);
+            }
+            SeekFrom::End(offset) => (self.len, offset),
+            SeekFrom::Current(offset) => (self.pos, offset),
+        };
+
+        match base.checked_add_signed(offset) {
+            Some(pos) => {
+                self.pos = pos;
+                Ok(pos)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
@@ -0,0 +1,985 @@
+use core::fmt::{self, Display};
+#[cfg(feature = "std")]
+use std::{
+    fs::File,
+    io::{Read, Seek},
+    path::Path,
+};
+
+use alloc::{
+    collections::BTreeSet,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[cfg(feature = "std")]
+use crate::buffer::{VeroBufReader, VeroBufReaderError};
+use crate::{
+    VeroTypeError,
+    shared::SharedBytes,
+    tables::{
+        RequiredTables, Table, TableEncodingError, TableSource, Tables,
+        avar::Avar,
+        cbdt::Cbdt,
+        cblc::Cblc,
+        cmap::Cmap,
+        dsig::Dsig,
+        fvar::{Axis, Fvar},
+        glyf::{Glyf, Glyph},
+        gvar::Gvar,
+        hdmx::Hdmx,
+        head::Head,
+        hhea::Hhea,
+        hmtx::Hmtx,
+        kern::Kern,
+        loca::Loca,
+        maxp::Maxp,
+        meta::Meta,
+        name::Name,
+        os2::Os2,
+        post::Post,
+        svg::Svg,
+    },
+    tag::Tag,
+};
+
+/// The component depth limit for fonts whose maxp table doesn't declare one,
+/// and the cap on a declared one so a crafted font can't recurse deep enough to overflow the stack
+const MAX_COMPONENT_DEPTH: u16 = 16;
+
+/// The name identifier of the font family name
+const FAMILY_NAME_ID: u16 = 1;
+
+/// The name identifier of the full font name
+const FULL_NAME_ID: u16 = 4;
+
+/// The name identifier of the PostScript name
+const POSTSCRIPT_NAME_ID: u16 = 6;
+
+/// A parsed font file, this is the high level entry point of the crate
+/// which ties the individual tables together.
+#[derive(Debug)]
+pub struct Font {
+    /// The table directory and the eagerly parsed tables
+    tables: Tables,
+
+    /// The name table
+    name: Name,
+
+    /// The maxp table
+    maxp: Maxp,
+
+    /// The cmap table
+    cmap: Cmap,
+
+    /// The hhea table
+    hhea: Hhea,
+
+    /// The hmtx table
+    hmtx: Hmtx,
+
+    /// The loca table, fonts with CFF outlines don't have one
+    loca: Option<Loca>,
+
+    /// The glyf table, fonts with CFF outlines don't have one
+    glyf: Option<Glyf>,
+
+    /// The OS/2 table, if the font has one
+    os2: Option<Os2>,
+
+    /// The post table, if the font has one
+    post: Option<Post>,
+
+    /// The kern table, if the font has one
+    kern: Option<Kern>,
+
+    /// The hdmx table, if the font has hinted advance widths
+    hdmx: Option<Hdmx>,
+
+    /// The meta table, if the font has one
+    meta: Option<Meta>,
+
+    /// The DSIG table, if the font has one
+    dsig: Option<Dsig>,
+
+    /// The fvar table, if the font is variable
+    fvar: Option<Fvar>,
+
+    /// The gvar table, only loaded when the font has an fvar table
+    gvar: Option<Gvar>,
+
+    /// The avar table, only loaded when the font has an fvar table
+    avar: Option<Avar>,
+
+    /// The SVG table, if the font has color glyphs drawn as SVG documents
+    svg: Option<Svg>,
+
+    /// The CBLC table, if the font has color bitmaps
+    cblc: Option<Cblc>,
+
+    /// The CBDT table, if the font has color bitmaps
+    cbdt: Option<Cbdt>,
+}
+
+impl Font {
+    /// Parses a font from a reader, reading the table directory
+    /// followed by the tables the high level API relies on.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `VeroTypeError` if reading fails, if a table is malformed or
+    /// if one of the required tables is missing from the directory. A loca table
+    /// whose last offset isn't the length of the glyf table is a
+    /// `TableEncodingError::LocaGlyfLengthMismatch`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use vero_type::{buffer::VeroBufReader, font::Font};
+    ///
+    /// let file = File::open("font.ttf").unwrap();
+    /// let mut reader = VeroBufReader::from_buffer(file);
+    /// let font = Font::from_reader(&mut reader).unwrap();
+    ///
+    /// println!("{}", font.summary());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_reader<B: Read + Seek>(
+        reader: &mut VeroBufReader<B>,
+    ) -> Result<Self, VeroTypeError> {
+        let tables = Tables::from_reader(reader)?;
+
+        Self::from_tables(tables, reader)
+    }
+
+    /// Parses a font out of a reference counted buffer holding the whole font file.
+    ///
+    /// Unlike `Font::from_reader` the tables which keep a part of their bytes around
+    /// (glyph outlines, color bitmaps, the name string storage) hold a view into
+    /// the shared buffer rather than a copy, so a long lived font doesn't cost
+    /// much more than the buffer itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `VeroTypeError` if a table is malformed, lies outside of the buffer
+    /// or if one of the required tables is missing from the directory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::sync::Arc;
+    /// use vero_type::font::Font;
+    ///
+    /// let data: Arc<[u8]> = std::fs::read("font.ttf").unwrap().into();
+    /// let font = Font::from_shared(Arc::clone(&data)).unwrap();
+    ///
+    /// println!("{}", font.summary());
+    /// ```
+    pub fn from_shared(data: impl Into<SharedBytes>) -> Result<Self, VeroTypeError> {
+        let mut data = data.into();
+        let tables = Tables::from_shared(&data)?;
+
+        Self::from_tables(tables, &mut data)
+    }
+
+    /// Opens and parses the font file at `path`.
+    ///
+    /// With the `memmap2` feature the file is memory mapped and the font holds views
+    /// into the mapping, if the file can't be mapped (a filesystem which doesn't
+    /// support it, ...) it's read into memory instead. Either way the font behaves
+    /// the same, without the feature the file is always read into memory.
+    ///
+    /// The file is closed as soon as it's mapped and the mapping is released when
+    /// the font is dropped, the tables holding views into it are owned by the font
+    /// and the slices borrowed from it (the bytes of `Font::color_bitmap`, ...) can't
+    /// outlive it. The file shouldn't be modified while the font is alive, a mapped
+    /// font would see the change.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `VeroBufReaderError` if the file can't be opened or read, otherwise
+    /// the errors of `Font::from_shared`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vero_type::font::Font;
+    ///
+    /// let font = Font::open("font.ttf").unwrap();
+    ///
+    /// println!("{}", font.summary());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, VeroTypeError> {
+        let mut file = File::open(path).map_err(VeroBufReaderError::from)?;
+
+        #[cfg(feature = "memmap2")]
+        {
+            // SAFETY: the mapping is read only, modifying the file while it's mapped
+            // is on the caller as documented above
+            if let Ok(map) = unsafe { memmap2::Mmap::map(&file) } {
+                return Self::from_shared(map);
+            }
+        }
+
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)
+            .map_err(VeroBufReaderError::from)?;
+
+        Self::from_shared(data)
+    }
+
+    /// Parses a font out of the bytes of a font file, the one call for
+    /// "I have the font bytes, give me a `Font`".
+    ///
+    /// The bytes are copied into a shared buffer, use `Font::from_shared` to hand
+    /// over a `Vec<u8>` or an `Arc<[u8]>` without copying.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `VeroTypeError` if a table is malformed, lies outside of `data`
+    /// or if one of the required tables is missing from the directory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vero_type::font::Font;
+    ///
+    /// let data = std::fs::read("font.ttf").unwrap();
+    /// let font = Font::from_bytes(&data).unwrap();
+    ///
+    /// println!("{}", font.summary());
+    /// ```
+    ///
+    /// ```
+    /// use vero_type::font::Font;
+    ///
+    /// // too short to even hold the offset table
+    /// assert!(Font::from_bytes(&[0, 1, 0, 0]).is_err());
+    /// ```
+    pub fn from_bytes(data: &[u8]) -> Result<Self, VeroTypeError> {
+        Self::from_shared(data)
+    }
+
+    /// Loads the tables the high level API relies on out of `source`,
+    /// `tables` must hold the head table
+    fn from_tables<S: TableSource>(tables: Tables, source: &mut S) -> Result<Self, VeroTypeError> {
+        // Strict parsing never leaves the head table out
+        let head = tables
+            .head()
+            .ok_or(TableEncodingError::MissingRequiredTable(
+                RequiredTables::Head,
+            ))?;
+
+        let name = load::<Name, S>(&tables, source)?;
+        let maxp = load::<Maxp, S>(&tables, source)?;
+        let cmap = load::<Cmap, S>(&tables, source)?;
+        let hhea = load::<Hhea, S>(&tables, source)?;
+        let hmtx = source.with_bytes(
+            Tag::new(*b"hmtx"),
+            tables.headers().require(RequiredTables::Hmtx)?,
+            |buf| Hmtx::from_buffer(buf, hhea.num_of_long_hor_metrics(), maxp.num_glyphs()),
+        )?;
+
+        let loca = match tables.headers().get(RequiredTables::Loca) {
+            Some(metadata) => Some(source.with_bytes(Tag::new(*b"loca"), metadata, |buf| {
+                Loca::from_buffer(buf, head.loca_format(), maxp.num_glyphs())
+            })?),
+            None => None,
+        };
+
+        let glyf = load_optional::<Glyf, S>(&tables, source)?;
+
+        // The glyphs end where the glyf table does, a renderer trusting a longer
+        // loca would read past the end of the glyph data
+        if let (Some(loca), Some(glyf)) = (&loca, tables.headers().get_by_tag(Glyf::TAG)) {
+            let last = loca.offsets().last().copied().unwrap_or(0);
+
+            if last != glyf.length() {
+                return Err(TableEncodingError::LocaGlyfLengthMismatch {
+                    loca: last,
+                    glyf: glyf.length(),
+                }
+                .into());
+            }
+        }
+        let os2 = load_optional::<Os2, S>(&tables, source)?;
+        let post = load_optional::<Post, S>(&tables, source)?;
+        let kern = load_optional::<Kern, S>(&tables, source)?;
+        // The hinted widths only refine the hmtx advances, so a malformed hdmx
+        // table is dropped rather than failing the whole font
+        let hdmx = match tables.headers().get_by_tag(*b"hdmx") {
+            Some(metadata) => match source.with_bytes(Tag::new(*b"hdmx"), metadata, |buf| {
+                Hdmx::from_buffer(buf, maxp.num_glyphs())
+            }) {
+                Ok(hdmx) => Some(hdmx),
+                Err(VeroTypeError::TableEncodingError(_)) => None,
+                Err(error) => return Err(error),
+            },
+            None => None,
+        };
+        let meta = load_optional::<Meta, S>(&tables, source)?;
+        let dsig = load_optional::<Dsig, S>(&tables, source)?;
+        let fvar = load_optional::<Fvar, S>(&tables, source)?;
+        // glyph variations and axis remappings are meaningless without the axes they apply to
+        let (gvar, avar) = match fvar {
+            Some(_) => (
+                load_optional::<Gvar, S>(&tables, source)?,
+                load_optional::<Avar, S>(&tables, source)?,
+            ),
+            None => (None, None),
+        };
+        let svg = load_optional::<Svg, S>(&tables, source)?;
+        let cblc = load_optional::<Cblc, S>(&tables, source)?;
+        let cbdt = load_optional::<Cbdt, S>(&tables, source)?;
+
+        Ok(Self {
+            tables,
+            name,
+            maxp,
+            cmap,
+            hhea,
+            hmtx,
+            loca,
+            glyf,
+            os2,
+            post,
+            kern,
+            hdmx,
+            meta,
+            dsig,
+            fvar,
+            gvar,
+            avar,
+            svg,
+            cblc,
+            cbdt,
+        })
+    }
+
+    /// Returns the table directory and the eagerly parsed tables
+    pub fn tables(&self) -> &Tables {
+        &self.tables
+    }
+
+    /// Returns the head table
+    pub fn head(&self) -> &Head {
+        // Font::from_reader refuses to construct a font without a head table
+        self.tables.head().expect("a font always has a head table")
+    }
+
+    /// Returns the units per em, a shortcut for `Head::units_per_em`.
+    ///
+    /// The value is validated when the head table is parsed so it's
+    /// always within 16 and 16384 and safe to divide by.
+    pub fn units_per_em(&self) -> u16 {
+        self.head().units_per_em()
+    }
+
+    /// Returns the name table
+    pub fn name(&self) -> &Name {
+        &self.name
+    }
+
+    /// Returns the maxp table
+    pub fn maxp(&self) -> &Maxp {
+        &self.maxp
+    }
+
+    /// Returns the cmap table
+    pub fn cmap(&self) -> &Cmap {
+        &self.cmap
+    }
+
+    /// Maps a character to it's glyph id, `None` if the font doesn't map it.
+    ///
+    /// The best cmap subtable is used, falling back to the 0xF000 offset of
+    /// symbol fonts, see `Cmap::resolve` to tell which one mapped the character.
+    ///
+    /// Variation sequences are a known limitation: format 14 subtables aren't parsed,
+    /// so a character always maps to it's default glyph and a variation selector is
+    /// looked up like any other character.
+    pub fn glyph_for_char(&self, c: char) -> Option<u16> {
+        self.cmap.resolve(c).glyph_id()
+    }
+
+    /// Checks if the font maps a character to a glyph other than `.notdef`,
+    /// through the same cmap lookup as `Font::glyph_for_char`.
+    ///
+    /// The best subtable is picked once at parse time, which keeps this cheap
+    /// enough to call for every character of a run when falling back between fonts.
+    pub fn supports_char(&self, c: char) -> bool {
+        self.glyph_for_char(c).is_some_and(|glyph_id| glyph_id != 0)
+    }
+
+    /// Iterates over the characters the font maps to a glyph along with the glyph id and
+    /// it's advance width, `(char, glyph_id, advance_width)`, see `Cmap::mappings`.
+    ///
+    /// Characters mapped to a glyph past the horizontal metrics are skipped.
+    pub fn char_metrics(&self) -> impl Iterator<Item = (char, u16, u16)> + '_ {
+        self.cmap.mappings().filter_map(|(c, glyph_id)| {
+            let advance_width = self.hmtx.advance_width(glyph_id)?;
+
+            Some((c, glyph_id, advance_width))
+        })
+    }
+
+    /// Returns the hhea table
+    pub fn hhea(&self) -> &Hhea {
+        &self.hhea
+    }
+
+    /// Returns the hmtx table
+    pub fn hmtx(&self) -> &Hmtx {
+        &self.hmtx
+    }
+
+    /// Returns the loca table, `None` for fonts with CFF outlines
+    pub fn loca(&self) -> Option<&Loca> {
+        self.loca.as_ref()
+    }
+
+    /// Returns the glyf table, `None` for fonts with CFF outlines
+    pub fn glyf(&self) -> Option<&Glyf> {
+        self.glyf.as_ref()
+    }
+
+    /// Parses the glyph with the given id out of the glyf table.
+    ///
+    /// Returns `Ok(None)` if the glyph id is out of bounds or the font
+    /// has no TrueType outlines (no loca or glyf table).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the glyph is malformed, see `Glyf::glyph`.
+    pub fn glyph(&self, glyph_id: u16) -> Result<Option<Glyph>, TableEncodingError> {
+        match (&self.loca, &self.glyf) {
+            (Some(loca), Some(glyf)) => glyf.glyph(loca, glyph_id),
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns the ids of the glyphs a composite glyph is directly made of,
+    /// see `Glyf::component_glyphs`.
+    ///
+    /// Empty if the glyph isn't a composite or the font has no TrueType outlines.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the glyph's component records are malformed.
+    pub fn component_glyphs(&self, glyph_id: u16) -> Result<Vec<u16>, TableEncodingError> {
+        match (&self.loca, &self.glyf) {
+            (Some(loca), Some(glyf)) => glyf.component_glyphs(loca, glyph_id),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Collects the glyphs a subset starting from `seed` has to keep, the seed glyphs
+    /// along with every glyph their composites are made of, followed transitively.
+    ///
+    /// Glyph ids outside of the font are left out, and a glyph is visited once
+    /// so composites referencing each other in a cycle don't loop forever.
+    /// The `.notdef` glyph is only included if it's seeded or referenced.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the component records of a visited
+    /// composite glyph are malformed, see `Glyf::component_glyphs`, or
+    /// `TableEncodingError::ComponentDepthExceeded` if composites nest deeper
+    /// than `Maxp::max_component_depth`, capped at 16 levels (the limit when the maxp
+    /// table predates it).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vero_type::font::Font;
+    ///
+    /// let font = Font::from_shared(std::fs::read("font.ttf").unwrap()).unwrap();
+    ///
+    /// // 'é' pulls in the 'e' and the accent it's built out of
+    /// let seed = font.cmap().glyph_indices("é");
+    /// let closure = font.glyph_closure(&seed).unwrap();
+    ///
+    /// assert!(closure.len() >= 3);
+    /// ```
+    pub fn glyph_closure(&self, seed: &[u16]) -> Result<BTreeSet<u16>, TableEncodingError> {
+        let max_depth = self
+            .maxp
+            .max_component_depth()
+            .unwrap_or(MAX_COMPONENT_DEPTH)
+            .min(MAX_COMPONENT_DEPTH);
+        let mut closure = BTreeSet::new();
+
+        for &glyph_id in seed {
+            self.close_over_components(glyph_id, 0, max_depth, &mut closure)?;
+        }
+
+        Ok(closure)
+    }
+
+    /// Adds a glyph and it's components to `closure`, `depth` is the nesting level
+    /// of the glyph, which bounds the recursion for a crafted font
+    fn close_over_components(
+        &self,
+        glyph_id: u16,
+        depth: u16,
+        max_depth: u16,
+        closure: &mut BTreeSet<u16>,
+    ) -> Result<(), TableEncodingError> {
+        if glyph_id >= self.maxp.num_glyphs() || !closure.insert(glyph_id) {
+            return Ok(());
+        }
+
+        let components = self.component_glyphs(glyph_id)?;
+
+        if !components.is_empty() && depth >= max_depth {
+            return Err(TableEncodingError::ComponentDepthExceeded(max_depth));
+        }
+
+        for component in components {
+            self.close_over_components(component, depth + 1, max_depth, closure)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the OS/2 table, if the font has one
+    pub fn os2(&self) -> Option<&Os2> {
+        self.os2.as_ref()
+    }
+
+    /// Gathers the metrics a layout engine needs from the head, hhea and OS/2 tables.
+    ///
+    /// The ascent, descent and line gap are taken from the first source which applies:
+    ///
+    /// 1. The OS/2 typographic metrics, when the `USE_TYPO_METRICS` fsSelection bit is set
+    /// 2. The hhea metrics, unless their ascent and descent are both 0
+    /// 3. The OS/2 typographic metrics, unless their ascent and descent are both 0
+    /// 4. The OS/2 Windows metrics, with the descent negated and no line gap
+    ///
+    /// When nothing applies the (zeroed) hhea metrics are returned.
+    pub fn metrics(&self) -> Metrics {
+        let hhea = (
+            self.hhea.ascent(),
+            self.hhea.descent(),
+            self.hhea.line_gap(),
+        );
+
+        let (ascent, descent, line_gap) = match &self.os2 {
+            Some(os2) => {
+                let typo = (
+                    os2.typo_ascender(),
+                    os2.typo_descender(),
+                    os2.typo_line_gap(),
+                );
+                // Windows metrics are unsigned, saturate rather than wrap the broken ones
+                let win = (
+                    i16::try_from(os2.win_ascent()).unwrap_or(i16::MAX),
+                    i16::try_from(os2.win_descent()).map_or(i16::MIN, |descent| -descent),
+                    0,
+                );
+
+                if os2.fs_selection().use_typo_metrics() {
+                    typo
+                } else if hhea.0 != 0 || hhea.1 != 0 {
+                    hhea
+                } else if typo.0 != 0 || typo.1 != 0 {
+                    typo
+                } else if win.0 != 0 || win.1 != 0 {
+                    win
+                } else {
+                    hhea
+                }
+            }
+            None => hhea,
+        };
+
+        let os2 = self.os2.as_ref();
+
+        Metrics {
+            units_per_em: self.units_per_em(),
+            ascent,
+            descent,
+            line_gap,
+            x_height: os2.and_then(Os2::x_height).filter(|&height| height != 0),
+            cap_height: os2.and_then(Os2::cap_height).filter(|&height| height != 0),
+        }
+    }
+
+    /// Returns the ascent of the font in font units, see `Font::metrics` for
+    /// which table it comes from. Divide by `Font::units_per_em` to scale it to a font size.
+    pub fn ascent(&self) -> i16 {
+        self.metrics().ascent
+    }
+
+    /// Returns the descent of the font in font units (usually negative),
+    /// see `Font::metrics` for which table it comes from
+    pub fn descent(&self) -> i16 {
+        self.metrics().descent
+    }
+
+    /// Returns the distance between two baselines in font units, see `Metrics::line_height`
+    pub fn line_height(&self) -> i32 {
+        self.metrics().line_height()
+    }
+
+    /// Resolves the weight and slope of the font out of the OS/2 and head tables.
+    ///
+    /// The two tables often disagree, so each value is taken from the first source which applies:
+    ///
+    /// - The weight is the OS/2 weight class when it's within 1 and 1000, otherwise
+    ///   700 for a bold font and 400 for the rest. A font is bold when the OS/2
+    ///   fsSelection says so, or when the head mac style does and there is no OS/2 table.
+    /// - The font is italic when the OS/2 fsSelection says so, or when the head mac
+    ///   style does and there is no OS/2 table.
+    /// - The font is oblique when the OS/2 fsSelection says so, the bit only exists
+    ///   from version 4 of the table onwards.
+    ///
+    /// The OS/2 table wins because it's the one Windows and most layout engines
+    /// match styles with. A set `REGULAR` fsSelection bit clears both bold and italic.
+    pub fn style(&self) -> FontStyle {
+        let head = self.head();
+
+        let (bold, italic, oblique) = match &self.os2 {
+            Some(os2) => {
+                let fs_selection = os2.fs_selection();
+                let regular = fs_selection.regular();
+
+                (
+                    fs_selection.bold() && !regular,
+                    fs_selection.italic() && !regular,
+                    os2.version() >= 4 && fs_selection.oblique(),
+                )
+            }
+            None => (head.mac_style_bold(), head.mac_style_italic(), false),
+        };
+
+        let weight = self
+            .os2
+            .as_ref()
+            .map(Os2::weight_class)
+            .filter(|weight| (1..=1000).contains(weight))
+            .unwrap_or(if bold { 700 } else { 400 });
+
+        FontStyle {
+            weight,
+            italic,
+            oblique,
+        }
+    }
+
+    /// Returns the post table, if the font has one
+    pub fn post(&self) -> Option<&Post> {
+        self.post.as_ref()
+    }
+
+    /// Finds the glyph id whose post table name is `name`, see `Post::glyph_id`.
+    ///
+    /// Returns `None` if no glyph has that name or the font has no glyph names.
+    pub fn glyph_id_for_name(&self, name: &str) -> Option<u16> {
+        self.post.as_ref()?.glyph_id(name)
+    }
+
+    /// Returns a label for a glyph in diagnostics, it's post table name if it has a
+    /// non-empty one and `glyph` followed by it's id otherwise, such as `glyph42`.
+    pub fn glyph_label(&self, glyph_id: u16) -> String {
+        match self.post.as_ref().and_then(|post| post.glyph_name(glyph_id)) {
+            Some(name) if !name.is_empty() => name.to_string(),
+            _ => format!("glyph{glyph_id}"),
+        }
+    }
+
+    /// Returns the kern table, if the font has one
+    pub fn kern(&self) -> Option<&Kern> {
+        self.kern.as_ref()
+    }
+
+    /// Returns the horizontal kerning between two glyphs in font units, see `Kern::kerning`.
+    ///
+    /// 0 if the pair isn't kerned or the font has no kern table.
+    pub fn kerning(&self, left: u16, right: u16) -> i16 {
+        self.kern
+            .as_ref()
+            .map_or(0, |kern| kern.kerning(left, right))
+    }
+
+    /// Returns the hdmx table, if the font has hinted advance widths.
+    ///
+    /// A malformed hdmx table is left out, the advances then come from hmtx alone.
+    pub fn hdmx(&self) -> Option<&Hdmx> {
+        self.hdmx.as_ref()
+    }
+
+    /// Returns the meta table, if the font has one
+    pub fn meta(&self) -> Option<&Meta> {
+        self.meta.as_ref()
+    }
+
+    /// Returns the DSIG table, if the font has one
+    pub fn dsig(&self) -> Option<&Dsig> {
+        self.dsig.as_ref()
+    }
+
+    /// Returns whether the font carries a digital signature, see `Dsig::has_signature`.
+    ///
+    /// The signature isn't verified.
+    pub fn is_signed(&self) -> bool {
+        self.dsig.as_ref().is_some_and(Dsig::has_signature)
+    }
+
+    /// Returns the fvar table, if the font is variable
+    pub fn fvar(&self) -> Option<&Fvar> {
+        self.fvar.as_ref()
+    }
+
+    /// Returns the gvar table, if the font is variable and has one
+    pub fn gvar(&self) -> Option<&Gvar> {
+        self.gvar.as_ref()
+    }
+
+    /// Returns the avar table, if the font is variable and has one
+    pub fn avar(&self) -> Option<&Avar> {
+        self.avar.as_ref()
+    }
+
+    /// Returns whether the font is a variable font, that is whether it has an fvar table
+    pub fn is_variable(&self) -> bool {
+        self.fvar.is_some()
+    }
+
+    /// Returns the variation axes of the font, empty if the font isn't variable
+    pub fn axes(&self) -> Vec<Axis> {
+        self.fvar
+            .as_ref()
+            .map(|fvar| fvar.axes().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Checks if the font is laid out with Apple's AAT model rather than OpenType's,
+    /// that is if the directory lists a `morx`, `mort` or `kerx` table.
+    ///
+    /// A font carrying both models counts as AAT only when the head flags ask for
+    /// it's default metamorphosis effects, otherwise the `GSUB` or `GPOS` tables win.
+    /// Only the directory and the head table are consulted.
+    pub fn has_aat_layout(&self) -> bool {
+        let headers = self.tables.headers();
+        let lists = |tag: &[u8; 4]| headers.get_by_tag(tag).is_some();
+
+        if !(lists(b"morx") || lists(b"mort") || lists(b"kerx")) {
+            return false;
+        }
+
+        self.head().flags().aat_default_metamorphosis() || !(lists(b"GSUB") || lists(b"GPOS"))
+    }
+
+    /// Returns the SVG table, if the font has one
+    pub fn svg(&self) -> Option<&Svg> {
+        self.svg.as_ref()
+    }
+
+    /// Returns the CBLC table, if the font has one
+    pub fn cblc(&self) -> Option<&Cblc> {
+        self.cblc.as_ref()
+    }
+
+    /// Returns the CBDT table, if the font has one
+    pub fn cbdt(&self) -> Option<&Cbdt> {
+        self.cbdt.as_ref()
+    }
+
+    /// Returns the PNG image of a glyph for the given pixels per em, see `Cbdt::color_bitmap`.
+    ///
+    /// Returns `None` if the glyph has no color bitmap or the font has no
+    /// color bitmaps (no CBLC or CBDT table).
+    pub fn color_bitmap(&self, glyph_id: u16, ppem: u16) -> Option<&[u8]> {
+        match (&self.cblc, &self.cbdt) {
+            (Some(cblc), Some(cbdt)) => cbdt.color_bitmap(cblc, glyph_id, ppem),
+            _ => None,
+        }
+    }
+
+    /// Returns the font family name (name id 1), preferring the English
+    /// Windows record, then the English Macintosh record, then any record.
+    pub fn family_name(&self) -> Option<String> {
+        self.name.find_preferred(FAMILY_NAME_ID)
+    }
+
+    /// Returns the full font name (name id 4), with the same preference as `Font::family_name`
+    pub fn full_name(&self) -> Option<String> {
+        self.name.find_preferred(FULL_NAME_ID)
+    }
+
+    /// Returns the PostScript name (name id 6), with the same preference as `Font::family_name`
+    pub fn postscript_name(&self) -> Option<String> {
+        self.name.find_preferred(POSTSCRIPT_NAME_ID)
+    }
+
+    /// Returns the number of tables in the directory, see `OffsetTable::num_tables`
+    pub fn num_tables(&self) -> u16 {
+        self.tables.offset().num_tables()
+    }
+
+    /// Returns the size of the offset table and the directory in bytes,
+    /// see `OffsetTable::directory_byte_size`
+    pub fn directory_byte_size(&self) -> u32 {
+        self.tables.offset().directory_byte_size()
+    }
+
+    /// Lists the tag of every table in the directory in directory order,
+    /// including the tables which aren't required.
+    ///
+    /// Only the directory is consulted, no table body is read.
+    pub fn tables_present(&self) -> Vec<Tag> {
+        self.tables.headers().tags().to_vec()
+    }
+
+    /// Returns a compact, human friendly overview of the font
+    /// which is better suited for logging than the `Debug` output of `Tables`.
+    pub fn summary(&self) -> FontSummary {
+        FontSummary {
+            family_name: self.family_name(),
+            num_glyphs: self.maxp.num_glyphs(),
+            units_per_em: self.units_per_em(),
+            sfnt_version: self.tables.offset().scalar_type(),
+            num_tables: self.num_tables(),
+        }
+    }
+}
+
+/// Loads a table the font can't do without
+fn load<T: Table, S: TableSource>(tables: &Tables, source: &mut S) -> Result<T, VeroTypeError> {
+    source.parse(tables.headers().require_tag(T::TAG)?)
+}
+
+/// Loads a table if the directory lists it
+fn load_optional<T: Table, S: TableSource>(
+    tables: &Tables,
+    source: &mut S,
+) -> Result<Option<T>, VeroTypeError> {
+    match tables.headers().get_by_tag(T::TAG) {
+        Some(metadata) => source.parse(metadata).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// The vertical metrics of a font in font units, see `Font::metrics` for where they come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metrics {
+    /// Units per em, divide by it to scale the other metrics to a font size
+    pub units_per_em: u16,
+
+    /// The distance from the baseline to the top of the line
+    pub ascent: i16,
+
+    /// The distance from the baseline to the bottom of the line, usually negative
+    pub descent: i16,
+
+    /// The extra space between two lines
+    pub line_gap: i16,
+
+    /// The height of the lowercase x, if the OS/2 table records one
+    pub x_height: Option<i16>,
+
+    /// The height of the uppercase H, if the OS/2 table records one
+    pub cap_height: Option<i16>,
+}
+
+impl Metrics {
+    /// Returns the distance between two baselines, ascent - descent + line gap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::font::Metrics;
+    ///
+    /// let metrics = Metrics {
+    ///     units_per_em: 2048,
+    ///     ascent: 1901,
+    ///     descent: -483,
+    ///     line_gap: 0,
+    ///     x_height: None,
+    ///     cap_height: None,
+    /// };
+    ///
+    /// assert_eq!(metrics.line_height(), 2384);
+    /// ```
+    pub fn line_height(&self) -> i32 {
+        i32::from(self.ascent) - i32::from(self.descent) + i32::from(self.line_gap)
+    }
+}
+
+/// The weight and slope of a font, see `Font::style` for how they're resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FontStyle {
+    /// The visual weight of the glyphs, 400 is regular and 700 bold
+    pub weight: u16,
+
+    /// Whether the glyphs are italic
+    pub italic: bool,
+
+    /// Whether the glyphs are an oblique (slanted rather than cursive) style
+    pub oblique: bool,
+}
+
+/// A compact overview of a font, rendered as a single line by its `Display` impl
+///
+/// # Examples
+///
+/// ```
+/// use vero_type::font::FontSummary;
+///
+/// let summary = FontSummary {
+///     family_name: Some("Open Sans".to_string()),
+///     num_glyphs: 938,
+///     units_per_em: 2048,
+///     sfnt_version: 0x00010000,
+///     num_tables: 18,
+/// };
+///
+/// assert_eq!(
+///     summary.to_string(),
+///     "Open Sans: 938 glyphs, 2048 units/em, sfnt 0x00010000, 18 tables"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FontSummary {
+    /// The font family name (name id 1), if the name table has a decodable one
+    pub family_name: Option<String>,
+
+    /// The number of glyphs in the font
+    pub num_glyphs: u16,
+
+    /// Units per em
+    pub units_per_em: u16,
+
+    /// The sfnt version (scalar type) of the font file
+    pub sfnt_version: u32,
+
+    /// The number of tables in the table directory
+    pub num_tables: u16,
+}
+
+impl Display for FontSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tag = self.sfnt_version.to_be_bytes();
+
+        write!(
+            f,
+            "{}: {} glyphs, {} units/em, ",
+            self.family_name.as_deref().unwrap_or("<unnamed>"),
+            self.num_glyphs,
+            self.units_per_em,
+        )?;
+
+        // 'OTTO' and 'true' are readable tags while 0x00010000 is not
+        if tag.iter().all(u8::is_ascii_graphic) {
+            write!(f, "sfnt '{}'", String::from_utf8_lossy(&tag))?;
+        } else {
+            write!(f, "sfnt 0x{:08X}", self.sfnt_version)?;
+        }
+
+        write!(f, ", {} tables", self.num_tables)
+    }
+}
@@ -0,0 +1,127 @@
+use alloc::collections::BTreeMap;
+
+use crate::{
+    font::Font,
+    tables::{TableEncodingError, glyf::Glyph},
+};
+
+/// A bounded cache of decoded glyphs in front of `Font::glyph`, for rendering
+/// loops which draw the same glyphs over and over again.
+///
+/// Once the cache holds `capacity` glyphs the least recently used one is evicted
+/// to make room for the next. Only decoded glyphs are cached, missing glyphs and
+/// errors go back to the font every time.
+///
+/// Composite glyphs are cached as decoded, without their components. Once
+/// components are resolved the cache should hold the resolved outline, so the
+/// components aren't looked up again on every hit.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vero_type::{font::Font, glyph_cache::GlyphCache};
+///
+/// let font = Font::from_shared(std::fs::read("font.ttf").unwrap()).unwrap();
+/// let mut cache = GlyphCache::new(&font, 256);
+///
+/// for glyph_id in [36, 37, 36] {
+///     // the second 36 is served from the cache
+///     let glyph = cache.glyph(glyph_id).unwrap();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct GlyphCache<'a> {
+    /// The font the glyphs are decoded from
+    font: &'a Font,
+
+    /// The maximum amount of cached glyphs, at least 1
+    capacity: usize,
+
+    /// The cached glyphs with the tick they were last used on
+    glyphs: BTreeMap<u16, (u64, Glyph)>,
+
+    /// The cached glyph ids keyed by the tick they were last used on,
+    /// the first entry is the least recently used glyph
+    recency: BTreeMap<u64, u16>,
+
+    /// Increases on every lookup, orders the uses
+    tick: u64,
+}
+
+impl<'a> GlyphCache<'a> {
+    /// Returns an empty cache in front of `font` holding at most `capacity` glyphs,
+    /// a `capacity` of 0 is treated as 1 so the last glyph can always be handed out.
+    pub fn new(font: &'a Font, capacity: usize) -> Self {
+        Self {
+            font,
+            capacity: capacity.max(1),
+            glyphs: BTreeMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    /// Returns the glyph with the given id, decoding and caching it on a miss.
+    ///
+    /// Returns `Ok(None)` if the glyph id is out of bounds or the font
+    /// has no TrueType outlines, see `Font::glyph`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the glyph is malformed, see `Glyf::glyph`.
+    pub fn glyph(&mut self, glyph_id: u16) -> Result<Option<&Glyph>, TableEncodingError> {
+        self.tick += 1;
+
+        if let Some((last_used, _)) = self.glyphs.get_mut(&glyph_id) {
+            self.recency.remove(last_used);
+            *last_used = self.tick;
+            self.recency.insert(self.tick, glyph_id);
+        } else {
+            let Some(glyph) = self.font.glyph(glyph_id)? else {
+                return Ok(None);
+            };
+
+            if self.glyphs.len() >= self.capacity
+                && let Some((_, evicted)) = self.recency.pop_first()
+            {
+                self.glyphs.remove(&evicted);
+            }
+
+            self.glyphs.insert(glyph_id, (self.tick, glyph));
+            self.recency.insert(self.tick, glyph_id);
+        }
+
+        Ok(self.glyphs.get(&glyph_id).map(|(_, glyph)| glyph))
+    }
+
+    /// Checks if the glyph is cached, without counting as a use
+    pub fn contains(&self, glyph_id: u16) -> bool {
+        self.glyphs.contains_key(&glyph_id)
+    }
+
+    /// Returns the amount of cached glyphs
+    pub fn len(&self) -> usize {
+        self.glyphs.len()
+    }
+
+    /// Checks if no glyph is cached
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+
+    /// Returns the maximum amount of cached glyphs
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Drops every cached glyph
+    pub fn clear(&mut self) {
+        self.glyphs.clear();
+        self.recency.clear();
+    }
+
+    /// Returns the font the glyphs are decoded from
+    pub fn font(&self) -> &'a Font {
+        self.font
+    }
+}
@@ -1,19 +1,39 @@
-use std::array::TryFromSliceError;
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use core::array::TryFromSliceError;
+
+#[cfg(feature = "std")]
 use buffer::VeroBufReaderError;
 use tables::TableEncodingError;
 use thiserror::Error;
+#[cfg(feature = "woff2")]
+use woff2::Woff2Error;
 
+#[cfg(feature = "std")]
 pub mod buffer;
+pub mod font;
+pub mod glyph_cache;
+pub mod shared;
 pub mod tables;
+pub mod tag;
+pub mod writer;
+#[cfg(feature = "woff2")]
+pub mod woff2;
 
 #[derive(Debug, Error)]
 pub enum VeroTypeError {
     #[error(transparent)]
     TableEncodingError(#[from] TableEncodingError),
 
+    #[cfg(feature = "std")]
     #[error(transparent)]
     VeroBufReaderError(#[from] VeroBufReaderError),
+
+    #[cfg(feature = "woff2")]
+    #[error(transparent)]
+    Woff2Error(#[from] Woff2Error),
     
     #[error(transparent)]
     FailedToReadEnoughBytes(#[from] TryFromSliceError)
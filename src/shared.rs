@@ -0,0 +1,147 @@
+use core::{fmt, ops::Deref, ops::Range};
+
+use alloc::{sync::Arc, vec::Vec};
+
+/// A reference counted view into the bytes of a font, cloning or slicing it
+/// never copies the bytes.
+///
+/// Tables which keep a part of their bytes around (the glyph outlines of glyf,
+/// the string storage of name, ...) hold one of these, so a font parsed with
+/// `Font::from_shared` hands out views into the one shared buffer instead of
+/// a copy per table.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use vero_type::shared::SharedBytes;
+///
+/// let font = SharedBytes::new(Arc::from(&b"OTTO and more"[..]));
+/// let tag = font.slice(0..4).unwrap();
+///
+/// assert_eq!(&*tag, b"OTTO");
+/// assert!(font.slice(10..20).is_none());
+/// ```
+#[derive(Clone, Default)]
+pub struct SharedBytes {
+    /// The whole buffer, shared by every view
+    data: Buffer,
+
+    /// The range of `data` this view covers
+    range: Range<usize>,
+}
+
+/// The storage behind a `SharedBytes`, either a buffer in memory or a memory mapped file
+#[derive(Clone)]
+enum Buffer {
+    /// Bytes held in memory
+    Owned(Arc<[u8]>),
+
+    /// A memory mapped file
+    #[cfg(feature = "memmap2")]
+    Mapped(Arc<memmap2::Mmap>),
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Self::Owned(Arc::default())
+    }
+}
+
+impl Deref for Buffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Owned(data) => data,
+            #[cfg(feature = "memmap2")]
+            Self::Mapped(map) => map,
+        }
+    }
+}
+
+impl SharedBytes {
+    /// Returns a view over the whole buffer
+    pub fn new(data: Arc<[u8]>) -> Self {
+        Self::from_buffer(Buffer::Owned(data))
+    }
+
+    /// Returns a view over the whole memory mapped file, the mapping is
+    /// unmapped once the last view of it (or of a slice of it) is dropped
+    #[cfg(feature = "memmap2")]
+    pub fn from_mmap(map: memmap2::Mmap) -> Self {
+        Self::from_buffer(Buffer::Mapped(Arc::new(map)))
+    }
+
+    fn from_buffer(data: Buffer) -> Self {
+        let range = 0..data.len();
+
+        Self { data, range }
+    }
+
+    /// Returns a view over `range` of this view, or `None` if the range
+    /// doesn't lie within it
+    pub fn slice(&self, range: Range<usize>) -> Option<Self> {
+        // validates the range against this view before it's made absolute
+        self.get(range.clone())?;
+
+        Some(Self {
+            data: self.data.clone(),
+            range: self.range.start + range.start..self.range.start + range.end,
+        })
+    }
+
+    /// Returns the whole buffer the view was sliced out of
+    pub fn buffer(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Deref for SharedBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.data[self.range.clone()]
+    }
+}
+
+impl AsRef<[u8]> for SharedBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl From<Arc<[u8]>> for SharedBytes {
+    fn from(data: Arc<[u8]>) -> Self {
+        Self::new(data)
+    }
+}
+
+impl From<Vec<u8>> for SharedBytes {
+    fn from(data: Vec<u8>) -> Self {
+        Self::new(data.into())
+    }
+}
+
+impl From<&[u8]> for SharedBytes {
+    fn from(data: &[u8]) -> Self {
+        Self::new(data.into())
+    }
+}
+
+#[cfg(feature = "memmap2")]
+impl From<memmap2::Mmap> for SharedBytes {
+    fn from(map: memmap2::Mmap) -> Self {
+        Self::from_mmap(map)
+    }
+}
+
+impl fmt::Debug for SharedBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The buffer may be a whole font, print the view only
+        f.debug_struct("SharedBytes")
+            .field("range", &self.range)
+            .field("len", &self.len())
+            .finish()
+    }
+}
@@ -0,0 +1,168 @@
+use alloc::vec::Vec;
+
+use crate::tag::Tag;
+
+use super::{Table, TableEncodingError, be_i16, be_u16, f2dot14_to_f32};
+
+/// A representation of the [avar table](https://learn.microsoft.com/en-us/typography/opentype/spec/avar)
+/// which remaps the normalized coordinates of the variation axes non-linearly.
+///
+/// Only the segment maps of version 1.0 are parsed, the variation data of version 2.0 is left out.
+#[derive(Debug)]
+pub struct Avar {
+    /// The major version of the avar table, 1 or 2
+    major_version: u16,
+
+    /// The minor version of the avar table, set to 0
+    minor_version: u16,
+
+    /// The segment maps, one per axis in the order of the fvar axes
+    segment_maps: Vec<SegmentMap>,
+}
+
+impl Table for Avar {
+    const TAG: Tag = Tag::new(*b"avar");
+
+    /// Parses the avar table from it's raw bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the header or a segment map lies outside of the table.
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        let major_version = be_u16(buf, 0)?;
+        let minor_version = be_u16(buf, 2)?;
+        // bytes 4..6 are reserved
+        let axis_count = be_u16(buf, 6)?;
+
+        let mut at = 8;
+        let segment_maps = (0..axis_count)
+            .map(|_| {
+                let map = SegmentMap::from_buffer(buf, at)?;
+                at += 2 + map.mappings.len() * 4;
+                Ok(map)
+            })
+            .collect::<Result<Vec<SegmentMap>, TableEncodingError>>()?;
+
+        Ok(Self {
+            major_version,
+            minor_version,
+            segment_maps,
+        })
+    }
+}
+
+impl Avar {
+    /// Returns the major version of the avar table
+    pub fn major_version(&self) -> u16 {
+        self.major_version
+    }
+
+    /// Returns the minor version of the avar table
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version
+    }
+
+    /// Returns the number of axes the table has segment maps for
+    pub fn axis_count(&self) -> usize {
+        self.segment_maps.len()
+    }
+
+    /// Returns the segment maps, one per axis in the order of the fvar axes
+    pub fn segment_maps(&self) -> &[SegmentMap] {
+        &self.segment_maps
+    }
+
+    /// Remaps a normalized coordinate of the axis at `axis_index`, in the range -1.0 to 1.0,
+    /// the coordinate is kept as is if the table has no segment map for the axis.
+    ///
+    /// ```
+    /// use vero_type::tables::{Table, avar::Avar};
+    ///
+    /// // a single axis mapping -1.0, 0.0, 0.5 and 1.0 to -1.0, 0.0, 0.25 and 1.0
+    /// let table = [
+    ///     0, 1, 0, 0, 0, 0, 0, 1, // version 1.0, one axis
+    ///     0, 4, // four mappings
+    ///     0xC0, 0, 0xC0, 0, 0, 0, 0, 0, 0x20, 0, 0x10, 0, 0x40, 0, 0x40, 0,
+    /// ];
+    /// let avar = Avar::parse(&table).unwrap();
+    ///
+    /// assert_eq!(avar.remap(0, 0.5), 0.25);
+    /// assert_eq!(avar.remap(0, 0.75), 0.625);
+    /// assert_eq!(avar.remap(0, -0.5), -0.5);
+    /// // there's no second axis
+    /// assert_eq!(avar.remap(1, 0.5), 0.5);
+    /// ```
+    pub fn remap(&self, axis_index: usize, normalized: f32) -> f32 {
+        match self.segment_maps.get(axis_index) {
+            Some(map) => map.remap(normalized),
+            None => normalized,
+        }
+    }
+}
+
+/// The piecewise linear mapping of the normalized coordinates of a single axis
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentMap {
+    /// The `(from, to)` coordinate pairs in ascending order of `from`
+    mappings: Vec<(f32, f32)>,
+}
+
+impl SegmentMap {
+    /// Parses the segment map starting at `at`
+    fn from_buffer(buf: &[u8], at: usize) -> Result<Self, TableEncodingError> {
+        let count = be_u16(buf, at)?;
+
+        let mappings = (0..usize::from(count))
+            .map(|index| {
+                let from = be_i16(buf, at + 2 + index * 4)?;
+                let to = be_i16(buf, at + 4 + index * 4)?;
+
+                Ok((f2dot14_to_f32(from), f2dot14_to_f32(to)))
+            })
+            .collect::<Result<Vec<(f32, f32)>, TableEncodingError>>()?;
+
+        Ok(Self { mappings })
+    }
+
+    /// Returns the `(from, to)` coordinate pairs in ascending order of `from`
+    pub fn mappings(&self) -> &[(f32, f32)] {
+        &self.mappings
+    }
+
+    /// Remaps a normalized coordinate by interpolating between the surrounding mappings,
+    /// coordinates outside of the mappings are shifted along with the nearest one
+    pub fn remap(&self, normalized: f32) -> f32 {
+        let (Some(&(first_from, first_to)), Some(&(last_from, last_to))) =
+            (self.mappings.first(), self.mappings.last())
+        else {
+            return normalized;
+        };
+
+        if normalized <= first_from {
+            return normalized - first_from + first_to;
+        }
+
+        if normalized >= last_from {
+            return normalized - last_from + last_to;
+        }
+
+        for pair in self.mappings.windows(2) {
+            let ((from_start, to_start), (from_end, to_end)) = (pair[0], pair[1]);
+
+            if normalized == from_end {
+                return to_end;
+            }
+
+            if normalized < from_end {
+                if from_end <= from_start {
+                    return to_end;
+                }
+
+                let ratio = (normalized - from_start) / (from_end - from_start);
+                return to_start + ratio * (to_end - to_start);
+            }
+        }
+
+        normalized
+    }
+}
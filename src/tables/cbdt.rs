@@ -0,0 +1,66 @@
+use crate::{shared::SharedBytes, tag::Tag};
+
+use super::{Table, TableEncodingError, be_u32, cblc::Cblc};
+
+/// PNG data preceded by 5 bytes of small glyph metrics
+const PNG_WITH_SMALL_METRICS: u16 = 17;
+
+/// PNG data preceded by 8 bytes of big glyph metrics
+const PNG_WITH_BIG_METRICS: u16 = 18;
+
+/// PNG data whose metrics live in the CBLC table
+const PNG_WITHOUT_METRICS: u16 = 19;
+
+/// A representation of the [CBDT table](https://learn.microsoft.com/en-us/typography/opentype/spec/cbdt)
+/// which holds color bitmaps, emoji for example, the CBLC table tells where every bitmap starts
+#[derive(Debug)]
+pub struct Cbdt {
+    /// The raw table, bitmaps are sliced out on demand
+    data: SharedBytes,
+}
+
+impl Table for Cbdt {
+    const TAG: Tag = Tag::new(*b"CBDT");
+
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        Ok(Self { data: buf.into() })
+    }
+
+    fn parse_shared(data: &SharedBytes) -> Result<Self, TableEncodingError> {
+        Ok(Self { data: data.clone() })
+    }
+}
+
+impl Cbdt {
+    /// Returns the raw bytes of the table
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the PNG image of a glyph, untouched so it can be handed straight
+    /// to an image decoder. `cblc` picks the strike which suits `ppem` best,
+    /// see `Cblc::locate`.
+    ///
+    /// Returns `None` if no strike has a bitmap for the glyph, the bitmap
+    /// isn't PNG data (formats 17 to 19) or it lies outside of the table.
+    pub fn color_bitmap(&self, cblc: &Cblc, glyph_id: u16, ppem: u16) -> Option<&[u8]> {
+        let location = cblc.locate(glyph_id, ppem)?;
+
+        let start = location.offset() as usize;
+        let bitmap = self
+            .data
+            .get(start..start.checked_add(location.length() as usize)?)?;
+
+        let metrics_length = match location.image_format() {
+            PNG_WITH_SMALL_METRICS => 5,
+            PNG_WITH_BIG_METRICS => 8,
+            PNG_WITHOUT_METRICS => 0,
+            _ => return None,
+        };
+
+        let png_length = be_u32(bitmap, metrics_length).ok()? as usize;
+        let png_start = metrics_length + 4;
+
+        bitmap.get(png_start..png_start.checked_add(png_length)?)
+    }
+}
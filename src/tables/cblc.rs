@@ -0,0 +1,358 @@
+use alloc::vec::Vec;
+
+use crate::{shared::SharedBytes, tag::Tag};
+
+use super::{Table, TableEncodingError, be_u16, be_u32};
+
+/// A representation of the [CBLC table](https://learn.microsoft.com/en-us/typography/opentype/spec/cblc)
+/// which locates the color bitmaps of every strike (bitmap size) in the CBDT table
+#[derive(Debug)]
+pub struct Cblc {
+    /// The major version of the CBLC table, set to 3
+    major_version: u16,
+
+    /// The minor version of the CBLC table, set to 0
+    minor_version: u16,
+
+    /// The strikes of the font, one for every bitmap size
+    strikes: Vec<Strike>,
+}
+
+impl Table for Cblc {
+    const TAG: Tag = Tag::new(*b"CBLC");
+
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        Self::from_shared(buf.into())
+    }
+
+    fn parse_shared(data: &SharedBytes) -> Result<Self, TableEncodingError> {
+        Self::from_shared(data.clone())
+    }
+}
+
+impl Cblc {
+    /// Parses the strikes and the headers of their index subtables, the bitmaps
+    /// of a glyph are only located when it's looked up.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::InvalidBufferLength` if a record or an index
+    /// subtable header lies outside of the table, or a
+    /// `TableEncodingError::UnsupportedBitmapIndexFormat` for index subtable
+    /// formats other than 1 to 5.
+    fn from_shared(data: SharedBytes) -> Result<Self, TableEncodingError> {
+        let buf: &[u8] = &data;
+        let major_version = be_u16(buf, 0)?;
+        let minor_version = be_u16(buf, 2)?;
+        let num_sizes = be_u32(buf, 4)? as usize;
+
+        // Every BitmapSize record is 48 bytes and the records start right after the 8 byte header
+        let strikes = (0..num_sizes)
+            .map(|index| Strike::from_buffer(&data, 8 + index * 48))
+            .collect::<Result<Vec<Strike>, TableEncodingError>>()?;
+
+        Ok(Self {
+            major_version,
+            minor_version,
+            strikes,
+        })
+    }
+
+    /// Returns the major version of the CBLC table
+    pub fn major_version(&self) -> u16 {
+        self.major_version
+    }
+
+    /// Returns the minor version of the CBLC table
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version
+    }
+
+    /// Returns the strikes of the font, one for every bitmap size
+    pub fn strikes(&self) -> &[Strike] {
+        &self.strikes
+    }
+
+    /// Locates the bitmap of a glyph in the strike which suits `ppem` best.
+    ///
+    /// Out of the strikes which have a bitmap for the glyph, that's the smallest
+    /// strike of at least `ppem` pixels per em, otherwise the largest one
+    /// since scaling a bitmap down looks better than scaling it up.
+    pub fn locate(&self, glyph_id: u16, ppem: u16) -> Option<BitmapLocation> {
+        let candidates = self
+            .strikes
+            .iter()
+            .filter_map(|strike| Some((u16::from(strike.ppem_y), strike.location(glyph_id)?)));
+
+        let mut best: Option<(u16, BitmapLocation)> = None;
+
+        for (strike_ppem, location) in candidates {
+            let better = match best {
+                None => true,
+                // A large enough strike beats any smaller one, the closest one wins
+                Some((best_ppem, _)) if strike_ppem >= ppem => {
+                    best_ppem < ppem || strike_ppem < best_ppem
+                }
+                Some((best_ppem, _)) => best_ppem < ppem && strike_ppem > best_ppem,
+            };
+
+            if better {
+                best = Some((strike_ppem, location));
+            }
+        }
+
+        best.map(|(_, location)| location)
+    }
+}
+
+/// A strike of the CBLC table, the bitmaps of a single size
+#[derive(Debug)]
+pub struct Strike {
+    /// The horizontal pixels per em of the bitmaps
+    ppem_x: u8,
+
+    /// The vertical pixels per em of the bitmaps
+    ppem_y: u8,
+
+    /// The bit depth of the bitmaps, 32 for color bitmaps
+    bit_depth: u8,
+
+    /// The lowest glyph id with a bitmap in the strike
+    start_glyph_id: u16,
+
+    /// The highest glyph id with a bitmap in the strike
+    end_glyph_id: u16,
+
+    /// The index subtables in the order of the index subtable array
+    subtables: Vec<IndexSubtable>,
+
+    /// The whole CBLC table, the index subtables are read from it on demand
+    data: SharedBytes,
+}
+
+impl Strike {
+    /// Parses the BitmapSize record at `at`, `data` is the whole CBLC table
+    /// as the index subtable offsets are relative to it
+    fn from_buffer(data: &SharedBytes, at: usize) -> Result<Self, TableEncodingError> {
+        let buf: &[u8] = data;
+        let array_offset = be_u32(buf, at)? as usize;
+        let num_subtables = be_u32(buf, at + 8)? as usize;
+
+        // bytes 12..40 are the color ref and the line metrics, which aren't needed
+        // to hand out the bitmaps
+        let start_glyph_id = be_u16(buf, at + 40)?;
+        let end_glyph_id = be_u16(buf, at + 42)?;
+        let ppem_bytes = buf
+            .get(at + 44..at + 47)
+            .ok_or(TableEncodingError::InvalidBufferLength(at + 47, buf.len()))?;
+
+        // Several entries may point at the same subtable, only the 8 byte entries
+        // and headers are read so aliasing them costs nothing more
+        let subtables = (0..num_subtables)
+            .map(|index| IndexSubtable::from_buffer(buf, array_offset, array_offset + index * 8))
+            .collect::<Result<Vec<IndexSubtable>, TableEncodingError>>()?;
+
+        Ok(Self {
+            ppem_x: ppem_bytes[0],
+            ppem_y: ppem_bytes[1],
+            bit_depth: ppem_bytes[2],
+            start_glyph_id,
+            end_glyph_id,
+            subtables,
+            data: data.clone(),
+        })
+    }
+
+    /// Returns the horizontal pixels per em of the bitmaps
+    pub fn ppem_x(&self) -> u8 {
+        self.ppem_x
+    }
+
+    /// Returns the vertical pixels per em of the bitmaps
+    pub fn ppem_y(&self) -> u8 {
+        self.ppem_y
+    }
+
+    /// Returns the bit depth of the bitmaps, 32 for color bitmaps
+    pub fn bit_depth(&self) -> u8 {
+        self.bit_depth
+    }
+
+    /// Returns the lowest glyph id with a bitmap in the strike
+    pub fn start_glyph_id(&self) -> u16 {
+        self.start_glyph_id
+    }
+
+    /// Returns the highest glyph id with a bitmap in the strike
+    pub fn end_glyph_id(&self) -> u16 {
+        self.end_glyph_id
+    }
+
+    /// Returns the location of the glyph's bitmap, or `None` if the strike doesn't have one.
+    ///
+    /// The index subtables are walked in order and the first one covering the glyph
+    /// wins, a subtable whose data is truncated doesn't locate the glyph.
+    pub fn location(&self, glyph_id: u16) -> Option<BitmapLocation> {
+        self.subtables
+            .iter()
+            .filter(|subtable| {
+                (subtable.first_glyph_id..=subtable.last_glyph_id).contains(&glyph_id)
+            })
+            .find_map(|subtable| subtable.locate(&self.data, glyph_id))
+    }
+}
+
+/// Where the bitmap of a glyph lives in the CBDT table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BitmapLocation {
+    /// The glyph the bitmap draws
+    glyph_id: u16,
+
+    /// The format of the bitmap data, 17 to 19 for PNG data
+    image_format: u16,
+
+    /// The offset of the bitmap data from the start of the CBDT table
+    offset: u32,
+
+    /// The length of the bitmap data, including it's metrics
+    length: u32,
+}
+
+impl BitmapLocation {
+    /// Returns the glyph the bitmap draws
+    pub fn glyph_id(&self) -> u16 {
+        self.glyph_id
+    }
+
+    /// Returns the format of the bitmap data, 17 to 19 for PNG data
+    pub fn image_format(&self) -> u16 {
+        self.image_format
+    }
+
+    /// Returns the offset of the bitmap data from the start of the CBDT table
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns the length of the bitmap data, including it's metrics
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+}
+
+/// An index subtable of a strike, locating the bitmaps of a range of glyphs
+#[derive(Debug, Clone, Copy)]
+struct IndexSubtable {
+    /// The first glyph the subtable covers
+    first_glyph_id: u16,
+
+    /// The last glyph the subtable covers, inclusive
+    last_glyph_id: u16,
+
+    /// The layout of the subtable, 1 to 5
+    index_format: u16,
+
+    /// The format of the bitmap data in CBDT
+    image_format: u16,
+
+    /// The offset of the subtable's bitmap data from the start of the CBDT table
+    image_data_offset: u32,
+
+    /// The offset of the format specific part of the subtable from the start of the CBLC table
+    body: usize,
+}
+
+impl IndexSubtable {
+    /// Parses the index subtable array entry at `entry` along with the header of
+    /// the subtable it points at, the subtable offsets are relative to `array_offset`
+    fn from_buffer(
+        buf: &[u8],
+        array_offset: usize,
+        entry: usize,
+    ) -> Result<Self, TableEncodingError> {
+        let at = array_offset.saturating_add(be_u32(buf, entry + 4)? as usize);
+        let index_format = be_u16(buf, at)?;
+
+        if !(1..=5).contains(&index_format) {
+            return Err(TableEncodingError::UnsupportedBitmapIndexFormat(
+                index_format,
+            ));
+        }
+
+        Ok(Self {
+            first_glyph_id: be_u16(buf, entry)?,
+            last_glyph_id: be_u16(buf, entry + 2)?,
+            index_format,
+            image_format: be_u16(buf, at + 2)?,
+            image_data_offset: be_u32(buf, at + 4)?,
+            body: at + 8,
+        })
+    }
+
+    /// Locates the bitmap of a glyph within the subtable, every format describes
+    /// the same thing (a glyph's offset and length in CBDT) in a different layout.
+    ///
+    /// Returns `None` if the subtable has no bitmap for the glyph or it's truncated.
+    fn locate(&self, buf: &[u8], glyph_id: u16) -> Option<BitmapLocation> {
+        let body = self.body;
+        let index = usize::from(glyph_id.checked_sub(self.first_glyph_id)?);
+
+        let (offset, length) = match self.index_format {
+            // Variable sized bitmaps, with 4 (format 1) or 2 (format 3) byte offsets
+            // and one extra offset so the length of the last bitmap can be computed
+            1 | 3 => {
+                let read = |index: usize| match self.index_format {
+                    1 => be_u32(buf, body + index * 4).ok(),
+                    _ => be_u16(buf, body + index * 2).ok().map(u32::from),
+                };
+
+                let (start, end) = (read(index)?, read(index + 1)?);
+                (start, end.saturating_sub(start))
+            }
+            // Constant sized bitmaps for the whole glyph range
+            2 => {
+                let image_size = be_u32(buf, body).ok()?;
+                ((index as u32).saturating_mul(image_size), image_size)
+            }
+            // Variable sized bitmaps for a sparse list of glyphs
+            4 => {
+                let num_glyphs = be_u32(buf, body).ok()? as usize;
+                let pairs = body + 4;
+
+                // One extra pair follows the listed glyphs so the length of the last can be computed
+                let index = (0..num_glyphs)
+                    .map_while(|index| be_u16(buf, pairs + index * 4).ok())
+                    .position(|listed| listed == glyph_id)?;
+
+                let start = u32::from(be_u16(buf, pairs + index * 4 + 2).ok()?);
+                let end = u32::from(be_u16(buf, pairs + index * 4 + 6).ok()?);
+                (start, end.saturating_sub(start))
+            }
+            // Constant sized bitmaps for a sparse list of glyphs, the image size
+            // is followed by 8 bytes of big glyph metrics
+            _ => {
+                let image_size = be_u32(buf, body).ok()?;
+                let num_glyphs = be_u32(buf, body + 12).ok()? as usize;
+                let ids = body + 16;
+
+                let index = (0..num_glyphs)
+                    .map_while(|index| be_u16(buf, ids + index * 2).ok())
+                    .position(|listed| listed == glyph_id)?;
+
+                ((index as u32).saturating_mul(image_size), image_size)
+            }
+        };
+
+        // A zero length marks a glyph without a bitmap in the offset based formats
+        if length == 0 {
+            return None;
+        }
+
+        Some(BitmapLocation {
+            glyph_id,
+            image_format: self.image_format,
+            offset: self.image_data_offset.saturating_add(offset),
+            length,
+        })
+    }
+}
@@ -1,15 +1,313 @@
+use alloc::vec::Vec;
+
+use crate::tag::Tag;
+
+use super::{
+    Table, TableEncodingError, be_i16, be_u16, be_u32,
+    name::{PlatformId, PlatformSpecificId},
+};
+
 /// A representation of the [cmap table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6cmap.html)
-/// including methods to extract it's values safely and efficiently 
-/// supporting only formats 4 and 12 as these are the most used formats while other
+/// including methods to extract it's values safely and efficiently
+/// supporting only formats 2, 4, 12 and 13 as these are the most used formats while other
 /// are either for specialized uses or just never got materialized as the reference manual suggests.
 #[derive(Debug)]
 pub struct Cmap {
     /// The version of the cmap table
     /// it's almost guarenteed to be set to zero
     version: u16,
-    
+
     /// The number of encoding subtables
     subtables: u16,
+
+    /// The encoding subtables in the order they appear in the table
+    records: Vec<CmapSub>,
+
+    /// The index of the best subtable, picked once when the table is parsed
+    best: Option<usize>,
+}
+
+impl Table for Cmap {
+    const TAG: Tag = Tag::new(*b"cmap");
+
+    /// Parses the cmap table from it's raw bytes.
+    ///
+    /// Every encoding subtable is read, subtables with a format that isn't
+    /// supported are kept (so they can be enumerated) but have no mapping.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the table (or one of the supported subtables) is truncated.
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        let version = be_u16(buf, 0)?;
+        let subtables = be_u16(buf, 2)?;
+
+        // Every encoding record is 8 bytes and the records array starts right after the header
+        let records = (0..usize::from(subtables))
+            .map(|index| CmapSub::from_buffer(buf, 4 + index * 8))
+            .collect::<Result<Vec<CmapSub>, TableEncodingError>>()?;
+
+        let best = records
+            .iter()
+            .enumerate()
+            .filter_map(|(index, sub)| sub.rank().map(|rank| (rank, index)))
+            .min_by_key(|(rank, _)| *rank)
+            .map(|(_, index)| index);
+
+        Ok(Self {
+            version,
+            subtables,
+            records,
+            best,
+        })
+    }
+}
+
+impl Cmap {
+    /// Returns the version of the cmap table
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the number of encoding subtables declared in the table header
+    pub fn num_subtables(&self) -> u16 {
+        self.subtables
+    }
+
+    /// Returns the encoding subtables
+    pub fn subtables(&self) -> &[CmapSub] {
+        &self.records
+    }
+
+    /// Returns the encoding subtable at `index`, in the order of `Cmap::subtables`.
+    ///
+    /// Useful when `Cmap::best_subtable` doesn't pick the subtable the caller wants.
+    pub fn subtable(&self, index: usize) -> Option<&CmapSub> {
+        self.records.get(index)
+    }
+
+    /// Picks the subtable best suited for mapping Unicode characters.
+    ///
+    /// Full repertoire subtables (format 12) are preferred over BMP only ones (format 4),
+    /// and Unicode encodings are preferred over the Microsoft symbol encoding.
+    /// Subtables with an unsupported format, format 2 ones and ones in a legacy
+    /// (non-Unicode) encoding are never picked. The pick is made once
+    /// when the table is parsed, so looking characters up doesn't repeat it.
+    pub fn best_subtable(&self) -> Option<&CmapSub> {
+        self.records.get(self.best_subtable_index()?)
+    }
+
+    /// Returns the index of `Cmap::best_subtable` within `Cmap::subtables`
+    fn best_subtable_index(&self) -> Option<usize> {
+        self.best
+    }
+
+    /// Like `Cmap::best_subtable`, but tells a cmap without any subtable
+    /// apart from a cmap whose subtables are all in formats this crate can't parse.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::UnsupportedCmapFormat` with the format of the
+    /// first subtable if there are subtables but none of them is supported.
+    /// Supported subtables which are all in a legacy encoding give `Ok(None)`.
+    pub fn try_best_subtable(&self) -> Result<Option<&CmapSub>, TableEncodingError> {
+        let supported = self.records.iter().any(|sub| sub.mapping.is_some());
+
+        match (self.best_subtable(), self.records.first()) {
+            (Some(sub), _) => Ok(Some(sub)),
+            (None, Some(first)) if !supported => {
+                Err(TableEncodingError::UnsupportedCmapFormat(first.format))
+            }
+            (None, _) => Ok(None),
+        }
+    }
+
+    /// Maps a character to it's glyph id using the best subtable,
+    /// see `Cmap::best_subtable`.
+    ///
+    /// Returns `None` if no subtable covers the character.
+    pub fn glyph_index(&self, c: char) -> Option<u16> {
+        self.best_subtable()?.glyph_index(c)
+    }
+
+    /// Maps every character of `text` to it's glyph id, characters which
+    /// aren't mapped fall back to glyph 0 (`.notdef`).
+    ///
+    /// This is a pure per-character cmap lookup, no shaping is done so
+    /// ligatures, contextual forms and combining sequences are not substituted.
+    pub fn glyph_indices(&self, text: &str) -> Vec<u16> {
+        let subtable = self.best_subtable();
+
+        text.chars()
+            .map(|c| subtable.and_then(|sub| sub.glyph_index(c)).unwrap_or(0))
+            .collect()
+    }
+
+    /// Maps a character to it's glyph id and tells which subtable and which
+    /// mechanism resolved it, to explain why a character renders a given glyph.
+    ///
+    /// The best subtable is tried first (see `Cmap::best_subtable`). A character
+    /// it doesn't map which fits in a single byte is then looked up at 0xF000 plus it's
+    /// code in the first Microsoft symbol subtable. Variation sequences aren't
+    /// resolved as format 14 subtables aren't parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::{Table, cmap::{Cmap, Resolution}};
+    ///
+    /// let cmap = Cmap::parse(&[0, 0, 0, 0]).unwrap();
+    ///
+    /// assert_eq!(cmap.resolve('A'), Resolution::Unmapped);
+    /// assert_eq!(cmap.resolve('A').glyph_id(), None);
+    /// ```
+    pub fn resolve(&self, c: char) -> Resolution {
+        if let Some(subtable) = self.best_subtable_index()
+            && let Some(glyph_id) = self.records[subtable].glyph_index(c)
+        {
+            return Resolution::Base { glyph_id, subtable };
+        }
+
+        let code = u32::from(c);
+        if code > 0xFF {
+            return Resolution::Unmapped;
+        }
+
+        self.records
+            .iter()
+            .position(|sub| (sub.platform_id, sub.platform_specific_id) == (3, 0))
+            .and_then(|subtable| {
+                let glyph_id = self.records[subtable].code_glyph_index(0xF000 | code)?;
+
+                Some(Resolution::SymbolOffset { glyph_id, subtable })
+            })
+            .unwrap_or(Resolution::Unmapped)
+    }
+
+    /// Tells how a character is covered by the best subtable, telling apart
+    /// characters without any mapping from characters mapped to `.notdef`
+    pub fn coverage(&self, c: char) -> Coverage {
+        match self.glyph_index(c) {
+            Some(0) => Coverage::NotDef,
+            Some(glyph_id) => Coverage::Glyph(glyph_id),
+            None => Coverage::Unmapped,
+        }
+    }
+
+    /// Returns the characters of `text` the font can't render, those which aren't
+    /// mapped or are mapped to glyph 0 (`.notdef`), see `Cmap::coverage` to tell them apart.
+    ///
+    /// Every character is listed once, in the order it first appears in `text`.
+    /// Picking a fallback font comes down to checking this is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::{Table, cmap::Cmap};
+    ///
+    /// // a cmap without any subtable can't render anything
+    /// let cmap = Cmap::parse(&[0, 0, 0, 0]).unwrap();
+    ///
+    /// assert_eq!(cmap.unsupported_chars("abba"), ['a', 'b']);
+    /// ```
+    pub fn unsupported_chars(&self, text: &str) -> Vec<char> {
+        let mut unsupported = Vec::new();
+
+        for c in text.chars() {
+            if !matches!(self.coverage(c), Coverage::Glyph(_)) && !unsupported.contains(&c) {
+                unsupported.push(c);
+            }
+        }
+
+        unsupported
+    }
+
+    /// Iterates over the characters the best subtable maps to a glyph other than
+    /// `.notdef`, along with their glyph ids, in ascending order and each character once.
+    ///
+    /// Format 2 subtables map legacy multi-byte codes rather than characters and yield nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::{Table, cmap::Cmap};
+    ///
+    /// let cmap = Cmap::parse(&[0, 0, 0, 0]).unwrap();
+    ///
+    /// assert_eq!(cmap.mappings().count(), 0);
+    /// ```
+    pub fn mappings(&self) -> impl Iterator<Item = (char, u16)> + '_ {
+        let subtable = self.best_subtable();
+        let ranges = subtable
+            .and_then(CmapSub::mapping)
+            .map(CmapMapping::char_ranges)
+            .unwrap_or_default();
+
+        ranges
+            .into_iter()
+            .flat_map(|(start, end)| start..=end)
+            .filter_map(move |code| {
+                let c = char::from_u32(code)?;
+
+                match subtable?.code_glyph_index(code)? {
+                    0 => None,
+                    glyph_id => Some((c, glyph_id)),
+                }
+            })
+    }
+}
+
+/// Where a character's glyph came from, see `Cmap::resolve`.
+///
+/// Variation sequences aren't resolved yet, the enum is non exhaustive so a variant
+/// for them can be added once format 14 subtables are parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Resolution {
+    /// The best subtable maps the character directly
+    Base {
+        /// The glyph the character is mapped to
+        glyph_id: u16,
+
+        /// The index of the subtable within `Cmap::subtables`
+        subtable: usize,
+    },
+
+    /// A Microsoft symbol subtable maps the character moved into the 0xF000 to 0xF0FF
+    /// private use range, the way symbol fonts encode their single byte codes
+    SymbolOffset {
+        /// The glyph the character is mapped to
+        glyph_id: u16,
+
+        /// The index of the subtable within `Cmap::subtables`
+        subtable: usize,
+    },
+
+    /// No subtable maps the character
+    Unmapped,
+}
+
+impl Resolution {
+    /// Returns the glyph the character resolved to, `None` if it's unmapped
+    pub fn glyph_id(&self) -> Option<u16> {
+        match *self {
+            Self::Base { glyph_id, .. } | Self::SymbolOffset { glyph_id, .. } => Some(glyph_id),
+            Self::Unmapped => None,
+        }
+    }
+}
+
+/// How a character is covered by a cmap, see `Cmap::coverage`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Coverage {
+    /// The character is mapped to the glyph with this id
+    Glyph(u16),
+
+    /// The character is explicitly mapped to glyph 0, `.notdef`
+    NotDef,
+
+    /// No subtable maps the character
+    Unmapped,
 }
 
 /// A representation of the cmap [sub table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6cmap.html)
@@ -17,10 +315,509 @@ pub struct Cmap {
 pub struct CmapSub {
     /// The platform identifier
     platform_id: u16,
-    
+
     /// The platform specific encoding identifier
     platform_specific_id: u16,
-    
+
     /// The offset of the mapping table
-    offset: u32
-}
\ No newline at end of file
+    offset: u32,
+
+    /// The format of the mapping table
+    format: u16,
+
+    /// The parsed mapping, `None` when the format isn't supported
+    mapping: Option<CmapMapping>,
+}
+
+impl CmapSub {
+    /// Parses the encoding record at `at` and the mapping table it points to,
+    /// `buf` is the whole cmap table as the mapping offset is relative to it.
+    fn from_buffer(buf: &[u8], at: usize) -> Result<Self, TableEncodingError> {
+        let platform_id = be_u16(buf, at)?;
+        let platform_specific_id = be_u16(buf, at + 2)?;
+        let offset = be_u32(buf, at + 4)?;
+
+        let start = offset as usize;
+        let format = be_u16(buf, start)?;
+
+        let mapping = match format {
+            2 => Some(CmapMapping::Format2(Format2::from_buffer(buf, start)?)),
+            4 => Some(CmapMapping::Format4(Format4::from_buffer(buf, start)?)),
+            12 => Some(CmapMapping::Format12(Format12::from_buffer(buf, start)?)),
+            13 => Some(CmapMapping::Format13(Format13::from_buffer(buf, start)?)),
+            _ => None,
+        };
+
+        Ok(Self {
+            platform_id,
+            platform_specific_id,
+            offset,
+            format,
+            mapping,
+        })
+    }
+
+    /// Returns the platform identifier
+    pub fn platform_id(&self) -> u16 {
+        self.platform_id
+    }
+
+    /// Returns the platform specific encoding identifier
+    pub fn platform_specific_id(&self) -> u16 {
+        self.platform_specific_id
+    }
+
+    /// Returns whether the subtable maps Unicode code points, see `PlatformSpecificId::is_unicode`
+    pub fn is_unicode(&self) -> bool {
+        let platform = PlatformId::from(self.platform_id);
+
+        PlatformSpecificId::from_platform(platform, self.platform_specific_id).is_unicode(platform)
+    }
+
+    /// Returns the offset of the mapping table from the start of the cmap table
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns the format of the mapping table
+    pub fn format(&self) -> u16 {
+        self.format
+    }
+
+    /// Returns the parsed mapping, `None` when the format isn't supported
+    pub fn mapping(&self) -> Option<&CmapMapping> {
+        self.mapping.as_ref()
+    }
+
+    /// Returns the parsed mapping
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::UnsupportedCmapFormat` if the format isn't supported.
+    pub fn try_mapping(&self) -> Result<&CmapMapping, TableEncodingError> {
+        self.mapping
+            .as_ref()
+            .ok_or(TableEncodingError::UnsupportedCmapFormat(self.format))
+    }
+
+    /// Maps a character to it's glyph id like `CmapSub::glyph_index`,
+    /// `Ok(None)` means the character isn't covered.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::UnsupportedCmapFormat` if the format isn't supported.
+    pub fn try_glyph_index(&self, c: char) -> Result<Option<u16>, TableEncodingError> {
+        self.try_mapping()?;
+
+        Ok(self.glyph_index(c))
+    }
+
+    /// Maps a character to it's glyph id using this subtable.
+    ///
+    /// Returns `None` if the character isn't covered or the format isn't supported.
+    pub fn glyph_index(&self, c: char) -> Option<u16> {
+        self.code_glyph_index(u32::from(c))
+    }
+
+    /// Maps a character code of the subtable's encoding, which doesn't have to be
+    /// a Unicode scalar value, to it's glyph id. A format 2 subtable of a Shift-JIS
+    /// font is looked up with the Shift-JIS code of the character, the lead byte
+    /// being the high byte of `code`.
+    ///
+    /// Returns `None` if the code isn't covered or the format isn't supported.
+    pub fn code_glyph_index(&self, code: u32) -> Option<u16> {
+        match self.mapping.as_ref()? {
+            CmapMapping::Format2(table) => table.glyph_index(code),
+            CmapMapping::Format4(table) => table.glyph_index(code),
+            CmapMapping::Format12(table) => table.glyph_index(code),
+            CmapMapping::Format13(table) => table.glyph_index(code),
+        }
+    }
+
+    /// Ranks the subtable for `Cmap::best_subtable`, lower is better
+    fn rank(&self) -> Option<u8> {
+        self.mapping.as_ref()?;
+
+        // Only Unicode and Windows symbol subtables are keyed by a `char`, format 2 and the
+        // legacy encodings are only reachable through `CmapSub::code_glyph_index`
+        let symbol = (self.platform_id, self.platform_specific_id) == (3, 0);
+        if self.format == 2 || !(self.is_unicode() || symbol) {
+            return None;
+        }
+
+        Some(
+            match (self.platform_id, self.platform_specific_id, self.format) {
+                // Windows full repertoire and Unicode full repertoire
+                (3, 10, 12) | (0, 4 | 6, 12) => 0,
+                // Any other Unicode full repertoire subtable
+                (0, _, 12) => 1,
+                // Windows BMP and Unicode BMP
+                (3, 1, 4) | (0, 3, 4) => 2,
+                // Any other Unicode BMP subtable
+                (0, _, 4) => 3,
+                // Last resort mappings only cover characters with a fallback glyph
+                (_, _, 13) => 6,
+                // Windows symbol
+                (3, 0, _) => 5,
+                _ => 4,
+            },
+        )
+    }
+}
+
+/// A parsed mapping table of one of the supported formats
+#[derive(Debug)]
+pub enum CmapMapping {
+    /// High-byte mapping through tables, covers the mixed one and two byte
+    /// legacy CJK encodings (Shift-JIS, Big5, ...)
+    Format2(Format2),
+
+    /// Segment mapping to delta values, covers the Basic Multilingual Plane
+    Format4(Format4),
+
+    /// Segmented coverage, covers the full Unicode repertoire
+    Format12(Format12),
+
+    /// Many-to-one range mappings, every character of a range maps to the same glyph
+    Format13(Format13),
+}
+
+impl CmapMapping {
+    /// Returns the sorted, disjoint and inclusive ranges of Unicode scalar values
+    /// the mapping covers, format 2 codes aren't characters so it has none
+    fn char_ranges(&self) -> Vec<(u32, u32)> {
+        let ranges: Vec<(u32, u32)> = match self {
+            Self::Format2(_) => Vec::new(),
+            Self::Format4(table) => table
+                .segments
+                .iter()
+                .map(|segment| (segment.start_code.into(), segment.end_code.into()))
+                .collect(),
+            Self::Format12(Format12 { groups }) | Self::Format13(Format13 { groups }) => groups
+                .iter()
+                .map(|group| (group.start_char_code, group.end_char_code))
+                .collect(),
+        };
+
+        // The format 12 and 13 group bounds are raw u32s, so clamp them to the scalar values
+        // around the surrogates, and the groups aren't guaranteed to be sorted or disjoint
+        let mut scalars = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            let end = end.min(char::MAX as u32);
+
+            for (start, end) in [(start, end.min(0xD7FF)), (start.max(0xE000), end)] {
+                if start <= end {
+                    scalars.push((start, end));
+                }
+            }
+        }
+        scalars.sort_unstable();
+
+        let mut merged: Vec<(u32, u32)> = Vec::with_capacity(scalars.len());
+        for (start, end) in scalars {
+            match merged.last_mut() {
+                Some((_, last_end)) if start <= last_end.saturating_add(1) => {
+                    *last_end = (*last_end).max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+
+        merged
+    }
+}
+
+/// A format 2 mapping table, the first byte of a code picks a sub header
+/// which maps a range of second bytes through the glyph index array.
+///
+/// A first byte whose key is 0 is a whole one byte code looked up in the first
+/// sub header, any other first byte is the lead byte of a two byte code.
+#[derive(Debug)]
+pub struct Format2 {
+    /// The index of the sub header of every first byte
+    sub_header_keys: Vec<u16>,
+
+    /// The sub headers, along with the position of their `id_range_offset` field
+    sub_headers: Vec<SubHeader>,
+
+    /// The bytes of the whole mapping table, which the range offsets point into
+    data: Vec<u8>,
+}
+
+/// A single sub header of a format 2 mapping table
+#[derive(Debug, Clone, Copy)]
+struct SubHeader {
+    first_code: u16,
+    entry_count: u16,
+    id_delta: i16,
+    id_range_offset: u16,
+
+    /// The position of the `id_range_offset` field within the mapping table,
+    /// the offset is relative to it
+    id_range_offset_at: usize,
+}
+
+impl Format2 {
+    /// Parses a format 2 mapping table starting at `start` within the cmap table
+    fn from_buffer(buf: &[u8], start: usize) -> Result<Self, TableEncodingError> {
+        let length = usize::from(be_u16(buf, start + 2)?);
+
+        // The 256 keys follow the format, length and language, each key is
+        // the byte offset of a sub header from the start of the sub headers
+        let sub_header_keys = (0..256)
+            .map(|i| Ok(be_u16(buf, start + 6 + i * 2)? / 8))
+            .collect::<Result<Vec<u16>, TableEncodingError>>()?;
+        let num_sub_headers = usize::from(sub_header_keys.iter().copied().max().unwrap_or(0)) + 1;
+
+        // The range offsets point past the sub headers, some fonts declare
+        // a length past the end of the table so clamp it
+        let end = (start + length).min(buf.len());
+        let data = buf.get(start..end).unwrap_or_default().to_vec();
+
+        let sub_headers = (0..num_sub_headers)
+            .map(|i| {
+                let at = 6 + 512 + i * 8;
+
+                Ok(SubHeader {
+                    first_code: be_u16(&data, at)?,
+                    entry_count: be_u16(&data, at + 2)?,
+                    id_delta: be_i16(&data, at + 4)?,
+                    id_range_offset: be_u16(&data, at + 6)?,
+                    id_range_offset_at: at + 6,
+                })
+            })
+            .collect::<Result<Vec<SubHeader>, TableEncodingError>>()?;
+
+        Ok(Self {
+            sub_header_keys,
+            sub_headers,
+            data,
+        })
+    }
+
+    /// Maps a one or two byte character code to it's glyph id
+    fn glyph_index(&self, code: u32) -> Option<u16> {
+        let code = u16::try_from(code).ok()?;
+        let [high, low] = code.to_be_bytes();
+
+        let sub_header = match code {
+            // A one byte code, unless the byte is the lead byte of two byte codes
+            0..=0xFF => match self.sub_header_keys[usize::from(low)] {
+                0 => self.sub_headers[0],
+                _ => return None,
+            },
+            // A two byte code, the high byte has to be a lead byte
+            _ => match self.sub_header_keys[usize::from(high)] {
+                0 => return None,
+                key => self.sub_headers[usize::from(key)],
+            },
+        };
+
+        let index = u16::from(low).checked_sub(sub_header.first_code)?;
+        if index >= sub_header.entry_count {
+            return None;
+        }
+
+        // id_range_offset is a byte offset from the sub header's own id_range_offset
+        // field to the glyph index array entry of it's first code
+        let at = sub_header.id_range_offset_at
+            + usize::from(sub_header.id_range_offset)
+            + usize::from(index) * 2;
+
+        match be_u16(&self.data, at).ok()? {
+            0 => Some(0),
+            glyph => Some(glyph.wrapping_add(sub_header.id_delta as u16)),
+        }
+    }
+}
+
+/// A format 4 mapping table, a set of segments where each
+/// segment maps a contiguous range of character codes
+#[derive(Debug)]
+pub struct Format4 {
+    /// The segments sorted by their end code
+    segments: Vec<Segment>,
+
+    /// The glyph index array which `id_range_offset` points into
+    glyph_ids: Vec<u16>,
+}
+
+/// A single segment of a format 4 mapping table
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    start_code: u16,
+    end_code: u16,
+    id_delta: i16,
+    id_range_offset: u16,
+}
+
+impl Format4 {
+    /// Parses a format 4 mapping table starting at `start` within the cmap table
+    fn from_buffer(buf: &[u8], start: usize) -> Result<Self, TableEncodingError> {
+        let length = usize::from(be_u16(buf, start + 2)?);
+        let seg_count = usize::from(be_u16(buf, start + 6)? / 2);
+
+        // The end codes array starts at 14, followed by a reserved pad of 2 bytes
+        // then the start codes, the deltas and the range offsets
+        let end_codes = start + 14;
+        let start_codes = end_codes + seg_count * 2 + 2;
+        let id_deltas = start_codes + seg_count * 2;
+        let id_range_offsets = id_deltas + seg_count * 2;
+        let glyph_id_array = id_range_offsets + seg_count * 2;
+
+        let segments = (0..seg_count)
+            .map(|i| {
+                Ok(Segment {
+                    start_code: be_u16(buf, start_codes + i * 2)?,
+                    end_code: be_u16(buf, end_codes + i * 2)?,
+                    id_delta: be_i16(buf, id_deltas + i * 2)?,
+                    id_range_offset: be_u16(buf, id_range_offsets + i * 2)?,
+                })
+            })
+            .collect::<Result<Vec<Segment>, TableEncodingError>>()?;
+
+        // The glyph id array spans until the end of the subtable, some fonts
+        // declare a length past the end of the table so clamp it
+        let end = (start + length).min(buf.len());
+        let glyph_ids = buf
+            .get(glyph_id_array..end)
+            .unwrap_or_default()
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect();
+
+        Ok(Self {
+            segments,
+            glyph_ids,
+        })
+    }
+
+    /// Maps a character code to it's glyph id
+    fn glyph_index(&self, code: u32) -> Option<u16> {
+        let code = u16::try_from(code).ok()?;
+
+        // Segments are sorted by end code, find the first one which ends at or after the code
+        let index = self
+            .segments
+            .partition_point(|segment| segment.end_code < code);
+        let segment = self.segments.get(index)?;
+
+        if segment.start_code > code {
+            return None;
+        }
+
+        if segment.id_range_offset == 0 {
+            return Some(code.wrapping_add(segment.id_delta as u16));
+        }
+
+        // id_range_offset is a byte offset from the segment's own id_range_offset entry,
+        // translate it into an index of the glyph id array which follows the range offsets
+        let glyph_index = usize::from(segment.id_range_offset / 2)
+            + usize::from(code - segment.start_code)
+            + index;
+        let glyph = *self
+            .glyph_ids
+            .get(glyph_index.checked_sub(self.segments.len())?)?;
+
+        match glyph {
+            0 => Some(0),
+            _ => Some(glyph.wrapping_add(segment.id_delta as u16)),
+        }
+    }
+}
+
+/// A format 12 mapping table, a set of groups where each group
+/// maps a contiguous range of character codes to a contiguous range of glyphs
+#[derive(Debug)]
+pub struct Format12 {
+    /// The groups sorted by their start character code
+    groups: Vec<SequentialMapGroup>,
+}
+
+/// A single group of a format 12 mapping table
+#[derive(Debug, Clone, Copy)]
+struct SequentialMapGroup {
+    start_char_code: u32,
+    end_char_code: u32,
+    start_glyph_id: u32,
+}
+
+impl Format12 {
+    /// Parses a format 12 mapping table starting at `start` within the cmap table
+    fn from_buffer(buf: &[u8], start: usize) -> Result<Self, TableEncodingError> {
+        Ok(Self {
+            groups: parse_groups(buf, start)?,
+        })
+    }
+
+    /// Maps a character code to it's glyph id
+    fn glyph_index(&self, code: u32) -> Option<u16> {
+        let group = find_group(&self.groups, code)?;
+
+        let glyph_id = group
+            .start_glyph_id
+            .checked_add(code - group.start_char_code)?;
+
+        u16::try_from(glyph_id).ok()
+    }
+}
+
+/// A format 13 mapping table, a set of groups where each group maps
+/// a contiguous range of character codes to a single glyph,
+/// used by last resort fonts to show one fallback glyph for whole ranges
+#[derive(Debug)]
+pub struct Format13 {
+    /// The groups sorted by their start character code,
+    /// `start_glyph_id` is the glyph of the whole group
+    groups: Vec<SequentialMapGroup>,
+}
+
+impl Format13 {
+    /// Parses a format 13 mapping table starting at `start` within the cmap table,
+    /// the layout is the same as format 12
+    fn from_buffer(buf: &[u8], start: usize) -> Result<Self, TableEncodingError> {
+        Ok(Self {
+            groups: parse_groups(buf, start)?,
+        })
+    }
+
+    /// Maps a character code to it's glyph id
+    fn glyph_index(&self, code: u32) -> Option<u16> {
+        let group = find_group(&self.groups, code)?;
+
+        u16::try_from(group.start_glyph_id).ok()
+    }
+}
+
+/// Parses the groups of a format 12 or 13 mapping table starting at `start` within the cmap table
+fn parse_groups(buf: &[u8], start: usize) -> Result<Vec<SequentialMapGroup>, TableEncodingError> {
+    let num_groups = be_u32(buf, start + 12)? as usize;
+
+    // Each group is 12 bytes and there can't be more groups than the table can hold
+    if num_groups > buf.len().saturating_sub(start + 16) / 12 {
+        return Err(TableEncodingError::InvalidBufferLength(
+            start + 16 + num_groups.saturating_mul(12),
+            buf.len(),
+        ));
+    }
+
+    (0..num_groups)
+        .map(|i| {
+            let at = start + 16 + i * 12;
+
+            Ok(SequentialMapGroup {
+                start_char_code: be_u32(buf, at)?,
+                end_char_code: be_u32(buf, at + 4)?,
+                start_glyph_id: be_u32(buf, at + 8)?,
+            })
+        })
+        .collect()
+}
+
+/// Finds the group covering a character code, the groups are sorted by their start code
+fn find_group(groups: &[SequentialMapGroup], code: u32) -> Option<&SequentialMapGroup> {
+    let index = groups.partition_point(|group| group.end_char_code < code);
+    let group = groups.get(index)?;
+
+    (group.start_char_code <= code).then_some(group)
+}
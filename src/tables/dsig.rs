@@ -0,0 +1,61 @@
+use crate::tag::Tag;
+
+use super::{Table, TableEncodingError, be_u16, be_u32};
+
+/// A representation of the [DSIG table](https://learn.microsoft.com/en-us/typography/opentype/spec/dsig)
+/// which holds the digital signatures of a font.
+///
+/// Only the header is parsed, the signatures themselves aren't read or verified.
+#[derive(Debug)]
+pub struct Dsig {
+    /// The version of the DSIG table, set to 1
+    version: u32,
+
+    /// The number of signatures in the table
+    num_signatures: u16,
+
+    /// The permission flags of the table
+    flags: u16,
+}
+
+impl Table for Dsig {
+    const TAG: Tag = Tag::new(*b"DSIG");
+
+    /// Parses the DSIG table header from it's raw bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the table is shorter than it's 8 byte header.
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        Ok(Self {
+            version: be_u32(buf, 0)?,
+            num_signatures: be_u16(buf, 4)?,
+            flags: be_u16(buf, 6)?,
+        })
+    }
+}
+
+impl Dsig {
+    /// Returns the version of the DSIG table
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the number of signatures in the table
+    pub fn num_signatures(&self) -> u16 {
+        self.num_signatures
+    }
+
+    /// Returns the permission flags of the table, bit 0 forbids resigning the font
+    pub fn flags(&self) -> u16 {
+        self.flags
+    }
+
+    /// Returns whether the table carries at least one signature.
+    ///
+    /// Fonts are sometimes shipped with a placeholder DSIG table without any
+    /// signatures, those aren't signed.
+    pub fn has_signature(&self) -> bool {
+        self.num_signatures > 0
+    }
+}
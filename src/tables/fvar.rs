@@ -0,0 +1,172 @@
+use alloc::vec::Vec;
+
+use crate::tag::Tag;
+
+use super::{Table, TableEncodingError, be_u16, be_u32, fixed_to_f32};
+
+/// The size of an axis record as of version 1.0, later versions may append fields
+const AXIS_RECORD_SIZE: u16 = 20;
+
+/// The axis flag marking an axis which shouldn't be exposed in user interfaces
+const HIDDEN_AXIS: u16 = 0x0001;
+
+/// A representation of the [fvar table](https://learn.microsoft.com/en-us/typography/opentype/spec/fvar)
+/// which makes a font variable and defines it's variation axes.
+///
+/// Only the header and the axis records are parsed, named instances are left out.
+#[derive(Debug)]
+pub struct Fvar {
+    /// The major version of the fvar table, set to 1
+    major_version: u16,
+
+    /// The minor version of the fvar table, set to 0
+    minor_version: u16,
+
+    /// The variation axes in the order the font declares them
+    axes: Vec<Axis>,
+
+    /// The number of named instances
+    instance_count: u16,
+}
+
+impl Table for Fvar {
+    const TAG: Tag = Tag::new(*b"fvar");
+
+    /// Parses the fvar table from it's raw bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the header or an axis record lies outside
+    /// of the table, or if the declared axis record size is too small to hold one.
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        let major_version = be_u16(buf, 0)?;
+        let minor_version = be_u16(buf, 2)?;
+        let axes_offset = usize::from(be_u16(buf, 4)?);
+        // bytes 6..8 are reserved
+        let axis_count = be_u16(buf, 8)?;
+        let axis_size = be_u16(buf, 10)?;
+        let instance_count = be_u16(buf, 12)?;
+
+        if axis_size < AXIS_RECORD_SIZE {
+            return Err(TableEncodingError::InvalidAxisSize(axis_size));
+        }
+
+        let axes = (0..usize::from(axis_count))
+            .map(|index| Axis::from_buffer(buf, axes_offset + index * usize::from(axis_size)))
+            .collect::<Result<Vec<Axis>, TableEncodingError>>()?;
+
+        Ok(Self {
+            major_version,
+            minor_version,
+            axes,
+            instance_count,
+        })
+    }
+}
+
+impl Fvar {
+    /// Returns the major version of the fvar table
+    pub fn major_version(&self) -> u16 {
+        self.major_version
+    }
+
+    /// Returns the minor version of the fvar table
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version
+    }
+
+    /// Returns the number of variation axes
+    pub fn axis_count(&self) -> usize {
+        self.axes.len()
+    }
+
+    /// Returns the variation axes in the order the font declares them
+    pub fn axes(&self) -> &[Axis] {
+        &self.axes
+    }
+
+    /// Returns the first axis with the given tag, such as `wght`
+    pub fn axis(&self, tag: impl Into<Tag>) -> Option<&Axis> {
+        let tag = tag.into();
+
+        self.axes.iter().find(|axis| axis.tag == tag)
+    }
+
+    /// Returns the number of named instances
+    pub fn instance_count(&self) -> u16 {
+        self.instance_count
+    }
+}
+
+/// A variation axis of a variable font, with it's values in user space coordinates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Axis {
+    /// The tag of the axis, such as `wght` or `wdth`
+    tag: Tag,
+
+    /// The minimum value of the axis, a 16.16 fixed point number
+    min_value: i32,
+
+    /// The default value of the axis, a 16.16 fixed point number
+    default_value: i32,
+
+    /// The maximum value of the axis, a 16.16 fixed point number
+    max_value: i32,
+
+    /// The axis flags
+    flags: u16,
+
+    /// The name id of the axis name in the name table
+    axis_name_id: u16,
+}
+
+impl Axis {
+    /// Parses the axis record starting at `at`
+    fn from_buffer(buf: &[u8], at: usize) -> Result<Self, TableEncodingError> {
+        let tag = Tag::new(be_u32(buf, at)?.to_be_bytes());
+
+        Ok(Self {
+            tag,
+            min_value: be_u32(buf, at + 4)? as i32,
+            default_value: be_u32(buf, at + 8)? as i32,
+            max_value: be_u32(buf, at + 12)? as i32,
+            flags: be_u16(buf, at + 16)?,
+            axis_name_id: be_u16(buf, at + 18)?,
+        })
+    }
+
+    /// Returns the tag of the axis, such as `wght` or `wdth`
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Returns the minimum value of the axis
+    pub fn min_value(&self) -> f32 {
+        fixed_to_f32(self.min_value)
+    }
+
+    /// Returns the default value of the axis
+    pub fn default_value(&self) -> f32 {
+        fixed_to_f32(self.default_value)
+    }
+
+    /// Returns the maximum value of the axis
+    pub fn max_value(&self) -> f32 {
+        fixed_to_f32(self.max_value)
+    }
+
+    /// Returns the axis flags
+    pub fn flags(&self) -> u16 {
+        self.flags
+    }
+
+    /// Returns whether the axis is meant to be hidden from user interfaces
+    pub fn is_hidden(&self) -> bool {
+        self.flags & HIDDEN_AXIS != 0
+    }
+
+    /// Returns the name id of the axis name in the name table
+    pub fn axis_name_id(&self) -> u16 {
+        self.axis_name_id
+    }
+}
@@ -0,0 +1,870 @@
+use alloc::vec::Vec;
+
+use crate::{shared::SharedBytes, tag::Tag};
+
+use super::{Table, TableEncodingError, be_i16, be_u16, checked_range, loca::Loca};
+
+/// The point is on the curve, otherwise it's a quadratic control point
+pub(crate) const ON_CURVE_POINT: u8 = 0x01;
+
+/// The x coordinate is a single byte, the sign is given by `X_IS_SAME_OR_POSITIVE`
+pub(crate) const X_SHORT_VECTOR: u8 = 0x02;
+
+/// The y coordinate is a single byte, the sign is given by `Y_IS_SAME_OR_POSITIVE`
+pub(crate) const Y_SHORT_VECTOR: u8 = 0x04;
+
+/// The next byte is the number of times to repeat this flag
+pub(crate) const REPEAT_FLAG: u8 = 0x08;
+
+/// With a short vector the x coordinate is positive, otherwise the x coordinate
+/// is the same as the previous one (and isn't stored)
+pub(crate) const X_IS_SAME_OR_POSITIVE: u8 = 0x10;
+
+/// With a short vector the y coordinate is positive, otherwise the y coordinate
+/// is the same as the previous one (and isn't stored)
+pub(crate) const Y_IS_SAME_OR_POSITIVE: u8 = 0x20;
+
+/// The contours of the simple glyph may overlap, only ever set on the first flag
+#[cfg_attr(not(feature = "woff2"), allow(dead_code))]
+pub(crate) const OVERLAP_SIMPLE: u8 = 0x40;
+
+/// The arguments of a component are words, otherwise they're bytes
+const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+
+/// The component has a single scale for both axes
+const WE_HAVE_A_SCALE: u16 = 0x0008;
+
+/// Another component follows this one
+pub(crate) const MORE_COMPONENTS: u16 = 0x0020;
+
+/// The component has a separate scale for each axis
+const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+
+/// The component has a 2 by 2 transformation matrix
+const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+
+/// Instructions follow the last component
+#[cfg_attr(not(feature = "woff2"), allow(dead_code))]
+pub(crate) const WE_HAVE_INSTRUCTIONS: u16 = 0x0100;
+
+/// The upper bound of line segments a single quadratic curve is flattened into,
+/// keeps a tiny (or zero) tolerance from exploding the output
+const MAX_CURVE_SEGMENTS: u32 = 64;
+
+/// The number of samples taken along each axis of a pixel when rasterizing
+#[cfg(feature = "std")]
+const RASTER_SAMPLES: usize = 4;
+
+/// A representation of the [glyf table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6glyf.html)
+/// which holds the outlines of the glyphs, the loca table tells where every glyph starts
+#[derive(Debug)]
+pub struct Glyf {
+    /// The raw table, glyphs are parsed on demand
+    data: SharedBytes,
+}
+
+impl Table for Glyf {
+    const TAG: Tag = Tag::new(*b"glyf");
+
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        Ok(Self { data: buf.into() })
+    }
+
+    fn parse_shared(data: &SharedBytes) -> Result<Self, TableEncodingError> {
+        Ok(Self { data: data.clone() })
+    }
+}
+
+impl Glyf {
+    /// Returns the raw bytes of the table
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Parses the glyph with the given id, `loca` locates it within the table.
+    ///
+    /// Returns `Ok(None)` if the glyph id is out of bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the glyph lies outside of the table
+    /// or it's outline is malformed.
+    pub fn glyph(&self, loca: &Loca, glyph_id: u16) -> Result<Option<Glyph>, TableEncodingError> {
+        match self.glyph_bytes(loca, glyph_id)? {
+            Some(bytes) => Glyph::from_buffer(bytes).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the ids of the glyphs a composite glyph is directly made of,
+    /// in the order of it's component records, without decoding any outline.
+    ///
+    /// The components may be composite glyphs themselves, a subsetter has to
+    /// keep following the returned ids until no new glyph turns up.
+    /// Simple glyphs, empty glyphs and out of bounds glyph ids have no components.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the glyph lies outside of the table
+    /// or it's component records are truncated.
+    pub fn component_glyphs(
+        &self,
+        loca: &Loca,
+        glyph_id: u16,
+    ) -> Result<Vec<u16>, TableEncodingError> {
+        match self.glyph_bytes(loca, glyph_id)? {
+            Some(bytes) if be_i16(bytes, 0).is_ok_and(|contours| contours < 0) => {
+                component_ids(bytes)
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns the number of points of a simple glyph out of it's last contour end point,
+    /// without decoding the outline. Cheap enough to run over every glyph of a font.
+    ///
+    /// Composite glyphs, empty glyphs and out of bounds glyph ids have no points.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the glyph lies outside of the table
+    /// or it's end points are truncated.
+    pub fn num_points(&self, loca: &Loca, glyph_id: u16) -> Result<usize, TableEncodingError> {
+        let Some(bytes) = self.glyph_bytes(loca, glyph_id)? else {
+            return Ok(0);
+        };
+
+        if bytes.is_empty() {
+            return Ok(0);
+        }
+
+        let contours = be_i16(bytes, 0)?;
+        if contours <= 0 {
+            return Ok(0);
+        }
+
+        // the end point of the last contour is the index of the last point
+        let last = be_u16(bytes, 10 + (contours as usize - 1) * 2)?;
+        Ok(usize::from(last) + 1)
+    }
+
+    /// Returns the bytes of a glyph, `None` if the glyph id is out of bounds
+    fn glyph_bytes(&self, loca: &Loca, glyph_id: u16) -> Result<Option<&[u8]>, TableEncodingError> {
+        let Some(range) = loca.glyph_range(glyph_id) else {
+            return Ok(None);
+        };
+
+        // glyph_range never hands out a reversed range
+        let range = checked_range(range.start, range.end - range.start)?;
+        let end = range.end;
+
+        self.data
+            .get(range)
+            .map(Some)
+            .ok_or(TableEncodingError::InvalidBufferLength(
+                end,
+                self.data.len(),
+            ))
+    }
+}
+
+/// A point of a glyph outline, in font units
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point {
+    /// The x coordinate
+    pub x: i16,
+
+    /// The y coordinate
+    pub y: i16,
+
+    /// Whether the point is on the curve, off-curve points are quadratic control points
+    pub on_curve: bool,
+}
+
+/// An affine transform of outline coordinates, the matrix `[xx, yx, xy, yy, dx, dy]`
+/// maps a point to `(xx * x + xy * y + dx, yx * x + yy * y + dy)`.
+///
+/// # Examples
+///
+/// ```
+/// use vero_type::tables::glyf::Transform;
+///
+/// // 2048 units per em drawn at 16 pixels per em, then moved 10 pixels to the right
+/// let transform = Transform::pixels_per_em(2048, 16.0).then(Transform::translate(10.0, 0.0));
+///
+/// assert_eq!(transform.apply((1024.0, 512.0)), (18.0, 4.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    /// The matrix `[xx, yx, xy, yy, dx, dy]`
+    matrix: [f32; 6],
+}
+
+impl Transform {
+    /// The transform leaving every point where it is
+    pub const IDENTITY: Self = Self::new([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+
+    /// Returns the transform of the matrix `[xx, yx, xy, yy, dx, dy]`
+    pub const fn new(matrix: [f32; 6]) -> Self {
+        Self { matrix }
+    }
+
+    /// Returns a transform scaling the x and y coordinates independently
+    pub const fn scale(x: f32, y: f32) -> Self {
+        Self::new([x, 0.0, 0.0, y, 0.0, 0.0])
+    }
+
+    /// Returns a transform scaling both coordinates by `factor`
+    pub const fn uniform_scale(factor: f32) -> Self {
+        Self::scale(factor, factor)
+    }
+
+    /// Returns a transform moving every point by `(x, y)`
+    pub const fn translate(x: f32, y: f32) -> Self {
+        Self::new([1.0, 0.0, 0.0, 1.0, x, y])
+    }
+
+    /// Returns a transform from font units to pixels, for a font of `units_per_em`
+    /// (see `Head::units_per_em`) drawn at `pixels_per_em`
+    pub fn pixels_per_em(units_per_em: u16, pixels_per_em: f32) -> Self {
+        Self::uniform_scale(pixels_per_em / f32::from(units_per_em))
+    }
+
+    /// Returns the matrix `[xx, yx, xy, yy, dx, dy]`
+    pub fn matrix(&self) -> [f32; 6] {
+        self.matrix
+    }
+
+    /// Returns the transform applying this transform followed by `next`
+    pub fn then(&self, next: Self) -> Self {
+        let [a, b, c, d, e, f] = self.matrix;
+        let [na, nb, nc, nd, ne, nf] = next.matrix;
+
+        Self::new([
+            na * a + nc * b,
+            nb * a + nd * b,
+            na * c + nc * d,
+            nb * c + nd * d,
+            na * e + nc * f + ne,
+            nb * e + nd * f + nf,
+        ])
+    }
+
+    /// Applies the transform to a point, such as the points of `Glyph::flatten`
+    pub fn apply(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        let [xx, yx, xy, yy, dx, dy] = self.matrix;
+
+        (xx * x + xy * y + dx, yx * x + yy * y + dy)
+    }
+}
+
+impl Default for Transform {
+    /// The identity transform
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<[f32; 6]> for Transform {
+    fn from(matrix: [f32; 6]) -> Self {
+        Self::new(matrix)
+    }
+}
+
+/// A single glyph of the glyf table
+#[derive(Debug, Clone, Default)]
+pub struct Glyph {
+    /// The number of contours, negative for composite glyphs
+    number_of_contours: i16,
+
+    /// The minimum x value of the glyph's bounding box
+    x_min: i16,
+
+    /// The minimum y value of the glyph's bounding box
+    y_min: i16,
+
+    /// The maximum x value of the glyph's bounding box
+    x_max: i16,
+
+    /// The maximum y value of the glyph's bounding box
+    y_max: i16,
+
+    /// The points of every contour, empty for composite glyphs
+    contours: Vec<Vec<Point>>,
+}
+
+impl Glyph {
+    /// Parses a glyph from it's bytes, as located by the loca table.
+    ///
+    /// An empty buffer is a glyph without an outline (e.g. a space).
+    /// Only simple glyphs have their outline parsed, composite glyphs
+    /// keep their header but have no contours.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the glyph is truncated or it's
+    /// contour end points aren't ascending.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::glyf::Glyph;
+    ///
+    /// // a single contour triangle, every point on the curve with word sized coordinates
+    /// let glyph = Glyph::from_buffer(&[
+    ///     0, 1, 0, 0, 0, 0, 0, 10, 0, 10, // header
+    ///     0, 2, // end points of contours
+    ///     0, 0, // no instructions
+    ///     1, 1, 1, // flags
+    ///     0, 0, 0, 10, 255, 246, // x deltas: 0, +10, -10
+    ///     0, 0, 0, 0, 0, 10, // y deltas: 0, 0, +10
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(glyph.contours().len(), 1);
+    /// assert_eq!(glyph.contours()[0][1].x, 10);
+    /// assert_eq!(glyph.contours()[0][2].y, 10);
+    /// ```
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        if buf.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let number_of_contours = be_i16(buf, 0)?;
+        let mut glyph = Self {
+            number_of_contours,
+            x_min: be_i16(buf, 2)?,
+            y_min: be_i16(buf, 4)?,
+            x_max: be_i16(buf, 6)?,
+            y_max: be_i16(buf, 8)?,
+            contours: Vec::new(),
+        };
+
+        if number_of_contours > 0 {
+            glyph.contours = parse_simple_outline(buf, number_of_contours as usize)?;
+        }
+
+        Ok(glyph)
+    }
+
+    /// Returns the number of contours, negative for composite glyphs
+    pub fn number_of_contours(&self) -> i16 {
+        self.number_of_contours
+    }
+
+    /// Checks if the glyph is a composite glyph, made out of other glyphs
+    pub fn is_composite(&self) -> bool {
+        self.number_of_contours < 0
+    }
+
+    /// Checks if the glyph has no outline at all, like the glyph of a space
+    pub fn is_empty(&self) -> bool {
+        self.number_of_contours == 0
+    }
+
+    /// Returns the number of contours of a simple glyph, 0 for composite glyphs
+    pub fn num_contours(&self) -> usize {
+        self.contours.len()
+    }
+
+    /// Returns the number of points of every contour combined, 0 for composite glyphs
+    pub fn num_points(&self) -> usize {
+        self.contours.iter().map(Vec::len).sum()
+    }
+
+    /// Returns the minimum x value of the glyph's bounding box
+    pub fn x_min(&self) -> i16 {
+        self.x_min
+    }
+
+    /// Returns the minimum y value of the glyph's bounding box
+    pub fn y_min(&self) -> i16 {
+        self.y_min
+    }
+
+    /// Returns the maximum x value of the glyph's bounding box
+    pub fn x_max(&self) -> i16 {
+        self.x_max
+    }
+
+    /// Returns the maximum y value of the glyph's bounding box
+    pub fn y_max(&self) -> i16 {
+        self.y_max
+    }
+
+    /// Returns the points of every contour, empty for composite glyphs
+    pub fn contours(&self) -> &[Vec<Point>] {
+        &self.contours
+    }
+
+    /// Applies an affine transform to every point of the outline, returning the
+    /// transformed points contour by contour.
+    ///
+    /// Control points are transformed along with the on-curve points, which keeps
+    /// the quadratic curves they describe intact since the transform is affine,
+    /// the on-curve flags of `Glyph::contours` still apply. To transform the
+    /// polylines of `Glyph::flatten` instead, map them through `Transform::apply`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::glyf::{Glyph, Transform};
+    ///
+    /// // a single contour triangle, every point on the curve with word sized coordinates
+    /// let glyph = Glyph::from_buffer(&[
+    ///     0, 1, 0, 0, 0, 0, 0, 10, 0, 10, // header
+    ///     0, 2, // end points of contours
+    ///     0, 0, // no instructions
+    ///     1, 1, 1, // flags
+    ///     0, 0, 0, 10, 255, 246, // x deltas: 0, +10, -10
+    ///     0, 0, 0, 0, 0, 10, // y deltas: 0, 0, +10
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     glyph.transformed(Transform::uniform_scale(0.5)),
+    ///     [[(0.0, 0.0), (5.0, 0.0), (0.0, 5.0)]]
+    /// );
+    /// // a raw matrix works too, this one mirrors the glyph horizontally
+    /// assert_eq!(
+    ///     glyph.transformed([-1.0, 0.0, 0.0, 1.0, 10.0, 0.0]),
+    ///     [[(10.0, 0.0), (0.0, 0.0), (10.0, 10.0)]]
+    /// );
+    /// ```
+    pub fn transformed(&self, transform: impl Into<Transform>) -> Vec<Vec<(f32, f32)>> {
+        let transform = transform.into();
+
+        self.contours
+            .iter()
+            .map(|contour| {
+                contour
+                    .iter()
+                    .map(|point| transform.apply((f32::from(point.x), f32::from(point.y))))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Flattens the outline into closed polylines, one per contour.
+    ///
+    /// Quadratic curves are subdivided until they deviate from the curve by at most
+    /// `tolerance` font units (capped at 64 segments per curve). Two consecutive
+    /// off-curve points imply an on-curve point halfway between them, and a contour
+    /// may start with an off-curve point, in which case it starts at the last point
+    /// (or the implied point between the last and the first). Every polyline ends
+    /// where it started.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::glyf::Glyph;
+    ///
+    /// // a contour of off-curve points only, every corner is implied
+    /// let glyph = Glyph::from_buffer(&[
+    ///     0, 1, 0, 0, 0, 0, 0, 10, 0, 10, // header
+    ///     0, 3, // end points of contours
+    ///     0, 0, // no instructions
+    ///     0, 0, 0, 0, // flags, all off-curve
+    ///     0, 0, 0, 10, 0, 0, 255, 246, // x deltas: 0, +10, 0, -10
+    ///     0, 0, 0, 0, 0, 10, 0, 0, // y deltas: 0, 0, +10, 0
+    /// ])
+    /// .unwrap();
+    ///
+    /// let polylines = glyph.flatten(100.0);
+    ///
+    /// // a generous tolerance leaves one segment per curve, between the implied points
+    /// assert_eq!(
+    ///     polylines,
+    ///     [[(0.0, 5.0), (5.0, 0.0), (10.0, 5.0), (5.0, 10.0), (0.0, 5.0)]]
+    /// );
+    /// ```
+    pub fn flatten(&self, tolerance: f32) -> Vec<Vec<(f32, f32)>> {
+        self.contours
+            .iter()
+            .filter(|contour| !contour.is_empty())
+            .map(|contour| flatten_contour(contour, tolerance))
+            .collect()
+    }
+
+    /// Renders the outline into a `width` by `height` grayscale coverage bitmap, one byte
+    /// per pixel in rows from the top, 0 where there's no ink and 255 for a fully covered pixel.
+    ///
+    /// The outline is scaled uniformly to fit the bitmap and centered in it. Contours are
+    /// filled with the nonzero winding rule like TrueType rasterizers do, so overlapping
+    /// contours stay filled and only counters wound the other way are left empty.
+    /// Every pixel is sampled on a 4 by 4 grid, which is plenty for previews and for
+    /// telling whether a glyph has any ink but isn't a production rasterizer (no hinting,
+    /// no exact area coverage).
+    ///
+    /// Composite glyphs (their outlines are in their components) and empty glyphs
+    /// render as a blank bitmap. A bitmap too large to allocate (`width * height`
+    /// past `isize::MAX` bytes) is returned empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::glyf::Glyph;
+    ///
+    /// // a 10 by 10 square
+    /// let glyph = Glyph::from_buffer(&[
+    ///     0, 1, 0, 0, 0, 0, 0, 10, 0, 10, // header
+    ///     0, 3, // end points of contours
+    ///     0, 0, // no instructions
+    ///     1, 1, 1, 1, // flags, all on-curve
+    ///     0, 0, 0, 10, 0, 0, 255, 246, // x deltas: 0, +10, 0, -10
+    ///     0, 0, 0, 0, 0, 10, 0, 0, // y deltas: 0, 0, +10, 0
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(glyph.rasterize(2, 2), [255; 4]);
+    /// // the square keeps it's aspect ratio and is centered
+    /// assert_eq!(glyph.rasterize(4, 2), [0, 255, 255, 0, 0, 255, 255, 0]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn rasterize(&self, width: u32, height: u32) -> Vec<u8> {
+        let (width, height) = (width as usize, height as usize);
+
+        // `width * height` can overflow on 32 bit platforms, and the samples of a row are counted too
+        let size = width
+            .checked_mul(height)
+            .filter(|&size| isize::try_from(size).is_ok())
+            .filter(|_| width.checked_mul(RASTER_SAMPLES).is_some());
+        let Some(size) = size else {
+            return Vec::new();
+        };
+        let mut bitmap = alloc::vec![0; size];
+
+        // The curves never leave the hull of their points, so the points bound the ink
+        let points = self.contours.iter().flatten();
+        let (Some(x_min), Some(x_max), Some(y_min), Some(y_max)) = (
+            points.clone().map(|point| point.x).min(),
+            points.clone().map(|point| point.x).max(),
+            points.clone().map(|point| point.y).min(),
+            points.map(|point| point.y).max(),
+        ) else {
+            return bitmap;
+        };
+
+        let glyph_width = f32::from(x_max) - f32::from(x_min);
+        let glyph_height = f32::from(y_max) - f32::from(y_min);
+
+        if bitmap.is_empty() || glyph_width == 0.0 || glyph_height == 0.0 {
+            return bitmap;
+        }
+
+        let scale = (width as f32 / glyph_width).min(height as f32 / glyph_height);
+        let offset_x = (width as f32 - glyph_width * scale) / 2.0;
+        let offset_y = (height as f32 - glyph_height * scale) / 2.0;
+
+        // Bitmap rows go down while font units go up
+        let to_pixels = |(x, y): (f32, f32)| {
+            (
+                (x - f32::from(x_min)) * scale + offset_x,
+                (f32::from(y_max) - y) * scale + offset_y,
+            )
+        };
+
+        // Horizontal edges never cross a scanline
+        let edges: Vec<_> = self
+            .flatten(0.25 / scale)
+            .iter()
+            .flat_map(|polyline| polyline.windows(2))
+            .map(|segment| (to_pixels(segment[0]), to_pixels(segment[1])))
+            .filter(|((_, y0), (_, y1))| y0 != y1)
+            .collect();
+
+        let samples = RASTER_SAMPLES as f32;
+        let mut coverage = alloc::vec![0u8; width];
+        let mut crossings = Vec::new();
+
+        for (row, pixels) in bitmap.chunks_exact_mut(width).enumerate() {
+            coverage.fill(0);
+
+            for sample_row in 0..RASTER_SAMPLES {
+                let y = row as f32 + (sample_row as f32 + 0.5) / samples;
+
+                crossings.clear();
+                for &((x0, y0), (x1, y1)) in &edges {
+                    // Each edge covers the half open range above it's lower end, a point
+                    // shared by two edges is only crossed once
+                    if (y0 <= y) == (y1 <= y) {
+                        continue;
+                    }
+
+                    let winding = if y1 > y0 { 1 } else { -1 };
+                    crossings.push((x0 + (y - y0) / (y1 - y0) * (x1 - x0), winding));
+                }
+                crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+                let mut winding = 0;
+                for pair in crossings.windows(2) {
+                    winding += pair[0].1;
+
+                    if winding == 0 {
+                        continue;
+                    }
+
+                    // The samples whose centers fall within the span
+                    let last = width * RASTER_SAMPLES;
+                    let from = ((pair[0].0 * samples - 0.5).ceil().max(0.0) as usize).min(last);
+                    let to = ((pair[1].0 * samples - 0.5).ceil().max(0.0) as usize).min(last);
+
+                    for sample in from..to {
+                        coverage[sample / RASTER_SAMPLES] += 1;
+                    }
+                }
+            }
+
+            for (pixel, count) in pixels.iter_mut().zip(&coverage) {
+                *pixel = (u32::from(*count) * 255 / (RASTER_SAMPLES * RASTER_SAMPLES) as u32) as u8;
+            }
+        }
+
+        bitmap
+    }
+}
+
+/// Parses the contours of a simple glyph, `buf` is the whole glyph
+fn parse_simple_outline(
+    buf: &[u8],
+    number_of_contours: usize,
+) -> Result<Vec<Vec<Point>>, TableEncodingError> {
+    // The end points array starts right after the 10 byte header
+    let end_points = (0..number_of_contours)
+        .map(|index| be_u16(buf, 10 + index * 2).map(usize::from))
+        .collect::<Result<Vec<usize>, TableEncodingError>>()?;
+
+    if end_points.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(TableEncodingError::MalformedGlyph(
+            "the contour end points aren't ascending",
+        ));
+    }
+
+    let num_points = end_points.last().map_or(0, |last| last + 1);
+    let instructions_length = usize::from(be_u16(buf, 10 + number_of_contours * 2)?);
+    let mut at = 12 + number_of_contours * 2 + instructions_length;
+
+    // Flags are run length encoded, a flag with the repeat bit is followed by a repeat count
+    let mut flags = Vec::with_capacity(num_points);
+
+    while flags.len() < num_points {
+        let flag = *buf
+            .get(at)
+            .ok_or(TableEncodingError::InvalidBufferLength(at + 1, buf.len()))?;
+        at += 1;
+
+        let mut repeat = 1;
+
+        if flag & REPEAT_FLAG != 0 {
+            repeat += usize::from(
+                *buf.get(at)
+                    .ok_or(TableEncodingError::InvalidBufferLength(at + 1, buf.len()))?,
+            );
+            at += 1;
+        }
+
+        flags.extend(core::iter::repeat_n(
+            flag,
+            repeat.min(num_points - flags.len()),
+        ));
+    }
+
+    let xs = read_coordinates(buf, &mut at, &flags, X_SHORT_VECTOR, X_IS_SAME_OR_POSITIVE)?;
+    let ys = read_coordinates(buf, &mut at, &flags, Y_SHORT_VECTOR, Y_IS_SAME_OR_POSITIVE)?;
+
+    let mut points = flags
+        .iter()
+        .zip(xs.into_iter().zip(ys))
+        .map(|(flag, (x, y))| Point {
+            x,
+            y,
+            on_curve: flag & ON_CURVE_POINT != 0,
+        });
+
+    let mut start = 0;
+
+    Ok(end_points
+        .iter()
+        .map(|end| {
+            let contour = points.by_ref().take(end + 1 - start).collect();
+            start = end + 1;
+            contour
+        })
+        .collect())
+}
+
+/// Walks the component records of a composite glyph, `buf` is the whole glyph
+fn component_ids(buf: &[u8]) -> Result<Vec<u16>, TableEncodingError> {
+    let mut ids = Vec::new();
+    // The component records start right after the 10 byte header
+    let mut at = 10;
+
+    loop {
+        let flags = be_u16(buf, at)?;
+        ids.push(be_u16(buf, at + 2)?);
+
+        at += component_record_len(flags);
+
+        if at > buf.len() {
+            return Err(TableEncodingError::InvalidBufferLength(at, buf.len()));
+        }
+
+        if flags & MORE_COMPONENTS == 0 {
+            return Ok(ids);
+        }
+    }
+}
+
+/// Reads one axis of delta encoded coordinates, returning the absolute coordinates
+fn read_coordinates(
+    buf: &[u8],
+    at: &mut usize,
+    flags: &[u8],
+    short_vector: u8,
+    same_or_positive: u8,
+) -> Result<Vec<i16>, TableEncodingError> {
+    let mut value = 0i16;
+
+    flags
+        .iter()
+        .map(|flag| {
+            let delta = match (flag & short_vector != 0, flag & same_or_positive != 0) {
+                (true, positive) => {
+                    let byte = *buf
+                        .get(*at)
+                        .ok_or(TableEncodingError::InvalidBufferLength(*at + 1, buf.len()))?;
+                    *at += 1;
+
+                    match positive {
+                        true => i16::from(byte),
+                        false => -i16::from(byte),
+                    }
+                }
+                (false, true) => 0,
+                (false, false) => {
+                    let delta = be_i16(buf, *at)?;
+                    *at += 2;
+                    delta
+                }
+            };
+
+            value = value.wrapping_add(delta);
+            Ok(value)
+        })
+        .collect()
+}
+
+/// Flattens a single non-empty contour into a closed polyline
+fn flatten_contour(contour: &[Point], tolerance: f32) -> Vec<(f32, f32)> {
+    let as_f32 = |point: &Point| (f32::from(point.x), f32::from(point.y));
+    let first = &contour[0];
+    let last = &contour[contour.len() - 1];
+
+    // Find an on-curve point to start from, when the first point is a control point
+    // the contour starts at the last point or at the point implied between the two
+    let (start, rest) = match (first.on_curve, last.on_curve) {
+        (true, _) => (as_f32(first), &contour[1..]),
+        (false, true) => (as_f32(last), &contour[..contour.len() - 1]),
+        (false, false) => (midpoint(as_f32(last), as_f32(first)), contour),
+    };
+
+    let mut polyline = Vec::from([start]);
+    let mut current = start;
+    let mut control = None;
+
+    for point in rest {
+        let position = as_f32(point);
+
+        match (point.on_curve, control) {
+            (true, Some(ctrl)) => {
+                flatten_quadratic(&mut polyline, current, ctrl, position, tolerance);
+                current = position;
+                control = None;
+            }
+            (true, None) => {
+                polyline.push(position);
+                current = position;
+            }
+            // Two control points in a row imply an on-curve point halfway between them
+            (false, Some(ctrl)) => {
+                let implied = midpoint(ctrl, position);
+                flatten_quadratic(&mut polyline, current, ctrl, implied, tolerance);
+                current = implied;
+                control = Some(position);
+            }
+            (false, None) => control = Some(position),
+        }
+    }
+
+    // Close the contour back to where it started
+    match control {
+        Some(ctrl) => flatten_quadratic(&mut polyline, current, ctrl, start, tolerance),
+        None => polyline.push(start),
+    }
+
+    polyline
+}
+
+/// Appends the quadratic curve from `from` to `to` as line segments, `from` is
+/// expected to be the last point of the polyline already
+fn flatten_quadratic(
+    polyline: &mut Vec<(f32, f32)>,
+    from: (f32, f32),
+    control: (f32, f32),
+    to: (f32, f32),
+    tolerance: f32,
+) {
+    // A curve split into n segments deviates from it by at most |from - 2 * control + to| / (4 * n^2),
+    // compared squared to stay clear of float functions which aren't available without std
+    let dx = from.0 - 2.0 * control.0 + to.0;
+    let dy = from.1 - 2.0 * control.1 + to.1;
+    let deviation_squared = dx * dx + dy * dy;
+
+    let mut segments = 1;
+
+    while segments < MAX_CURVE_SEGMENTS {
+        let bound = 4.0 * tolerance * (segments * segments) as f32;
+
+        if tolerance > 0.0 && deviation_squared <= bound * bound {
+            break;
+        }
+
+        segments += 1;
+    }
+
+    for step in 1..=segments {
+        let t = step as f32 / segments as f32;
+        let mt = 1.0 - t;
+
+        polyline.push((
+            mt * mt * from.0 + 2.0 * mt * t * control.0 + t * t * to.0,
+            mt * mt * from.1 + 2.0 * mt * t * control.1 + t * t * to.1,
+        ));
+    }
+}
+
+/// Returns the point halfway between `a` and `b`
+fn midpoint(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0)
+}
+
+/// Returns the length of a component record (including it's flags and glyph index)
+/// given it's flags
+pub(crate) fn component_record_len(flags: u16) -> usize {
+    let arguments = match flags & ARG_1_AND_2_ARE_WORDS != 0 {
+        true => 4,
+        false => 2,
+    };
+
+    let transform = if flags & WE_HAVE_A_SCALE != 0 {
+        2
+    } else if flags & WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+        4
+    } else if flags & WE_HAVE_A_TWO_BY_TWO != 0 {
+        8
+    } else {
+        0
+    };
+
+    4 + arguments + transform
+}
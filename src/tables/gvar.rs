@@ -0,0 +1,342 @@
+use alloc::vec::Vec;
+
+use crate::{shared::SharedBytes, tag::Tag};
+
+use super::{Table, TableEncodingError, be_i16, be_u16, be_u32, f2dot14_to_f32};
+
+/// The header flag marking the glyph variation data offsets as 32 bit
+const LONG_OFFSETS: u16 = 0x0001;
+
+/// The tuple variation count flag marking point numbers shared by all the tuples of a glyph
+const SHARED_POINT_NUMBERS: u16 = 0x8000;
+
+/// The mask of the actual number of tuples within the tuple variation count
+const TUPLE_COUNT_MASK: u16 = 0x0FFF;
+
+/// The tuple index flag marking a peak tuple embedded in the tuple variation header
+const EMBEDDED_PEAK_TUPLE: u16 = 0x8000;
+
+/// The tuple index flag marking an intermediate region embedded in the tuple variation header
+const INTERMEDIATE_REGION: u16 = 0x4000;
+
+/// The tuple index flag marking point numbers private to the tuple
+const PRIVATE_POINT_NUMBERS: u16 = 0x2000;
+
+/// The mask of the shared tuple index within the tuple index
+const TUPLE_INDEX_MASK: u16 = 0x0FFF;
+
+/// A representation of the [gvar table](https://learn.microsoft.com/en-us/typography/opentype/spec/gvar)
+/// which holds the variations of the glyph outlines of a variable font.
+///
+/// Only the header, the shared tuples and the tuple variation headers are parsed,
+/// the packed point numbers and deltas are left out.
+#[derive(Debug)]
+pub struct Gvar {
+    /// The major version of the gvar table, set to 1
+    major_version: u16,
+
+    /// The minor version of the gvar table, set to 0
+    minor_version: u16,
+
+    /// The number of variation axes, which should match the fvar table
+    axis_count: u16,
+
+    /// The peak tuples the tuple variation headers may refer to by index
+    shared_tuples: Vec<Vec<f32>>,
+
+    /// The offsets of the glyph variation data from the start of the table, one more than there are glyphs
+    offsets: Vec<u32>,
+
+    /// The raw table, the glyph variation data is parsed on demand
+    data: SharedBytes,
+}
+
+impl Table for Gvar {
+    const TAG: Tag = Tag::new(*b"gvar");
+
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        Self::from_shared(buf.into())
+    }
+
+    fn parse_shared(data: &SharedBytes) -> Result<Self, TableEncodingError> {
+        Self::from_shared(data.clone())
+    }
+}
+
+impl Gvar {
+    /// Parses the header, the shared tuples and the glyph variation data offsets.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the header, the shared tuples or the
+    /// offsets lie outside of the table.
+    fn from_shared(data: SharedBytes) -> Result<Self, TableEncodingError> {
+        let buf: &[u8] = &data;
+
+        let major_version = be_u16(buf, 0)?;
+        let minor_version = be_u16(buf, 2)?;
+        let axis_count = be_u16(buf, 4)?;
+        let shared_tuple_count = be_u16(buf, 6)?;
+        let shared_tuples_offset = be_u32(buf, 8)? as usize;
+        let glyph_count = be_u16(buf, 12)?;
+        let flags = be_u16(buf, 14)?;
+        let array_offset = be_u32(buf, 16)?;
+
+        let shared_tuples = (0..usize::from(shared_tuple_count))
+            .map(|index| {
+                let at = shared_tuples_offset + index * usize::from(axis_count) * 2;
+                read_tuple(buf, at, axis_count)
+            })
+            .collect::<Result<Vec<Vec<f32>>, TableEncodingError>>()?;
+
+        let offsets = (0..=usize::from(glyph_count))
+            .map(|index| {
+                let offset = if flags & LONG_OFFSETS != 0 {
+                    be_u32(buf, 20 + index * 4)?
+                } else {
+                    u32::from(be_u16(buf, 20 + index * 2)?) * 2
+                };
+
+                Ok(array_offset.saturating_add(offset))
+            })
+            .collect::<Result<Vec<u32>, TableEncodingError>>()?;
+
+        Ok(Self {
+            major_version,
+            minor_version,
+            axis_count,
+            shared_tuples,
+            offsets,
+            data,
+        })
+    }
+
+    /// Returns the major version of the gvar table
+    pub fn major_version(&self) -> u16 {
+        self.major_version
+    }
+
+    /// Returns the minor version of the gvar table
+    pub fn minor_version(&self) -> u16 {
+        self.minor_version
+    }
+
+    /// Returns the number of variation axes, which should match the fvar table
+    pub fn axis_count(&self) -> u16 {
+        self.axis_count
+    }
+
+    /// Returns the number of shared tuples
+    pub fn shared_tuple_count(&self) -> usize {
+        self.shared_tuples.len()
+    }
+
+    /// Returns the shared peak tuples, one coordinate per axis in the range -1.0 to 1.0
+    pub fn shared_tuples(&self) -> &[Vec<f32>] {
+        &self.shared_tuples
+    }
+
+    /// Returns the number of glyphs the table holds variation data for
+    pub fn glyph_count(&self) -> u16 {
+        // there's one more offset than there are glyphs
+        (self.offsets.len() - 1) as u16
+    }
+
+    /// Checks if the glyph with the given id has variation data,
+    /// out of bounds glyph ids have none
+    pub fn has_variation_data(&self, glyph_id: u16) -> bool {
+        self.glyph_range(glyph_id)
+            .is_some_and(|(start, end)| start < end)
+    }
+
+    /// Returns the ids of the glyphs which have variation data, in ascending order
+    pub fn varying_glyphs(&self) -> Vec<u16> {
+        (0..self.glyph_count())
+            .filter(|glyph_id| self.has_variation_data(*glyph_id))
+            .collect()
+    }
+
+    /// Parses the tuple variation headers of the glyph with the given id.
+    ///
+    /// Returns `Ok(None)` if the glyph id is out of bounds or the glyph has no variation data.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the glyph variation data lies outside of
+    /// the table or it's tuple variation headers are truncated.
+    pub fn glyph_variation_data(
+        &self,
+        glyph_id: u16,
+    ) -> Result<Option<GlyphVariationData>, TableEncodingError> {
+        let Some((start, end)) = self.glyph_range(glyph_id) else {
+            return Ok(None);
+        };
+
+        if start >= end {
+            return Ok(None);
+        }
+
+        let buf = self.data.get(start as usize..end as usize).ok_or(
+            TableEncodingError::InvalidBufferLength(end as usize, self.data.len()),
+        )?;
+
+        GlyphVariationData::from_buffer(buf, self.axis_count).map(Some)
+    }
+
+    /// Returns the start and the end of the glyph variation data of a glyph
+    fn glyph_range(&self, glyph_id: u16) -> Option<(u32, u32)> {
+        let index = usize::from(glyph_id);
+
+        Some((*self.offsets.get(index)?, *self.offsets.get(index + 1)?))
+    }
+}
+
+/// The tuple variation headers of a single glyph
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlyphVariationData {
+    /// Whether the packed point numbers shared by all the tuples precede the deltas
+    shared_point_numbers: bool,
+
+    /// The offset of the packed point numbers and deltas from the start of the glyph variation data
+    data_offset: u16,
+
+    /// The tuple variation headers in the order the glyph declares them
+    tuples: Vec<TupleVariationHeader>,
+}
+
+impl GlyphVariationData {
+    /// Parses the tuple variation headers of the glyph variation data in `buf`
+    fn from_buffer(buf: &[u8], axis_count: u16) -> Result<Self, TableEncodingError> {
+        let tuple_count = be_u16(buf, 0)?;
+        let data_offset = be_u16(buf, 2)?;
+
+        let mut at = 4;
+        let tuples = (0..tuple_count & TUPLE_COUNT_MASK)
+            .map(|_| {
+                let (tuple, size) = TupleVariationHeader::from_buffer(buf, at, axis_count)?;
+                at += size;
+                Ok(tuple)
+            })
+            .collect::<Result<Vec<TupleVariationHeader>, TableEncodingError>>()?;
+
+        Ok(Self {
+            shared_point_numbers: tuple_count & SHARED_POINT_NUMBERS != 0,
+            data_offset,
+            tuples,
+        })
+    }
+
+    /// Checks if packed point numbers shared by all the tuples precede the deltas
+    pub fn has_shared_point_numbers(&self) -> bool {
+        self.shared_point_numbers
+    }
+
+    /// Returns the offset of the packed point numbers and deltas from the start of the glyph variation data
+    pub fn data_offset(&self) -> u16 {
+        self.data_offset
+    }
+
+    /// Returns the number of tuple variations
+    pub fn tuple_count(&self) -> usize {
+        self.tuples.len()
+    }
+
+    /// Returns the tuple variation headers in the order the glyph declares them
+    pub fn tuples(&self) -> &[TupleVariationHeader] {
+        &self.tuples
+    }
+}
+
+/// A tuple variation header, which tells the region of the variation space
+/// a set of deltas applies to
+#[derive(Debug, Clone, PartialEq)]
+pub struct TupleVariationHeader {
+    /// The size in bytes of the serialized point numbers and deltas of the tuple
+    variation_data_size: u16,
+
+    /// The raw tuple index, the flags in the high bits and the shared tuple index in the low bits
+    tuple_index: u16,
+
+    /// The peak tuple, if it's embedded rather than shared
+    peak_tuple: Option<Vec<f32>>,
+
+    /// The start and end tuples of the intermediate region, if it has one
+    intermediate_region: Option<(Vec<f32>, Vec<f32>)>,
+}
+
+impl TupleVariationHeader {
+    /// Parses the tuple variation header starting at `at`, returns it along with it's size in bytes
+    fn from_buffer(
+        buf: &[u8],
+        at: usize,
+        axis_count: u16,
+    ) -> Result<(Self, usize), TableEncodingError> {
+        let variation_data_size = be_u16(buf, at)?;
+        let tuple_index = be_u16(buf, at + 2)?;
+        let tuple_size = usize::from(axis_count) * 2;
+        let mut size = 4;
+
+        let peak_tuple = if tuple_index & EMBEDDED_PEAK_TUPLE != 0 {
+            let tuple = read_tuple(buf, at + size, axis_count)?;
+            size += tuple_size;
+            Some(tuple)
+        } else {
+            None
+        };
+
+        let intermediate_region = if tuple_index & INTERMEDIATE_REGION != 0 {
+            let start = read_tuple(buf, at + size, axis_count)?;
+            let end = read_tuple(buf, at + size + tuple_size, axis_count)?;
+            size += tuple_size * 2;
+            Some((start, end))
+        } else {
+            None
+        };
+
+        let header = Self {
+            variation_data_size,
+            tuple_index,
+            peak_tuple,
+            intermediate_region,
+        };
+
+        Ok((header, size))
+    }
+
+    /// Returns the size in bytes of the serialized point numbers and deltas of the tuple
+    pub fn variation_data_size(&self) -> u16 {
+        self.variation_data_size
+    }
+
+    /// Returns the index of the shared peak tuple, `None` if the peak tuple is embedded
+    pub fn shared_tuple_index(&self) -> Option<u16> {
+        match self.tuple_index & EMBEDDED_PEAK_TUPLE {
+            0 => Some(self.tuple_index & TUPLE_INDEX_MASK),
+            _ => None,
+        }
+    }
+
+    /// Returns the embedded peak tuple, `None` if the tuple refers to a shared one
+    pub fn peak_tuple(&self) -> Option<&[f32]> {
+        self.peak_tuple.as_deref()
+    }
+
+    /// Returns the start and end tuples of the intermediate region, if it has one
+    pub fn intermediate_region(&self) -> Option<(&[f32], &[f32])> {
+        self.intermediate_region
+            .as_ref()
+            .map(|(start, end)| (start.as_slice(), end.as_slice()))
+    }
+
+    /// Checks if the tuple has point numbers of it's own rather than the shared ones
+    pub fn has_private_point_numbers(&self) -> bool {
+        self.tuple_index & PRIVATE_POINT_NUMBERS != 0
+    }
+}
+
+/// Reads a tuple of `axis_count` F2DOT14 coordinates starting at `at`
+fn read_tuple(buf: &[u8], at: usize, axis_count: u16) -> Result<Vec<f32>, TableEncodingError> {
+    (0..usize::from(axis_count))
+        .map(|axis| be_i16(buf, at + axis * 2).map(f2dot14_to_f32))
+        .collect()
+}
@@ -0,0 +1,150 @@
+#[cfg(feature = "std")]
+use std::io::{Read, Seek};
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::{VeroTypeError, buffer::VeroBufReader};
+
+#[cfg(feature = "std")]
+use super::TableMetadata;
+use super::{TableEncodingError, be_u16, be_u32};
+
+/// A representation of the [hdmx table](https://learn.microsoft.com/en-us/typography/opentype/spec/hdmx)
+/// which stores the hinted advance widths of every glyph at specific pixel sizes
+#[derive(Debug)]
+pub struct Hdmx {
+    /// The version of the hdmx table, set to 0
+    version: u16,
+
+    /// The size in bytes of a device record, padded to a multiple of 4
+    record_size: u32,
+
+    /// The device records, one per pixel size
+    records: Vec<DeviceRecord>,
+}
+
+impl Hdmx {
+    /// Constructs a `Hdmx` instance by reading data from the provided `VeroBufReader`,
+    /// see `Hdmx::from_buffer`.
+    #[cfg(feature = "std")]
+    pub fn from_reader<B: Read + Seek>(
+        reader: &mut VeroBufReader<B>,
+        metadata: &TableMetadata,
+        num_glyphs: u16,
+    ) -> Result<Self, VeroTypeError> {
+        let buf = metadata.read_bytes(reader)?;
+
+        Ok(Self::from_buffer(&buf, num_glyphs)?)
+    }
+
+    /// Constructs the hdmx table from the raw table bytes,
+    /// `num_glyphs` comes from the maxp table.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::MalformedHdmx` if the record count is negative
+    /// or the declared record size can't hold a width for every glyph, and a
+    /// `TableEncodingError::InvalidBufferLength` if a record lies outside of the table.
+    pub fn from_buffer(buf: &[u8], num_glyphs: u16) -> Result<Self, TableEncodingError> {
+        let version = be_u16(buf, 0)?;
+        // the record count is signed but a negative count makes no sense
+        let num_records = usize::try_from(be_u16(buf, 2)? as i16).map_err(|_| {
+            TableEncodingError::MalformedHdmx("the device record count is negative")
+        })?;
+        let record_size = be_u32(buf, 4)?;
+
+        let needed = usize::from(num_glyphs) + 2;
+        if (record_size as usize) < needed {
+            return Err(TableEncodingError::MalformedHdmx(
+                "a device record is too small to hold a width for every glyph",
+            ));
+        }
+
+        let records = (0..num_records)
+            .map(|index| {
+                let start = 8 + index * record_size as usize;
+                let end = start + needed;
+
+                let record = buf
+                    .get(start..end)
+                    .ok_or(TableEncodingError::InvalidBufferLength(end, buf.len()))?;
+
+                Ok(DeviceRecord {
+                    pixel_size: record[0],
+                    max_width: record[1],
+                    widths: record[2..].to_vec(),
+                })
+            })
+            .collect::<Result<Vec<DeviceRecord>, TableEncodingError>>()?;
+
+        Ok(Self {
+            version,
+            record_size,
+            records,
+        })
+    }
+
+    /// Returns the version of the hdmx table
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the size in bytes of a device record, padded to a multiple of 4
+    pub fn record_size(&self) -> u32 {
+        self.record_size
+    }
+
+    /// Returns the device records in the order the table stores them
+    pub fn records(&self) -> &[DeviceRecord] {
+        &self.records
+    }
+
+    /// Returns the device record for the given pixel size, if the table has one
+    pub fn record(&self, ppem: u8) -> Option<&DeviceRecord> {
+        self.records.iter().find(|record| record.pixel_size == ppem)
+    }
+
+    /// Returns the hinted advance width in pixels of a glyph at the given pixel size.
+    ///
+    /// Returns `None` if the table has no record for the pixel size
+    /// or the glyph id is out of bounds, scale the hmtx advance instead.
+    pub fn advance_at_ppem(&self, glyph_id: u16, ppem: u8) -> Option<u8> {
+        self.record(ppem)?.advance_width(glyph_id)
+    }
+}
+
+/// The hinted advance widths of every glyph at a single pixel size
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceRecord {
+    /// The pixel size the widths are for
+    pixel_size: u8,
+
+    /// The largest of the widths
+    max_width: u8,
+
+    /// The advance widths in pixels, indexed by glyph id
+    widths: Vec<u8>,
+}
+
+impl DeviceRecord {
+    /// Returns the pixel size the widths are for
+    pub fn pixel_size(&self) -> u8 {
+        self.pixel_size
+    }
+
+    /// Returns the largest of the widths
+    pub fn max_width(&self) -> u8 {
+        self.max_width
+    }
+
+    /// Returns the advance widths in pixels, indexed by glyph id
+    pub fn widths(&self) -> &[u8] {
+        &self.widths
+    }
+
+    /// Returns the advance width in pixels of a glyph, `None` if the glyph id is out of bounds
+    pub fn advance_width(&self, glyph_id: u16) -> Option<u8> {
+        self.widths.get(usize::from(glyph_id)).copied()
+    }
+}
@@ -1,8 +1,17 @@
-use std::io::{Read, Seek};
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
 
-use crate::{VeroTypeError, buffer::VeroBufReader};
+use crate::tag::Tag;
 
-use super::TableMetadata;
+use super::{Table, TableEncodingError, be_i16, be_i64, be_u16, be_u32};
+
+/// The magic number every head table carries, since it's not a palindrome
+/// it doubles as a canary for the byte order, read little-endian it would be 0xF53C0F5F.
+pub const HEAD_MAGIC_NUMBER: u32 = 0x5F0F3CF5;
+
+/// The only version of the head table, 1.0 as a 16.16 fixed point number.
+/// Every field is read with it's layout, whatever the version says.
+pub const HEAD_VERSION: u32 = 0x0001_0000;
 
 /// Represents the flags field of the 'head' table in a TrueType font file.
 /// Each field corresponds to a specific bit in the 16-bit flags value.
@@ -78,6 +87,25 @@ impl HeadFlags {
         ((self.bits & 0b0011_1000_0000_0000) >> 11) as u8
     }
 
+    /// Checks if the font data is lossless after an optimizing transformation
+    /// or compression, such as WOFF2's (bit 11). Microsoft's meaning of the
+    /// first Adobe-defined bit, Apple leaves it to Adobe.
+    pub fn lossless_font_data(&self) -> bool {
+        (self.bits & 0b0000_1000_0000_0000) != 0
+    }
+
+    /// Checks if the font was converted to produce compatible metrics (bit 12).
+    /// Microsoft's meaning of the second Adobe-defined bit, Apple leaves it to Adobe.
+    pub fn converted_font(&self) -> bool {
+        (self.bits & 0b0001_0000_0000_0000) != 0
+    }
+
+    /// Checks if the font is optimized for ClearType (bit 13). Microsoft's meaning
+    /// of the third Adobe-defined bit, Apple leaves it to Adobe.
+    pub fn optimized_for_cleartype(&self) -> bool {
+        (self.bits & 0b0010_0000_0000_0000) != 0
+    }
+
     /// Checks if the glyphs are generic symbols for code point ranges (bit 14).
     pub fn generic_symbol_font(&self) -> bool {
         (self.bits & 0b0100_0000_0000_0000) != 0
@@ -87,11 +115,162 @@ impl HeadFlags {
     pub fn bits(&self) -> u16 {
         self.bits
     }
+
+    /// Returns the names of the set flags in bit order, named after their accessors.
+    /// The Adobe defined bits are listed one by one, as `lossless_font_data` (bit 11),
+    /// `converted_font` (bit 12) and `optimized_for_cleartype` (bit 13).
+    ///
+    /// Every bit either specification defines is listed, see `HeadFlags::active_for`
+    /// to read the flags the way one of them does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::head::HeadFlags;
+    ///
+    /// assert_eq!(
+    ///     HeadFlags::from_bits(0x000B).active(),
+    ///     [
+    ///         "y_value_zero_is_baseline",
+    ///         "x_pos_leftmost_black_bit_lsb",
+    ///         "use_integer_scaling",
+    ///     ]
+    /// );
+    /// ```
+    pub fn active(&self) -> Vec<&'static str> {
+        [
+            ("y_value_zero_is_baseline", self.y_value_zero_is_baseline()),
+            (
+                "x_pos_leftmost_black_bit_lsb",
+                self.x_pos_leftmost_black_bit_lsb(),
+            ),
+            (
+                "scaled_point_size_differs",
+                self.scaled_point_size_differs(),
+            ),
+            ("use_integer_scaling", self.use_integer_scaling()),
+            ("microsoft_scaler_flag", self.microsoft_scaler_flag()),
+            ("vertical_layout", self.vertical_layout()),
+            ("must_be_zero", self.must_be_zero()),
+            (
+                "requires_linguistic_layout",
+                self.requires_linguistic_layout(),
+            ),
+            (
+                "aat_default_metamorphosis",
+                self.aat_default_metamorphosis(),
+            ),
+            ("strong_rtl_glyphs", self.strong_rtl_glyphs()),
+            ("indic_rearrangement", self.indic_rearrangement()),
+            ("lossless_font_data", self.lossless_font_data()),
+            ("converted_font", self.converted_font()),
+            ("optimized_for_cleartype", self.optimized_for_cleartype()),
+            ("generic_symbol_font", self.generic_symbol_font()),
+        ]
+        .into_iter()
+        .filter_map(|(name, set)| set.then_some(name))
+        .collect()
+    }
+
+    /// Returns the names of the set flags in bit order, the way `spec` defines them.
+    ///
+    /// The specifications agree on bits 0 to 4 and on bit 14, which Microsoft
+    /// calls the last resort font bit. Bits 5 to 10 are Apple's (vertical layout,
+    /// AAT metamorphosis, ...), Microsoft asks for them to be set the same way but
+    /// OpenType doesn't implement them so they're left out of it's list. Bits 11
+    /// to 13 are defined by Microsoft (`lossless_font_data`, `converted_font` and
+    /// `optimized_for_cleartype`) while Apple only reserves them for Adobe, they're
+    /// listed once as `adobe_defined` when any of them is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::head::{FlagSpec, HeadFlags};
+    ///
+    /// // vertical layout and optimized for ClearType
+    /// let flags = HeadFlags::from_bits(0x2020);
+    ///
+    /// assert_eq!(flags.active_for(FlagSpec::Apple), ["vertical_layout", "adobe_defined"]);
+    /// assert_eq!(flags.active_for(FlagSpec::Microsoft), ["optimized_for_cleartype"]);
+    /// ```
+    pub fn active_for(&self, spec: FlagSpec) -> Vec<&'static str> {
+        let mut active = self.active();
+
+        match spec {
+            FlagSpec::Apple => {
+                let adobe_defined = [
+                    "lossless_font_data",
+                    "converted_font",
+                    "optimized_for_cleartype",
+                ];
+
+                active.retain(|name| !adobe_defined.contains(name));
+                if self.adobe_defined() != 0 {
+                    // bit 14 is the only flag above the Adobe-defined bits
+                    let at = active.len() - usize::from(self.generic_symbol_font());
+                    active.insert(at, "adobe_defined");
+                }
+            }
+            FlagSpec::Microsoft => {
+                active.retain(|name| {
+                    ![
+                        "vertical_layout",
+                        "must_be_zero",
+                        "requires_linguistic_layout",
+                        "aat_default_metamorphosis",
+                        "strong_rtl_glyphs",
+                        "indic_rearrangement",
+                    ]
+                    .contains(name)
+                });
+            }
+        }
+
+        active
+    }
+}
+
+/// The specification to interpret the head flags by, see `HeadFlags::active_for`.
+///
+/// Apple's TrueType reference and Microsoft's OpenType specification share the
+/// head table but not the meaning of every flag, which matters when matching the
+/// behavior of one of their rasterizers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlagSpec {
+    /// Apple's TrueType reference manual
+    Apple,
+
+    /// Microsoft's OpenType specification
+    Microsoft,
 }
 
+/// The format of the offsets in the loca table, decoded from `Head::index_to_loc_format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LocaFormat {
+    /// Offsets are stored as `u16` values divided by two
+    Short,
+    /// Offsets are stored as `u32` values
+    Long,
+    /// Any value other than 0 or 1, which the spec doesn't define
+    Unknown(i16),
+}
+
+impl From<i16> for LocaFormat {
+    fn from(value: i16) -> Self {
+        match value {
+            0 => Self::Short,
+            1 => Self::Long,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+/// The legal range of `Head::units_per_em`
+pub const UNITS_PER_EM_RANGE: RangeInclusive<u16> = 16..=16384;
+
 /// A representation of the [head table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6head.html)
 /// including methods to extract it's values safely and efficiently
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct Head {
     /// The version of the head table
     /// it's almost guarenteed to be set to version 0x00010000
@@ -152,78 +331,102 @@ pub struct Head {
     glyph_data_format: i16,
 }
 
-impl Head {
-    /// Constructs a `Head` instance by reading data from the provided `VeroBufReader`.
-    ///
-    /// This method takes a mutable reference to a `VeroBufReader` and a `TableMetadata`
-    /// struct. The `TableMetadata` provides the offset and length of the 'head' table
-    /// within the font file.
-    ///
-    /// The method seeks to the beginning of the 'head' table data using the offset
-    /// from the metadata, reads the specified number of bytes into a buffer, and then
-    /// parses this buffer to populate the fields of the `Head` struct.
-    ///
-    /// # Arguments
-    ///
-    /// * `reader`: A mutable reference to a `VeroBufReader` that provides access to the font file data.
-    /// * `metadata`: A reference to a `TableMetadata` struct containing the offset and length of the 'head' table.
+impl Table for Head {
+    const TAG: Tag = Tag::new(*b"head");
+
+    /// Parses the head table from it's raw bytes.
     ///
     /// # Errors
     ///
-    /// This method can return a `VeroTypeError` in the following cases:
+    /// Returns a `TableEncodingError::InvalidBufferLength` if the table is shorter than 54 bytes,
+    /// or a `TableEncodingError::InvalidUnitsPerEm` if the units per em are outside of
+    /// `UNITS_PER_EM_RANGE` since every scaling calculation divides by them.
     ///
-    /// * **Seeking Error:** If an error occurs while seeking to the specified offset in the `reader`
-    ///    (wrapped as `VeroTypeError::IoError`).
-    /// * **Reading Error:** If an error occurs while reading the 'head' table data from the `reader`
-    ///    (wrapped as `VeroTypeError::IoError`). This could happen if the end of the file is reached
-    ///    before the expected number of bytes are read.
-    /// * **Data Conversion Error:** If an error occurs during the conversion of the byte slices
-    ///    to the expected data types (e.g., `u32`, `u16`, `i64`, `i16`). Note that the `unwrap()`
-    ///    calls on `try_into()` will panic if the slice lengths are incorrect, which should be
-    ///    prevented by the `metadata.length` check. However, underlying `from_be_bytes` errors
-    ///    could potentially occur.
-    ///
-    /// # Returns
-    ///
-    /// A `Result` containing:
-    ///
-    /// * `Ok(Self)`: A new `Head` instance populated with the data read from the `reader`.
-    /// * `Err(VeroTypeError)`: An error that occurred during the process.
-    pub(crate) fn from_reader<B: Read + Seek>(
-        reader: &mut VeroBufReader<B>,
-        metadata: &TableMetadata,
-    ) -> Result<Self, VeroTypeError> {
-        reader.seek_to(metadata.offset.into())?;
-        let mut buf = vec![0u8; metadata.length as usize];
+    /// A version other than `HEAD_VERSION` is still parsed with the 1.0 layout, see
+    /// `Head::is_standard_version`, and is logged as a warning with the `tracing` feature.
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        if buf.len() < 54 {
+            return Err(TableEncodingError::InvalidBufferLength(54, buf.len()));
+        }
+
+        let version = be_u32(buf, 0)?;
 
-        reader.read_exact(&mut buf)?;
+        #[cfg(feature = "tracing")]
+        if version != HEAD_VERSION {
+            tracing::warn!(
+                version = format_args!("{version:#010x}"),
+                "unexpected head table version, parsed as version 1.0"
+            );
+        }
+
+        let units_per_em = be_u16(buf, 18)?;
+
+        if !UNITS_PER_EM_RANGE.contains(&units_per_em) {
+            return Err(TableEncodingError::InvalidUnitsPerEm(units_per_em));
+        }
 
         Ok(Self {
-            version: u32::from_be_bytes(buf[0..4].try_into()?),
-            font_revision: u32::from_be_bytes(buf[4..8].try_into()?),
-            checksum_adjustment: u32::from_be_bytes(buf[8..12].try_into()?),
-            magic_number: u32::from_be_bytes(buf[12..16].try_into()?),
-            flags: HeadFlags::from_bits(u16::from_be_bytes(buf[16..18].try_into()?)),
-            units_per_em: u16::from_be_bytes(buf[18..20].try_into()?),
-            created: i64::from_be_bytes(buf[20..28].try_into()?),
-            modified: i64::from_be_bytes(buf[28..36].try_into()?),
-            x_min: i16::from_be_bytes(buf[36..38].try_into()?),
-            y_min: i16::from_be_bytes(buf[38..40].try_into()?),
-            x_max: i16::from_be_bytes(buf[40..42].try_into()?),
-            y_max: i16::from_be_bytes(buf[42..44].try_into()?),
-            mac_style: u16::from_be_bytes(buf[44..46].try_into()?),
-            lowest_rec_ppem: u16::from_be_bytes(buf[46..48].try_into()?),
-            font_direction_hint: i16::from_be_bytes(buf[48..50].try_into()?),
-            index_to_loc_format: i16::from_be_bytes(buf[50..52].try_into()?),
-            glyph_data_format: i16::from_be_bytes(buf[52..54].try_into()?),
+            version,
+            font_revision: be_u32(buf, 4)?,
+            checksum_adjustment: be_u32(buf, 8)?,
+            magic_number: be_u32(buf, 12)?,
+            flags: HeadFlags::from_bits(be_u16(buf, 16)?),
+            units_per_em,
+            created: be_i64(buf, 20)?,
+            modified: be_i64(buf, 28)?,
+            x_min: be_i16(buf, 36)?,
+            y_min: be_i16(buf, 38)?,
+            x_max: be_i16(buf, 40)?,
+            y_max: be_i16(buf, 42)?,
+            mac_style: be_u16(buf, 44)?,
+            lowest_rec_ppem: be_u16(buf, 46)?,
+            font_direction_hint: be_i16(buf, 48)?,
+            index_to_loc_format: be_i16(buf, 50)?,
+            glyph_data_format: be_i16(buf, 52)?,
         })
     }
+}
 
+impl Default for Head {
+    /// A valid baseline head table: version 1.0, revision 1.0, the magic number,
+    /// the baseline at y = 0 and the left side bearing at x = 0 flags, 1000 units
+    /// per em, short loca offsets and the deprecated font direction hint set to 2
+    /// as the spec requires. Every date, bound and the checksum adjustment are 0.
+    fn default() -> Self {
+        Self {
+            version: HEAD_VERSION,
+            font_revision: 0x0001_0000,
+            checksum_adjustment: 0,
+            magic_number: HEAD_MAGIC_NUMBER,
+            flags: HeadFlags::from_bits(0x0003),
+            units_per_em: 1000,
+            created: 0,
+            modified: 0,
+            x_min: 0,
+            y_min: 0,
+            x_max: 0,
+            y_max: 0,
+            mac_style: 0,
+            lowest_rec_ppem: 0,
+            font_direction_hint: 2,
+            index_to_loc_format: 0,
+            glyph_data_format: 0,
+        }
+    }
+}
+
+impl Head {
     /// Returns the version of the head table.
     pub fn version(&self) -> u32 {
         self.version
     }
 
+    /// Checks if the head table is version 1.0, the only version defined so far.
+    /// The fields of any other version were read assuming the 1.0 layout.
+    pub fn is_standard_version(&self) -> bool {
+        self.version == HEAD_VERSION
+    }
+
     /// Returns the font revision set by the font author/manufacturer.
     pub fn font_revision(&self) -> u32 {
         self.font_revision
@@ -234,7 +437,7 @@ impl Head {
         self.checksum_adjustment
     }
 
-    /// Returns the magic number (always 0x5F0F3CF5).
+    /// Returns the magic number (always `HEAD_MAGIC_NUMBER`).
     pub fn magic_number(&self) -> u32 {
         self.magic_number
     }
@@ -244,7 +447,7 @@ impl Head {
         self.flags
     }
 
-    /// Returns the units per em value.
+    /// Returns the units per em value, always within `UNITS_PER_EM_RANGE`.
     pub fn units_per_em(&self) -> u16 {
         self.units_per_em
     }
@@ -284,6 +487,16 @@ impl Head {
         self.mac_style
     }
 
+    /// Checks if the mac style marks the glyphs as emboldened (bit 0).
+    pub fn mac_style_bold(&self) -> bool {
+        self.mac_style & 0x0001 != 0
+    }
+
+    /// Checks if the mac style marks the glyphs as italic (bit 1).
+    pub fn mac_style_italic(&self) -> bool {
+        self.mac_style & 0x0002 != 0
+    }
+
     /// Returns the smallest readable size in pixel.
     pub fn lowest_rec_ppem(&self) -> u16 {
         self.lowest_rec_ppem
@@ -294,13 +507,19 @@ impl Head {
         self.font_direction_hint
     }
 
-    /// Returns the index to loc format (0 for short offsets, 1 for long).
+    /// Returns the index to loc format (0 for short offsets, 1 for long),
+    /// see `Head::loca_format` for the typed equivalent.
     pub fn index_to_loc_format(&self) -> i16 {
         self.index_to_loc_format
     }
 
+    /// Returns the index to loc format as a `LocaFormat`.
+    pub fn loca_format(&self) -> LocaFormat {
+        LocaFormat::from(self.index_to_loc_format)
+    }
+
     /// Returns the glyph data format (0 is for the current format).
     pub fn glyph_data_format(&self) -> i16 {
         self.glyph_data_format
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,161 @@
+use crate::tag::Tag;
+
+use super::{Table, TableEncodingError, be_i16, be_u16, be_u32};
+
+/// A representation of the [hhea table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6hhea.html)
+/// which holds the information needed to lay out fonts horizontally
+#[derive(Debug)]
+pub struct Hhea {
+    /// The version of the hhea table, set to 0x00010000
+    version: u32,
+
+    /// Distance from baseline of highest ascender
+    ascent: i16,
+
+    /// Distance from baseline of lowest descender
+    descent: i16,
+
+    /// Typographic line gap
+    line_gap: i16,
+
+    /// Must be consistent with horizontal metrics
+    advance_width_max: u16,
+
+    /// Must be consistent with horizontal metrics
+    min_left_side_bearing: i16,
+
+    /// Must be consistent with horizontal metrics
+    min_right_side_bearing: i16,
+
+    /// max(lsb + (xMax - xMin))
+    x_max_extent: i16,
+
+    /// Used to calculate the slope of the caret (rise/run), set to 1 for vertical caret
+    caret_slope_rise: i16,
+
+    /// 0 for vertical
+    caret_slope_run: i16,
+
+    /// Set value to 0 for non-slanted fonts
+    caret_offset: i16,
+
+    /// The format of the metric data, 0 for the current format
+    metric_data_format: i16,
+
+    /// The number of advance widths in the hmtx table
+    num_of_long_hor_metrics: u16,
+}
+
+impl Table for Hhea {
+    const TAG: Tag = Tag::new(*b"hhea");
+
+    /// Parses the hhea table from it's raw bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::InvalidBufferLength` if the table is shorter than 36 bytes.
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        if buf.len() < 36 {
+            return Err(TableEncodingError::InvalidBufferLength(36, buf.len()));
+        }
+
+        // bytes 24..32 are reserved and set to 0
+        Ok(Self {
+            version: be_u32(buf, 0)?,
+            ascent: be_i16(buf, 4)?,
+            descent: be_i16(buf, 6)?,
+            line_gap: be_i16(buf, 8)?,
+            advance_width_max: be_u16(buf, 10)?,
+            min_left_side_bearing: be_i16(buf, 12)?,
+            min_right_side_bearing: be_i16(buf, 14)?,
+            x_max_extent: be_i16(buf, 16)?,
+            caret_slope_rise: be_i16(buf, 18)?,
+            caret_slope_run: be_i16(buf, 20)?,
+            caret_offset: be_i16(buf, 22)?,
+            metric_data_format: be_i16(buf, 32)?,
+            num_of_long_hor_metrics: be_u16(buf, 34)?,
+        })
+    }
+}
+
+impl Hhea {
+    /// Returns the version of the hhea table.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the distance from the baseline of the highest ascender.
+    pub fn ascent(&self) -> i16 {
+        self.ascent
+    }
+
+    /// Returns the distance from the baseline of the lowest descender.
+    pub fn descent(&self) -> i16 {
+        self.descent
+    }
+
+    /// Returns the typographic line gap.
+    pub fn line_gap(&self) -> i16 {
+        self.line_gap
+    }
+
+    /// Returns the maximum advance width.
+    pub fn advance_width_max(&self) -> u16 {
+        self.advance_width_max
+    }
+
+    /// Returns the minimum left side bearing.
+    pub fn min_left_side_bearing(&self) -> i16 {
+        self.min_left_side_bearing
+    }
+
+    /// Returns the minimum right side bearing.
+    pub fn min_right_side_bearing(&self) -> i16 {
+        self.min_right_side_bearing
+    }
+
+    /// Returns the maximum extent, max(lsb + (xMax - xMin)).
+    pub fn x_max_extent(&self) -> i16 {
+        self.x_max_extent
+    }
+
+    /// Returns the rise of the caret slope.
+    pub fn caret_slope_rise(&self) -> i16 {
+        self.caret_slope_rise
+    }
+
+    /// Returns the run of the caret slope.
+    pub fn caret_slope_run(&self) -> i16 {
+        self.caret_slope_run
+    }
+
+    /// Returns the caret offset.
+    pub fn caret_offset(&self) -> i16 {
+        self.caret_offset
+    }
+
+    /// Returns the angle of the caret slope in counter-clockwise degrees from the vertical,
+    /// negative for a caret leaning to the right like the `post` italic angle.
+    ///
+    /// A vertical caret (or a malformed one with a zero rise) has an angle of 0.
+    #[cfg(feature = "std")]
+    pub fn caret_angle_degrees(&self) -> f32 {
+        if self.caret_slope_run == 0 || self.caret_slope_rise == 0 {
+            return 0.0;
+        }
+
+        -f32::from(self.caret_slope_run)
+            .atan2(f32::from(self.caret_slope_rise))
+            .to_degrees()
+    }
+
+    /// Returns the format of the metric data (0 is for the current format).
+    pub fn metric_data_format(&self) -> i16 {
+        self.metric_data_format
+    }
+
+    /// Returns the number of advance widths in the hmtx table.
+    pub fn num_of_long_hor_metrics(&self) -> u16 {
+        self.num_of_long_hor_metrics
+    }
+}
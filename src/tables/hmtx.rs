@@ -0,0 +1,165 @@
+#[cfg(feature = "std")]
+use std::io::{Read, Seek};
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::{VeroTypeError, buffer::VeroBufReader};
+
+#[cfg(feature = "std")]
+use super::TableMetadata;
+use super::{TableEncodingError, be_i16, be_u16};
+
+/// A representation of the [hmtx table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6hmtx.html)
+/// which stores the horizontal metrics of every glyph
+#[derive(Debug)]
+pub struct Hmtx {
+    /// The advance width and left side bearing of the first `num_of_long_hor_metrics` glyphs
+    h_metrics: Vec<LongHorMetric>,
+
+    /// The left side bearings of the remaining glyphs, which share the last advance width
+    left_side_bearings: Vec<i16>,
+}
+
+impl Hmtx {
+    /// Constructs a `Hmtx` instance by reading data from the provided `VeroBufReader`,
+    /// see `Hmtx::from_buffer`.
+    #[cfg(feature = "std")]
+    pub fn from_reader<B: Read + Seek>(
+        reader: &mut VeroBufReader<B>,
+        metadata: &TableMetadata,
+        num_of_long_hor_metrics: u16,
+        num_glyphs: u16,
+    ) -> Result<Self, VeroTypeError> {
+        let buf = metadata.read_bytes(reader)?;
+
+        Ok(Self::from_buffer(&buf, num_of_long_hor_metrics, num_glyphs)?)
+    }
+
+    /// Constructs the hmtx table from the raw table bytes.
+    ///
+    /// `num_of_long_hor_metrics` comes from the hhea table
+    /// and `num_glyphs` from the maxp table.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::InconsistentMetrics` if `num_of_long_hor_metrics`
+    /// is greater than `num_glyphs`, since the table would then be read past it's end.
+    /// Also errors when the table is too short for `num_glyphs`.
+    pub fn from_buffer(
+        buf: &[u8],
+        num_of_long_hor_metrics: u16,
+        num_glyphs: u16,
+    ) -> Result<Self, TableEncodingError> {
+        if num_of_long_hor_metrics > num_glyphs {
+            return Err(TableEncodingError::InconsistentMetrics(
+                num_of_long_hor_metrics,
+                num_glyphs,
+            ));
+        }
+
+        // Every long horizontal metric is 4 bytes and the left side bearings follow them
+        let h_metrics = (0..usize::from(num_of_long_hor_metrics))
+            .map(|index| LongHorMetric::from_buffer(buf, index * 4))
+            .collect::<Result<Vec<LongHorMetric>, TableEncodingError>>()?;
+
+        let lsb_start = usize::from(num_of_long_hor_metrics) * 4;
+        let left_side_bearings = (0..usize::from(num_glyphs - num_of_long_hor_metrics))
+            .map(|index| be_i16(buf, lsb_start + index * 2))
+            .collect::<Result<Vec<i16>, TableEncodingError>>()?;
+
+        Ok(Self {
+            h_metrics,
+            left_side_bearings,
+        })
+    }
+
+    /// Returns the long horizontal metrics
+    pub fn h_metrics(&self) -> &[LongHorMetric] {
+        &self.h_metrics
+    }
+
+    /// Returns the left side bearings of the glyphs past the long horizontal metrics
+    pub fn left_side_bearings(&self) -> &[i16] {
+        &self.left_side_bearings
+    }
+
+    /// Returns the advance width of a glyph, glyphs past the long horizontal
+    /// metrics share the advance width of the last one.
+    ///
+    /// Returns `None` if the glyph id is out of bounds.
+    pub fn advance_width(&self, glyph_id: u16) -> Option<u16> {
+        let index = usize::from(glyph_id);
+
+        if index >= self.h_metrics.len() + self.left_side_bearings.len() {
+            return None;
+        }
+
+        self.h_metrics
+            .get(index)
+            .or(self.h_metrics.last())
+            .map(|metric| metric.advance_width)
+    }
+
+    /// Returns the advance width of every glyph, indexed by glyph id, the glyphs past
+    /// the long horizontal metrics repeat the advance width of the last one.
+    ///
+    /// A table without any long horizontal metric gives those glyphs an advance of 0.
+    pub fn advance_widths(&self) -> Vec<u16> {
+        let shared = self
+            .h_metrics
+            .last()
+            .map_or(0, |metric| metric.advance_width);
+
+        self.h_metrics
+            .iter()
+            .map(|metric| metric.advance_width)
+            .chain(core::iter::repeat_n(shared, self.left_side_bearings.len()))
+            .collect()
+    }
+
+    /// Returns the left side bearing of a glyph
+    ///
+    /// Returns `None` if the glyph id is out of bounds.
+    pub fn left_side_bearing(&self, glyph_id: u16) -> Option<i16> {
+        let index = usize::from(glyph_id);
+
+        match self.h_metrics.get(index) {
+            Some(metric) => Some(metric.left_side_bearing),
+            None => self
+                .left_side_bearings
+                .get(index - self.h_metrics.len())
+                .copied(),
+        }
+    }
+}
+
+/// The advance width and left side bearing of a glyph
+#[derive(Debug, Clone, Copy)]
+pub struct LongHorMetric {
+    /// The advance width of the glyph
+    advance_width: u16,
+
+    /// The left side bearing of the glyph
+    left_side_bearing: i16,
+}
+
+impl LongHorMetric {
+    /// Parses the metric at `at`
+    fn from_buffer(buf: &[u8], at: usize) -> Result<Self, TableEncodingError> {
+        Ok(Self {
+            advance_width: be_u16(buf, at)?,
+            left_side_bearing: be_i16(buf, at + 2)?,
+        })
+    }
+
+    /// Returns the advance width of the glyph
+    pub fn advance_width(&self) -> u16 {
+        self.advance_width
+    }
+
+    /// Returns the left side bearing of the glyph
+    pub fn left_side_bearing(&self) -> i16 {
+        self.left_side_bearing
+    }
+}
@@ -0,0 +1,380 @@
+use alloc::vec::Vec;
+
+use crate::tag::Tag;
+
+use super::{Table, TableEncodingError, be_i16, be_u16, be_u32, exact_chunks};
+
+/// A representation of the [kern table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6kern.html)
+/// which holds the kerning between pairs of glyphs.
+///
+/// Both the OpenType (version 0) and the Apple (version 1.0) layouts are read,
+/// only the subtables of format 0 (a list of pairs) and format 2 (a two dimensional
+/// array indexed by glyph classes) are parsed, the others are kept without a mapping.
+#[derive(Debug)]
+pub struct Kern {
+    /// The version of the kern table, 0 for the OpenType layout and 0x00010000 for Apple's
+    version: u32,
+
+    /// The subtables in the order they appear in the table
+    subtables: Vec<KernSubtable>,
+}
+
+impl Table for Kern {
+    const TAG: Tag = Tag::new(*b"kern");
+
+    /// Parses the kern table from it's raw bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::UnsupportedKernVersion` for a version other than
+    /// 0 and 1.0, or another `TableEncodingError` if a subtable is truncated.
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        // The OpenType layout starts with a 16 bit version, Apple's with a 32 bit one
+        let (version, num_tables, mut at) = match be_u16(buf, 0)? {
+            0 => (0, be_u16(buf, 2)? as u32, 4),
+            _ => match be_u32(buf, 0)? {
+                APPLE_VERSION => (APPLE_VERSION, be_u32(buf, 4)?, 8),
+                version => return Err(TableEncodingError::UnsupportedKernVersion(version)),
+            },
+        };
+
+        let mut subtables = Vec::new();
+
+        for _ in 0..num_tables {
+            let (subtable, length) = KernSubtable::from_buffer(buf, at, version == APPLE_VERSION)?;
+            subtables.push(subtable);
+
+            // A length too short to hold the subtable header would never get past it
+            if length < 6 {
+                break;
+            }
+            at += length;
+        }
+
+        Ok(Self { version, subtables })
+    }
+}
+
+impl Kern {
+    /// Returns the version of the kern table, 0 for the OpenType layout
+    /// and 0x00010000 for the Apple layout
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the subtables in the order they appear in the table
+    pub fn subtables(&self) -> &[KernSubtable] {
+        &self.subtables
+    }
+
+    /// Returns the horizontal kerning between two glyphs in font units,
+    /// 0 when the pair isn't kerned.
+    ///
+    /// The values of every horizontal subtable are added up, a subtable with the
+    /// override flag replaces the value accumulated so far instead. Cross-stream,
+    /// minimum and variation subtables don't adjust the advance and are skipped.
+    pub fn kerning(&self, left: u16, right: u16) -> i16 {
+        self.subtables
+            .iter()
+            .filter(|subtable| {
+                subtable.is_horizontal()
+                    && !subtable.is_cross_stream()
+                    && !subtable.is_minimum()
+                    && !subtable.is_variation()
+            })
+            .fold(0i16, |total, subtable| {
+                match subtable.kerning(left, right) {
+                    Some(value) if subtable.is_override() => value,
+                    Some(value) => total.saturating_add(value),
+                    None => total,
+                }
+            })
+    }
+}
+
+/// The version of the Apple layout of the kern table, 1.0 as a 16.16 fixed point number
+const APPLE_VERSION: u32 = 0x0001_0000;
+
+/// A single subtable of the kern table
+#[derive(Debug)]
+pub struct KernSubtable {
+    /// The format of the subtable
+    format: u8,
+
+    /// The coverage flags, normalized out of the OpenType or the Apple layout
+    flags: u8,
+
+    /// The parsed pairs or class array, `None` for unsupported formats
+    mapping: Option<KernMapping>,
+}
+
+/// The subtable kerns horizontal text
+const HORIZONTAL: u8 = 0x01;
+
+/// The subtable holds minimum values rather than kerning values
+const MINIMUM: u8 = 0x02;
+
+/// The subtable kerns perpendicular to the text flow
+const CROSS_STREAM: u8 = 0x04;
+
+/// The subtable's values replace the accumulated value instead of adding to it
+const OVERRIDE: u8 = 0x08;
+
+/// The subtable holds the variation values of a variable font
+const VARIATION: u8 = 0x10;
+
+impl KernSubtable {
+    /// Parses the subtable at `at`, `buf` is the whole kern table.
+    /// Returns the subtable along with the length it declares.
+    fn from_buffer(
+        buf: &[u8],
+        at: usize,
+        apple: bool,
+    ) -> Result<(Self, usize), TableEncodingError> {
+        let coverage = be_u16(buf, at + 4)?;
+
+        let (length, format, flags, header_len) = if apple {
+            // length, coverage with the flags in the high byte and the format
+            // in the low one, then a tuple index
+            let mut flags = 0;
+
+            if coverage & 0x8000 == 0 {
+                flags |= HORIZONTAL;
+            }
+            if coverage & 0x4000 != 0 {
+                flags |= CROSS_STREAM;
+            }
+            if coverage & 0x2000 != 0 {
+                flags |= VARIATION;
+            }
+
+            (be_u32(buf, at)? as usize, coverage as u8, flags, 8)
+        } else {
+            // version, length, then coverage with the format in the high byte
+            // and the flags in the low one
+            let flags = coverage as u8 & (HORIZONTAL | MINIMUM | CROSS_STREAM | OVERRIDE);
+
+            (
+                usize::from(be_u16(buf, at + 2)?),
+                (coverage >> 8) as u8,
+                flags,
+                6,
+            )
+        };
+
+        // A length past the end of the table (or an overflown one) is clamped,
+        // format 0 declares how many pairs it holds anyway
+        let end = match at.saturating_add(length) {
+            end if end >= at + header_len && end <= buf.len() => end,
+            _ => buf.len(),
+        };
+        let subtable = buf.get(at..end).unwrap_or_default();
+
+        let mapping = match format {
+            0 => Some(KernMapping::Format0(Format0::from_buffer(
+                buf.get(at..).unwrap_or_default(),
+                header_len,
+            )?)),
+            2 => Some(KernMapping::Format2(Format2::from_buffer(
+                subtable, header_len,
+            )?)),
+            _ => None,
+        };
+
+        Ok((
+            Self {
+                format,
+                flags,
+                mapping,
+            },
+            length,
+        ))
+    }
+
+    /// Returns the format of the subtable
+    pub fn format(&self) -> u8 {
+        self.format
+    }
+
+    /// Checks if the subtable kerns horizontal text
+    pub fn is_horizontal(&self) -> bool {
+        self.flags & HORIZONTAL != 0
+    }
+
+    /// Checks if the subtable holds minimum values rather than kerning values,
+    /// only the OpenType layout has the flag
+    pub fn is_minimum(&self) -> bool {
+        self.flags & MINIMUM != 0
+    }
+
+    /// Checks if the subtable kerns perpendicular to the text flow
+    pub fn is_cross_stream(&self) -> bool {
+        self.flags & CROSS_STREAM != 0
+    }
+
+    /// Checks if the subtable's values replace the accumulated value,
+    /// only the OpenType layout has the flag
+    pub fn is_override(&self) -> bool {
+        self.flags & OVERRIDE != 0
+    }
+
+    /// Checks if the subtable holds variation values, only the Apple layout has the flag
+    pub fn is_variation(&self) -> bool {
+        self.flags & VARIATION != 0
+    }
+
+    /// Returns the parsed pairs or class array, `None` if the format isn't supported
+    pub fn mapping(&self) -> Option<&KernMapping> {
+        self.mapping.as_ref()
+    }
+
+    /// Returns the kerning value of a pair of glyphs in this subtable.
+    ///
+    /// Returns `None` if the pair isn't kerned or the format isn't supported.
+    pub fn kerning(&self, left: u16, right: u16) -> Option<i16> {
+        match self.mapping.as_ref()? {
+            KernMapping::Format0(table) => table.kerning(left, right),
+            KernMapping::Format2(table) => table.kerning(left, right),
+        }
+    }
+}
+
+/// A parsed kern subtable of one of the supported formats
+#[derive(Debug)]
+pub enum KernMapping {
+    /// An ordered list of kerning pairs
+    Format0(Format0),
+
+    /// A two dimensional array of kerning values indexed by glyph classes
+    Format2(Format2),
+}
+
+/// A format 0 kern subtable, a list of pairs sorted by their left then right glyph
+#[derive(Debug)]
+pub struct Format0 {
+    /// The `(left, right, value)` pairs sorted by `(left, right)`
+    pairs: Vec<(u16, u16, i16)>,
+}
+
+impl Format0 {
+    /// Parses a format 0 subtable whose body starts at `at`, `buf` starts at the subtable
+    fn from_buffer(buf: &[u8], at: usize) -> Result<Self, TableEncodingError> {
+        let num_pairs = usize::from(be_u16(buf, at)?);
+        // The search range, entry selector and range shift follow, then the 6 byte pairs
+        let start = at + 8;
+        let end = start + num_pairs * 6;
+
+        let records = buf
+            .get(start..end)
+            .ok_or(TableEncodingError::InvalidBufferLength(end, buf.len()))?;
+
+        let mut pairs = exact_chunks(records, 6)?
+            .map(|pair| {
+                (
+                    u16::from_be_bytes([pair[0], pair[1]]),
+                    u16::from_be_bytes([pair[2], pair[3]]),
+                    i16::from_be_bytes([pair[4], pair[5]]),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // The pairs have to be sorted for the binary search, don't trust the font on it
+        pairs.sort_by_key(|&(left, right, _)| (left, right));
+
+        Ok(Self { pairs })
+    }
+
+    /// Returns the `(left, right, value)` pairs sorted by `(left, right)`
+    pub fn pairs(&self) -> &[(u16, u16, i16)] {
+        &self.pairs
+    }
+
+    /// Returns the kerning value of a pair of glyphs
+    fn kerning(&self, left: u16, right: u16) -> Option<i16> {
+        let index = self
+            .pairs
+            .binary_search_by_key(&(left, right), |&(left, right, _)| (left, right))
+            .ok()?;
+
+        Some(self.pairs[index].2)
+    }
+}
+
+/// A format 2 kern subtable, a two dimensional array of kerning values.
+///
+/// The left glyph's class is the byte offset of it's row from the start of the
+/// subtable and the right glyph's class the byte offset of the value within the row,
+/// their sum locates the kerning value.
+#[derive(Debug)]
+pub struct Format2 {
+    /// The classes of the left glyphs
+    left_classes: ClassTable,
+
+    /// The classes of the right glyphs
+    right_classes: ClassTable,
+
+    /// The bytes of the whole subtable, which the summed classes point into
+    data: Vec<u8>,
+}
+
+impl Format2 {
+    /// Parses a format 2 subtable whose body starts at `at`, `buf` is the whole subtable
+    fn from_buffer(buf: &[u8], at: usize) -> Result<Self, TableEncodingError> {
+        // The row width and the array offset aren't needed, the classes
+        // are offsets which already account for them
+        let left_classes = ClassTable::from_buffer(buf, usize::from(be_u16(buf, at + 2)?))?;
+        let right_classes = ClassTable::from_buffer(buf, usize::from(be_u16(buf, at + 4)?))?;
+
+        Ok(Self {
+            left_classes,
+            right_classes,
+            data: buf.to_vec(),
+        })
+    }
+
+    /// Returns the kerning value of a pair of glyphs, `None` if either
+    /// glyph isn't covered by it's class table
+    fn kerning(&self, left: u16, right: u16) -> Option<i16> {
+        let offset = self.left_classes.class(left)? + self.right_classes.class(right)?;
+
+        // a value past the end of the subtable is treated as not kerned
+        be_i16(&self.data, offset).ok()
+    }
+}
+
+/// A class table of a format 2 kern subtable, mapping a range of glyphs to their class
+#[derive(Debug)]
+struct ClassTable {
+    /// The first glyph covered by the table
+    first_glyph: u16,
+
+    /// The class of every glyph from the first one on
+    classes: Vec<u16>,
+}
+
+impl ClassTable {
+    /// Parses the class table at `at`, `buf` starts at the subtable
+    fn from_buffer(buf: &[u8], at: usize) -> Result<Self, TableEncodingError> {
+        let first_glyph = be_u16(buf, at)?;
+        let num_glyphs = usize::from(be_u16(buf, at + 2)?);
+
+        let classes = (0..num_glyphs)
+            .map(|index| be_u16(buf, at + 4 + index * 2))
+            .collect::<Result<Vec<u16>, TableEncodingError>>()?;
+
+        Ok(Self {
+            first_glyph,
+            classes,
+        })
+    }
+
+    /// Returns the class of a glyph, `None` if the table doesn't cover it
+    fn class(&self, glyph_id: u16) -> Option<usize> {
+        let index = glyph_id.checked_sub(self.first_glyph)?;
+
+        self.classes
+            .get(usize::from(index))
+            .copied()
+            .map(usize::from)
+    }
+}
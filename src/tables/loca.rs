@@ -0,0 +1,137 @@
+use core::ops::Range;
+#[cfg(feature = "std")]
+use std::io::{Read, Seek};
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use crate::{VeroTypeError, buffer::VeroBufReader};
+
+#[cfg(feature = "std")]
+use super::TableMetadata;
+use super::{TableEncodingError, be_u16, be_u32, head::LocaFormat};
+
+/// A representation of the [loca table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6loca.html)
+/// which stores the offsets of every glyph in the glyf table
+#[derive(Debug)]
+pub struct Loca {
+    /// The byte offsets of the glyphs relative to the start of the glyf table,
+    /// there's one more offset than glyphs so the length of the last glyph can be computed
+    offsets: Vec<u32>,
+}
+
+impl Loca {
+    /// Constructs a `Loca` instance by reading data from the provided `VeroBufReader`,
+    /// see `Loca::from_buffer`.
+    #[cfg(feature = "std")]
+    pub fn from_reader<B: Read + Seek>(
+        reader: &mut VeroBufReader<B>,
+        metadata: &TableMetadata,
+        format: LocaFormat,
+        num_glyphs: u16,
+    ) -> Result<Self, VeroTypeError> {
+        let buf = metadata.read_bytes(reader)?;
+
+        Ok(Self::from_buffer(&buf, format, num_glyphs)?)
+    }
+
+    /// Constructs a `Loca` instance like `Loca::from_reader`, but recovers
+    /// from a corrupt format, see `Loca::from_buffer_lenient`.
+    #[cfg(feature = "std")]
+    pub fn from_reader_lenient<B: Read + Seek>(
+        reader: &mut VeroBufReader<B>,
+        metadata: &TableMetadata,
+        format: LocaFormat,
+        num_glyphs: u16,
+    ) -> Result<(Self, Option<TableEncodingError>), VeroTypeError> {
+        let buf = metadata.read_bytes(reader)?;
+
+        Ok(Self::from_buffer_lenient(&buf, format, num_glyphs)?)
+    }
+
+    /// Constructs the loca table from the raw table bytes.
+    ///
+    /// `format` comes from the head table (see `Head::loca_format`)
+    /// and `num_glyphs` from the maxp table.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::InvalidLocaFormat` if `format`
+    /// is `LocaFormat::Unknown`, see `Loca::from_buffer_lenient` for a recovering alternative.
+    /// Also errors when the table is too short for `num_glyphs`.
+    pub fn from_buffer(
+        buf: &[u8],
+        format: LocaFormat,
+        num_glyphs: u16,
+    ) -> Result<Self, TableEncodingError> {
+        let long = match format {
+            LocaFormat::Short => false,
+            LocaFormat::Long => true,
+            LocaFormat::Unknown(value) => Err(TableEncodingError::InvalidLocaFormat(value))?,
+        };
+
+        Self::read(buf, long, num_glyphs)
+    }
+
+    /// Constructs a `Loca` instance like `Loca::from_buffer`, but recovers
+    /// from a corrupt (`LocaFormat::Unknown`) format.
+    ///
+    /// Damaged fonts sometimes set the format to something other than 0 or 1,
+    /// in that case the format is guessed from the table length: a table of
+    /// `(num_glyphs + 1) * 4` bytes or longer holds long offsets, anything shorter
+    /// holds short offsets. When a guess was made the `InvalidLocaFormat` error
+    /// is returned alongside the table as a warning.
+    ///
+    /// # Errors
+    ///
+    /// Errors when the table is too short for `num_glyphs`.
+    pub fn from_buffer_lenient(
+        buf: &[u8],
+        format: LocaFormat,
+        num_glyphs: u16,
+    ) -> Result<(Self, Option<TableEncodingError>), TableEncodingError> {
+        let (long, warning) = match format {
+            LocaFormat::Short => (false, None),
+            LocaFormat::Long => (true, None),
+            LocaFormat::Unknown(value) => {
+                let long_length = (usize::from(num_glyphs) + 1) * 4;
+                let long = buf.len() >= long_length;
+
+                (long, Some(TableEncodingError::InvalidLocaFormat(value)))
+            }
+        };
+
+        Ok((Self::read(buf, long, num_glyphs)?, warning))
+    }
+
+    /// Reads `num_glyphs + 1` offsets, short offsets are stored divided by two
+    fn read(buf: &[u8], long: bool, num_glyphs: u16) -> Result<Self, TableEncodingError> {
+        let count = usize::from(num_glyphs) + 1;
+        let offsets = (0..count)
+            .map(|index| match long {
+                true => be_u32(buf, index * 4),
+                false => be_u16(buf, index * 2).map(|offset| u32::from(offset) * 2),
+            })
+            .collect::<Result<Vec<u32>, TableEncodingError>>()?;
+
+        Ok(Self { offsets })
+    }
+
+    /// Returns the byte offsets of the glyphs relative to the start of the glyf table,
+    /// the last entry marks the end of the last glyph.
+    pub fn offsets(&self) -> &[u32] {
+        &self.offsets
+    }
+
+    /// Returns the byte range of a glyph within the glyf table,
+    /// an empty range means the glyph has no outline (e.g. a space).
+    ///
+    /// Returns `None` if the glyph id is out of bounds or the offsets aren't ascending.
+    pub fn glyph_range(&self, glyph_id: u16) -> Option<Range<u32>> {
+        let index = usize::from(glyph_id);
+        let start = *self.offsets.get(index)?;
+        let end = *self.offsets.get(index + 1)?;
+
+        (start <= end).then_some(start..end)
+    }
+}
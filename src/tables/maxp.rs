@@ -0,0 +1,69 @@
+use crate::tag::Tag;
+
+use super::{Table, TableEncodingError, be_u16, be_u32};
+
+/// A representation of the [maxp table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6maxp.html)
+/// which establishes the memory requirements of the font
+#[derive(Debug)]
+pub struct Maxp {
+    /// The version of the maxp table
+    /// 0x00010000 for TrueType outlines, 0x00005000 for CFF outlines
+    version: u32,
+
+    /// The number of glyphs in the font
+    num_glyphs: u16,
+
+    /// The maximum levels of nested composite glyphs, only version 1.0 has it
+    max_component_depth: Option<u16>,
+}
+
+impl Table for Maxp {
+    const TAG: Tag = Tag::new(*b"maxp");
+
+    /// Parses the maxp table from it's raw bytes.
+    ///
+    /// Only the version and the number of glyphs are read, these two fields
+    /// are shared by every version of the table, along with the maximum
+    /// component depth of version 1.0 tables.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::InvalidBufferLength` if the table is shorter than 6 bytes.
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        if buf.len() < 6 {
+            return Err(TableEncodingError::InvalidBufferLength(6, buf.len()));
+        }
+
+        let version = be_u32(buf, 0)?;
+
+        // A version 1.0 table too short to hold the field is treated like version 0.5
+        let max_component_depth = match version {
+            0x0001_0000 => be_u16(buf, 30).ok(),
+            _ => None,
+        };
+
+        Ok(Self {
+            version,
+            num_glyphs: be_u16(buf, 4)?,
+            max_component_depth,
+        })
+    }
+}
+
+impl Maxp {
+    /// Returns the version of the maxp table.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the number of glyphs in the font.
+    pub fn num_glyphs(&self) -> u16 {
+        self.num_glyphs
+    }
+
+    /// Returns the maximum levels of nested composite glyphs, 1 when composites
+    /// are only made of simple glyphs. `None` for tables before version 1.0.
+    pub fn max_component_depth(&self) -> Option<u16> {
+        self.max_component_depth
+    }
+}
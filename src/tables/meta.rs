@@ -0,0 +1,146 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::tag::Tag;
+
+use super::{Table, TableEncodingError, be_u32};
+
+/// The tag of the data map listing the languages the font was designed for
+const DESIGNED_LANGUAGES: Tag = Tag::new(*b"dlng");
+
+/// The tag of the data map listing the languages the font supports
+const SUPPORTED_LANGUAGES: Tag = Tag::new(*b"slng");
+
+/// A representation of the [meta table](https://learn.microsoft.com/en-us/typography/opentype/spec/meta)
+/// which holds metadata such as the languages a font was designed for or supports
+#[derive(Debug)]
+pub struct Meta {
+    /// The version of the meta table, set to 1
+    version: u32,
+
+    /// The flags of the meta table, currently unused and set to 0
+    flags: u32,
+
+    /// The data maps of the table, with their data copied out of the table
+    data_maps: Vec<DataMap>,
+}
+
+impl Table for Meta {
+    const TAG: Tag = Tag::new(*b"meta");
+
+    /// Parses the meta table from it's raw bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError` if the header, a data map record
+    /// or the data it points to lies outside of the table.
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        let version = be_u32(buf, 0)?;
+        let flags = be_u32(buf, 4)?;
+        // bytes 8..12 are reserved
+        let data_maps_count = be_u32(buf, 12)? as usize;
+
+        // Every data map record is 12 bytes and the records start right after the 16 byte header
+        let data_maps = (0..data_maps_count)
+            .map(|index| DataMap::from_buffer(buf, 16 + index * 12))
+            .collect::<Result<Vec<DataMap>, TableEncodingError>>()?;
+
+        Ok(Self {
+            version,
+            flags,
+            data_maps,
+        })
+    }
+}
+
+impl Meta {
+    /// Returns the version of the meta table
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the flags of the meta table
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+
+    /// Returns the data maps of the table
+    pub fn data_maps(&self) -> &[DataMap] {
+        &self.data_maps
+    }
+
+    /// Returns the raw data of the first data map with the given tag
+    pub fn data(&self, tag: impl Into<Tag>) -> Option<&[u8]> {
+        let tag = tag.into();
+
+        self.data_maps
+            .iter()
+            .find(|map| map.tag == tag)
+            .map(|map| map.data.as_slice())
+    }
+
+    /// Returns the languages the font was designed for (the `dlng` map)
+    /// as BCP 47 script/language tags, empty if the map is missing.
+    pub fn designed_languages(&self) -> Vec<String> {
+        self.languages(DESIGNED_LANGUAGES)
+    }
+
+    /// Returns the languages the font supports (the `slng` map)
+    /// as BCP 47 script/language tags, empty if the map is missing.
+    pub fn supported_languages(&self) -> Vec<String> {
+        self.languages(SUPPORTED_LANGUAGES)
+    }
+
+    /// Splits a comma separated UTF-8 list of language tags
+    fn languages(&self, tag: Tag) -> Vec<String> {
+        let Some(data) = self.data(tag) else {
+            return Vec::new();
+        };
+
+        String::from_utf8_lossy(data)
+            .split(',')
+            .map(str::trim)
+            .filter(|language| !language.is_empty())
+            .map(String::from)
+            .collect()
+    }
+}
+
+/// A data map of the meta table, tagging a blob of metadata
+#[derive(Debug)]
+pub struct DataMap {
+    /// The tag of the metadata
+    tag: Tag,
+
+    /// The metadata, copied out of the table
+    data: Vec<u8>,
+}
+
+impl DataMap {
+    /// Parses the data map record at `at`, `buf` is the whole meta table
+    /// as the data offset is relative to it
+    fn from_buffer(buf: &[u8], at: usize) -> Result<Self, TableEncodingError> {
+        let tag = Tag::new(be_u32(buf, at)?.to_be_bytes());
+        let offset = be_u32(buf, at + 4)? as usize;
+        let length = be_u32(buf, at + 8)? as usize;
+
+        let end = offset.saturating_add(length);
+        let data = buf
+            .get(offset..end)
+            .ok_or(TableEncodingError::InvalidBufferLength(end, buf.len()))?;
+
+        Ok(Self {
+            tag,
+            data: data.to_vec(),
+        })
+    }
+
+    /// Returns the tag of the metadata
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    /// Returns the raw metadata
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
@@ -1,22 +1,52 @@
-use std::{
-    collections::{BTreeMap, btree_map::IntoIter},
-    io::{Read, Seek},
+#[cfg(feature = "std")]
+use std::io::{Read, Seek};
+
+use core::ops::Range;
+
+use alloc::{
+    collections::{
+        BTreeMap, BTreeSet,
+        btree_map::{IntoIter, Iter},
+    },
+    string::String,
+    vec::Vec,
 };
 
 use head::Head;
-use name::Name;
 use thiserror::Error;
 
-use crate::{VeroTypeError, buffer::VeroBufReader};
+#[cfg(feature = "std")]
+use crate::buffer::{TableReader, VeroBufReader};
+use crate::{VeroTypeError, shared::SharedBytes, tag::Tag};
+#[cfg(feature = "std")]
+use crate::writer::{CHECKSUM_ADJUSTMENT_OFFSET, CHECKSUM_MAGIC};
 
+pub mod avar;
+pub mod cbdt;
+pub mod cblc;
+pub mod cmap;
+pub mod dsig;
+pub mod fvar;
+pub mod glyf;
+pub mod gvar;
+pub mod hdmx;
 pub mod head;
+pub mod hhea;
+pub mod hmtx;
+pub mod kern;
+pub mod loca;
+pub mod maxp;
+pub mod meta;
 pub mod name;
+pub mod os2;
+pub mod post;
+pub mod svg;
 
 /// An enum for the required tables
 /// tables where every TrueType formatted font must include in it's
 /// file's table directory.
 /// For more information, see the [Apple Documentation Table 2](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6.html)
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum RequiredTables {
     Cmap,
     Glyf,
@@ -33,33 +63,343 @@ impl TryFrom<&[u8]> for RequiredTables {
     type Error = ();
 
     fn try_from(value: &[u8]) -> Result<Self, ()> {
-        // TODO: This unwrap
-        Ok(match str::from_utf8(value).unwrap() {
-            "cmap" => Self::Cmap,
-            "glyf" => Self::Glyf,
-            "head" => Self::Head,
-            "hhea" => Self::Hhea,
-            "hmtx" => Self::Hmtx,
-            "loca" => Self::Loca,
-            "maxp" => Self::Maxp,
-            "name" => Self::Name,
-            "post" => Self::Post,
+        // Anything but 4 bytes can't be a tag
+        let tag: &[u8; 4] = value.try_into().map_err(|_| ())?;
+
+        Self::try_from(tag)
+    }
+}
+
+/// Parses a string tag such as `"cmap"`, which must be exactly the 4 bytes of the tag
+///
+/// # Examples
+///
+/// ```
+/// use vero_type::tables::RequiredTables;
+///
+/// assert_eq!(RequiredTables::try_from("cmap"), Ok(RequiredTables::Cmap));
+/// assert_eq!(RequiredTables::try_from("cmap "), Err(()));
+/// assert_eq!(RequiredTables::try_from("GSUB"), Err(()));
+/// ```
+impl TryFrom<&str> for RequiredTables {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, ()> {
+        Self::try_from(value.as_bytes())
+    }
+}
+
+impl TryFrom<&[u8; 4]> for RequiredTables {
+    type Error = ();
+
+    fn try_from(value: &[u8; 4]) -> Result<Self, ()> {
+        Self::try_from(Tag::from(value))
+    }
+}
+
+impl TryFrom<Tag> for RequiredTables {
+    type Error = ();
+
+    fn try_from(value: Tag) -> Result<Self, ()> {
+        Ok(match value.as_bytes() {
+            b"cmap" => Self::Cmap,
+            b"glyf" => Self::Glyf,
+            b"head" => Self::Head,
+            b"hhea" => Self::Hhea,
+            b"hmtx" => Self::Hmtx,
+            b"loca" => Self::Loca,
+            b"maxp" => Self::Maxp,
+            b"name" => Self::Name,
+            b"post" => Self::Post,
             _ => Err(())?,
         })
     }
 }
 
+impl RequiredTables {
+    /// Returns the required table a tag stands for, `None` for any other tag
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::{tables::RequiredTables, tag::Tag};
+    ///
+    /// assert_eq!(RequiredTables::from_tag(Tag::new(*b"head")), Some(RequiredTables::Head));
+    /// assert_eq!(RequiredTables::from_tag(Tag::new(*b"meta")), None);
+    /// // the tags are case sensitive
+    /// assert_eq!(RequiredTables::from_tag(Tag::new(*b"HEAD")), None);
+    /// ```
+    pub fn from_tag(tag: Tag) -> Option<Self> {
+        Self::try_from(tag).ok()
+    }
+}
+
 /// Represents the error messages which may occur when trying
 /// to parse tables from raw binary buffers
 #[derive(Error, Debug)]
 pub enum TableEncodingError {
-    #[error("The required buffer length for this table is {0} bytes, got {0} bytes")]
+    #[error("The required buffer length for this table is {0} bytes, got {1} bytes")]
     InvalidBufferLength(usize, usize),
+
+    #[error("The font is missing the required {0:?} table")]
+    MissingRequiredTable(RequiredTables),
+
+    #[error("The font is missing the '{0}' table")]
+    MissingTable(Tag),
+
+    #[error("The index to loc format must be 0 or 1, got {0}")]
+    InvalidLocaFormat(i16),
+
+    #[error("The table directory lists the '{0}' table more than once")]
+    DuplicateTable(Tag),
+
+    #[error("The hhea table declares {0} horizontal metrics but the font only has {1} glyphs")]
+    InconsistentMetrics(u16, u16),
+
+    #[error("The units per em must be within 16 and 16384, got {0}")]
+    InvalidUnitsPerEm(u16),
+
+    #[error("The directory declares {declared} tables but the file only has room for {available}")]
+    DirectoryTooLarge { declared: u16, available: u64 },
+
+    #[error("The fvar axis records must be at least 20 bytes long, got {0} bytes")]
+    InvalidAxisSize(u16),
+
+    #[error("The table declares {expected} records but only {found} were found")]
+    RecordCountMismatch { expected: u16, found: usize },
+
+    #[error("The cmap subtable format {0} isn't supported")]
+    UnsupportedCmapFormat(u16),
+
+    #[error("The name table format {0} isn't supported")]
+    UnsupportedNameFormat(u16),
+
+    #[error("The last loca offset is {loca} but the glyf table is {glyf} bytes long")]
+    LocaGlyfLengthMismatch { loca: u32, glyf: u32 },
+
+    #[error("The composite glyphs nest deeper than the maximum component depth of {0}")]
+    ComponentDepthExceeded(u16),
+
+    #[error("The glyph outline is malformed, {0}")]
+    MalformedGlyph(&'static str),
+
+    #[error("The bitmap index subtable format {0} isn't supported")]
+    UnsupportedBitmapIndexFormat(u16),
+
+    #[error("The SVG document is malformed, {0}")]
+    MalformedSvgDocument(&'static str),
+
+    #[error("The '{tag}' table ends at byte {end}, past the end of the {file_len} byte file")]
+    TableOutOfBounds { tag: Tag, end: u64, file_len: u64 },
+
+    #[error("The '{0}' and '{1}' tables overlap")]
+    OverlappingTables(Tag, Tag),
+
+    #[error("The kern table version {0:#010x} isn't supported")]
+    UnsupportedKernVersion(u32),
+
+    #[error("The {length} bytes at offset {offset} don't fit in the address space")]
+    RangeOverflow { offset: u32, length: u32 },
+
+    #[error("The font has {0} tables but a directory lists at most 65535")]
+    TooManyTables(usize),
+
+    #[error("The '{0}' table lies past the 4 GiB a directory entry can address")]
+    TableTooLarge(Tag),
+
+    #[error("The sfnt version {sfnt_version:#010x} doesn't match the '{table}' outline table")]
+    OutlineFormatMismatch { sfnt_version: u32, table: Tag },
+
+    #[error("The hdmx table is malformed, {0}")]
+    MalformedHdmx(&'static str),
+}
+
+/// Splits `buf` into records of `size` bytes, erroring instead of silently
+/// dropping a trailing partial record, which is a sign of a truncated table
+pub(crate) fn exact_chunks(
+    buf: &[u8],
+    size: usize,
+) -> Result<impl Iterator<Item = &[u8]>, TableEncodingError> {
+    match buf.len().checked_rem(size) {
+        Some(0) => Ok(buf.chunks_exact(size)),
+        Some(_) => Err(TableEncodingError::InvalidBufferLength(
+            buf.len().div_ceil(size) * size,
+            buf.len(),
+        )),
+        None => Err(TableEncodingError::InvalidBufferLength(size, buf.len())),
+    }
+}
+
+/// Converts the `length` bytes at `offset` of a file to a range of indices,
+/// erroring instead of truncating when the end doesn't fit in a `usize`
+/// (on 32 bit platforms, or with adversarial values)
+pub(crate) fn checked_range(offset: u32, length: u32) -> Result<Range<usize>, TableEncodingError> {
+    let start = usize::try_from(offset).ok();
+    let end = start
+        .zip(usize::try_from(length).ok())
+        .and_then(|(start, length)| start.checked_add(length));
+
+    match (start, end) {
+        (Some(start), Some(end)) => Ok(start..end),
+        _ => Err(TableEncodingError::RangeOverflow { offset, length }),
+    }
+}
+
+/// Decodes UTF-16BE bytes, unpaired surrogates are replaced with U+FFFD
+/// and a trailing odd byte is ignored
+pub(crate) fn decode_utf16be(bytes: &[u8]) -> String {
+    let units = bytes
+        .chunks_exact(2)
+        .map(|unit| u16::from_be_bytes([unit[0], unit[1]]));
+
+    char::decode_utf16(units)
+        .map(|unit| unit.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect()
+}
+
+/// Reads a big-endian `u16` at `at`, erroring instead of panicking
+/// when the buffer is too short
+pub(crate) fn be_u16(buf: &[u8], at: usize) -> Result<u16, TableEncodingError> {
+    match buf.get(at..at + 2) {
+        Some(bytes) => Ok(u16::from_be_bytes([bytes[0], bytes[1]])),
+        None => Err(TableEncodingError::InvalidBufferLength(at + 2, buf.len())),
+    }
+}
+
+/// Reads a big-endian `i16` at `at`, erroring instead of panicking
+/// when the buffer is too short
+pub(crate) fn be_i16(buf: &[u8], at: usize) -> Result<i16, TableEncodingError> {
+    be_u16(buf, at).map(|value| value as i16)
+}
+
+/// Reads a big-endian `i64` at `at`, erroring instead of panicking
+/// when the buffer is too short
+pub(crate) fn be_i64(buf: &[u8], at: usize) -> Result<i64, TableEncodingError> {
+    match buf.get(at..at + 8) {
+        Some(bytes) => {
+            let mut value = [0u8; 8];
+            value.copy_from_slice(bytes);
+
+            Ok(i64::from_be_bytes(value))
+        }
+        None => Err(TableEncodingError::InvalidBufferLength(at + 8, buf.len())),
+    }
+}
+
+/// Reads a big-endian `u32` at `at`, erroring instead of panicking
+/// when the buffer is too short
+pub(crate) fn be_u32(buf: &[u8], at: usize) -> Result<u32, TableEncodingError> {
+    match buf.get(at..at + 4) {
+        Some(bytes) => Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])),
+        None => Err(TableEncodingError::InvalidBufferLength(at + 4, buf.len())),
+    }
+}
+
+/// Converts a 16.16 fixed point number to a float
+pub(crate) fn fixed_to_f32(value: i32) -> f32 {
+    value as f32 / 65536.0
+}
+
+/// Converts a 2.14 fixed point number to a float
+pub(crate) fn f2dot14_to_f32(value: i16) -> f32 {
+    f32::from(value) / 16384.0
+}
+
+/// Runs the parse of a table, with the `tracing` feature it runs in a `table` span
+/// recording the tag, offset and length of the table and a failed parse is logged
+/// as a warning within the span
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub(crate) fn traced<R>(
+    tag: Tag,
+    metadata: &TableMetadata,
+    parse: impl FnOnce() -> Result<R, VeroTypeError>,
+) -> Result<R, VeroTypeError> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!(
+        "table",
+        tag = %tag,
+        offset = metadata.offset,
+        length = metadata.length
+    )
+    .entered();
+
+    let result = parse();
+
+    #[cfg(feature = "tracing")]
+    if let Err(error) = &result {
+        tracing::warn!(%error, "failed to parse the table");
+    }
+
+    result
+}
+
+/// A table which can be parsed out of it's raw bytes, see `Tables::load`
+pub trait Table: Sized {
+    /// The tag of the table in the table directory
+    const TAG: Tag;
+
+    /// Parses the table from it's raw bytes, `buf` holds exactly the
+    /// bytes the directory entry of the table points at
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError>;
+
+    /// Parses the table out of a shared buffer, like `Table::parse`.
+    ///
+    /// Tables which keep a part of their bytes around override this
+    /// to hold a view into `data` rather than a copy.
+    fn parse_shared(data: &SharedBytes) -> Result<Self, TableEncodingError> {
+        Self::parse(data)
+    }
+}
+
+/// Where the bytes of the tables come from, a reader or a shared buffer,
+/// so the tables of a font are loaded the same way from both
+pub(crate) trait TableSource {
+    /// Hands the bytes of the `tag` table to `f`
+    fn with_bytes<R>(
+        &mut self,
+        tag: Tag,
+        metadata: &TableMetadata,
+        f: impl FnOnce(&[u8]) -> Result<R, TableEncodingError>,
+    ) -> Result<R, VeroTypeError>;
+
+    /// Parses a table through it's `Table` implementation
+    fn parse<T: Table>(&mut self, metadata: &TableMetadata) -> Result<T, VeroTypeError> {
+        self.with_bytes(T::TAG, metadata, T::parse)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<B: Read + Seek> TableSource for VeroBufReader<B> {
+    fn with_bytes<R>(
+        &mut self,
+        tag: Tag,
+        metadata: &TableMetadata,
+        f: impl FnOnce(&[u8]) -> Result<R, TableEncodingError>,
+    ) -> Result<R, VeroTypeError> {
+        traced(tag, metadata, || Ok(f(&metadata.read_bytes(self)?)?))
+    }
+}
+
+impl TableSource for SharedBytes {
+    fn with_bytes<R>(
+        &mut self,
+        tag: Tag,
+        metadata: &TableMetadata,
+        f: impl FnOnce(&[u8]) -> Result<R, TableEncodingError>,
+    ) -> Result<R, VeroTypeError> {
+        traced(tag, metadata, || Ok(f(&metadata.slice(self)?)?))
+    }
+
+    fn parse<T: Table>(&mut self, metadata: &TableMetadata) -> Result<T, VeroTypeError> {
+        traced(T::TAG, metadata, || {
+            Ok(T::parse_shared(&metadata.slice(self)?)?)
+        })
+    }
 }
 
 /// Represents the offset subtable directory and it's metadata
 /// providing us with a important info such as the number of tables
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct OffsetTable {
     scalar_type: u32,
     num_tables: u16,
@@ -68,6 +408,19 @@ pub struct OffsetTable {
     range_shift: u16,
 }
 
+impl Default for OffsetTable {
+    /// The offset table of a TrueType font without any table
+    fn default() -> Self {
+        Self {
+            scalar_type: 0x0001_0000,
+            num_tables: 0,
+            search_range: 0,
+            entry_selector: 0,
+            range_shift: 0,
+        }
+    }
+}
+
 impl OffsetTable {
     /// Constructs the offset sub table from a raw buffer
     /// the offset sub table buffer size must be 12 per the reference manual.
@@ -87,13 +440,42 @@ impl OffsetTable {
 
     /// Parses an offset table completely from a reader reference
     /// which reads the WHOLE file
+    #[cfg(feature = "std")]
     pub(crate) fn from_reader<B: Read + Seek>(
         reader: &mut VeroBufReader<B>,
     ) -> Result<Self, VeroTypeError> {
-        // since we know it's a fixed size of 12 we can seek to byte 0 and read exactly
+        Self::from_reader_at(reader, 0)
+    }
+
+    /// Parses an offset table which starts `offset` bytes into the file,
+    /// such as the i-th font of a collection or sfnt data embedded in another container.
+    ///
+    /// The reader is left right after the offset table, where the table directory starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use vero_type::{buffer::VeroBufReader, tables::OffsetTable};
+    ///
+    /// // 4 bytes of an unrelated header, then an offset table of a font with 2 tables
+    /// let data = [0xFF, 0xFF, 0xFF, 0xFF, 0, 1, 0, 0, 0, 2, 0, 32, 0, 1, 0, 0];
+    /// let mut reader = VeroBufReader::from_buffer(Cursor::new(data));
+    ///
+    /// let offset_table = OffsetTable::from_reader_at(&mut reader, 4).unwrap();
+    /// assert_eq!(offset_table.scalar_type(), 0x0001_0000);
+    /// assert_eq!(offset_table.num_tables(), 2);
+    /// assert_eq!(reader.position().unwrap(), 16);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_reader_at<B: Read + Seek>(
+        reader: &mut VeroBufReader<B>,
+        offset: u64,
+    ) -> Result<Self, VeroTypeError> {
+        // since we know it's a fixed size of 12 we can seek to the offset and read exactly
         // 12 bytes in order to get the buffer
         // then we can use from_buffer
-        reader.seek_to(0)?;
+        reader.seek_to(offset)?;
 
         // Allocate the fixed-size buffer of 12 bytes
         let mut buffer = [0u8; 12];
@@ -102,23 +484,54 @@ impl OffsetTable {
         Ok(Self::from_buffer(&buffer)?)
     }
 
+    /// Returns the scalar type (sfnt version) of the font file
+    /// 0x00010000 or 'true' for TrueType outlines, 'OTTO' for CFF outlines
+    pub fn scalar_type(&self) -> u32 {
+        self.scalar_type
+    }
+
     /// Returns the number of tables exists in the font file
     pub fn num_tables(&self) -> u16 {
         self.num_tables
     }
+
+    /// Returns the size of the offset table and the directory entries following it,
+    /// 12 + 16 * num_tables bytes, the table data starts no sooner than this
+    pub fn directory_byte_size(&self) -> u32 {
+        12 + 16 * u32::from(self.num_tables)
+    }
+
+    /// Returns the search range, (maximum power of 2 <= num_tables) * 16
+    pub fn search_range(&self) -> u16 {
+        self.search_range
+    }
+
+    /// Returns the entry selector, log2(maximum power of 2 <= num_tables)
+    pub fn entry_selector(&self) -> u16 {
+        self.entry_selector
+    }
+
+    /// Returns the range shift, num_tables * 16 - search_range
+    pub fn range_shift(&self) -> u16 {
+        self.range_shift
+    }
 }
 
 /// Represents all of the tables and their respective data types.
 #[derive(Debug)]
 pub struct Tables {
     /// The offset table, which provides the starting offsets of other tables.
-    pub offset: OffsetTable,
+    offset: OffsetTable,
 
     /// The headers of the tables
-    pub headers: TablesHeaders,
+    headers: TablesHeaders,
+
+    /// The head table, only `None` when parsed leniently and the table failed to parse
+    head_table: Option<Head>,
 
-    /// The head table
-    pub head_table: Head,
+    /// The errors of the tables which failed to parse in lenient mode,
+    /// always empty in strict mode
+    warnings: Vec<VeroTypeError>,
 }
 
 impl Tables {
@@ -136,59 +549,428 @@ impl Tables {
     /// # Examples
     ///
     /// ```no_run
-    /// // Assuming you have a VeroBufReader instance named 'reader'
-    /// // and an OffsetTable struct defined elsewhere.
-    /// # use std::io::{Cursor, Read, Seek};
-    /// # use vero_buf_reader::VeroBufReader;
-    /// #
-    /// # #[derive(Debug)]
-    /// # struct OffsetTable {}
-    /// #
-    /// # impl OffsetTable {
-    /// #     pub fn from_reader<B: Read + Seek>(reader: &mut VeroBufReader<B>) -> Result<Self, VeroTypeError> {
-    /// #         // Implementation to read the offset table
-    /// #         Ok(OffsetTable {})
-    /// #     }
-    /// # }
-    /// #
-    /// # #[derive(Debug)]
-    /// # enum VeroTypeError {
-    /// #     OffsetTableError,
-    /// #     IoError(std::io::Error),
-    /// # }
-    /// #
-    /// # fn main() -> Result<(), VeroTypeError> {
-    /// #     let data = vec![0u8; 12]; // Example data for the offset table
-    /// #     let cursor = Cursor::new(data);
-    /// #     let mut reader = VeroBufReader::from_buffer(cursor);
-    ///     let tables_result = Tables::from_reader(&mut reader);
-    ///
-    ///     match tables_result {
-    ///         Ok(tables) => {
-    ///             println!("Successfully parsed tables: {:?}", tables);
-    ///         }
-    ///         Err(e) => {
-    ///             eprintln!("Error parsing tables: {:?}", e);
-    ///         }
+    /// use std::fs::File;
+    /// use vero_type::{buffer::VeroBufReader, tables::Tables};
+    ///
+    /// let file = File::open("font.ttf").unwrap();
+    /// let mut reader = VeroBufReader::from_buffer(file);
+    /// let tables_result = Tables::from_reader(&mut reader);
+    ///
+    /// match tables_result {
+    ///     Ok(tables) => {
+    ///         println!("Successfully parsed tables: {:?}", tables);
+    ///     }
+    ///     Err(e) => {
+    ///         eprintln!("Error parsing tables: {:?}", e);
     ///     }
-    /// #     Ok(())
-    /// # }
+    /// }
     /// ```
+    #[cfg(feature = "std")]
     pub fn from_reader<B: Read + Seek>(
         reader: &mut VeroBufReader<B>,
+    ) -> Result<Self, VeroTypeError> {
+        Self::from_reader_with_options(reader, ParseOptions::default())
+    }
+
+    /// Constructs a `Tables` instance like `Tables::from_reader`, with control
+    /// over how a table which fails to parse is handled.
+    ///
+    /// In strict mode the first failing table aborts the parse, in lenient mode
+    /// the table is recorded as `None` and it's error is collected into `warnings`.
+    /// The offset table and the directory are always required, without them
+    /// there are no tables to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use vero_type::{
+    ///     buffer::VeroBufReader,
+    ///     tables::{ParseOptions, Tables},
+    /// };
+    ///
+    /// let file = File::open("font.ttf").unwrap();
+    /// let mut reader = VeroBufReader::from_buffer(file);
+    /// let tables = Tables::from_reader_with_options(&mut reader, ParseOptions { strict: false }).unwrap();
+    ///
+    /// for warning in tables.warnings() {
+    ///     eprintln!("skipped a malformed table: {warning}");
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_reader_with_options<B: Read + Seek>(
+        reader: &mut VeroBufReader<B>,
+        options: ParseOptions,
+    ) -> Result<Self, VeroTypeError> {
+        let mut tables = Self::directory_from_reader(reader)?;
+
+        tables.head_table = options.recover(
+            tables.headers.load::<Head, B>(reader),
+            &mut tables.warnings,
+        )?;
+
+        Ok(tables)
+    }
+
+    /// Reads only the offset table and the table directory, no table body is parsed
+    /// so `Tables::head` is always `None`.
+    ///
+    /// This is the fast path for scanning many fonts, read the directory then
+    /// `Tables::load` just the tables you're after.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use vero_type::{buffer::VeroBufReader, tables::{Tables, name::Name}};
+    ///
+    /// let file = File::open("font.ttf").unwrap();
+    /// let mut reader = VeroBufReader::from_buffer(file);
+    ///
+    /// let tables = Tables::directory_from_reader(&mut reader).unwrap();
+    /// let name = tables.load::<Name, _>(&mut reader).unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn directory_from_reader<B: Read + Seek>(
+        reader: &mut VeroBufReader<B>,
     ) -> Result<Self, VeroTypeError> {
         let offset_table = OffsetTable::from_reader(reader)?;
         let headers = TablesHeaders::from_reader(reader, offset_table.num_tables())?;
-        let head_table = Head::from_reader(reader, headers.get(RequiredTables::Head).unwrap())?;
-        let name_table = Name::from_reader(reader, headers.get(RequiredTables::Name).unwrap())?;
-        
-        println!("{:?}", name_table);
+
+        Ok(Self {
+            offset: offset_table,
+            head_table: None,
+            headers,
+            warnings: Vec::new(),
+        })
+    }
+
+    /// Parses the offset table, the table directory and the head table out of
+    /// a shared buffer holding the whole font, the counterpart of `Tables::from_reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `VeroTypeError` if the directory or the head table is
+    /// malformed, lies outside of the buffer or the head table is missing.
+    pub fn from_shared(data: &SharedBytes) -> Result<Self, VeroTypeError> {
+        let offset_table = OffsetTable::from_buffer(
+            data.get(0..12)
+                .ok_or(TableEncodingError::InvalidBufferLength(12, data.len()))?,
+        )?;
+
+        let end_of_directory = offset_table.directory_byte_size() as usize;
+        let headers = TablesHeaders::from_buffer(data.get(12..end_of_directory).ok_or(
+            TableEncodingError::DirectoryTooLarge {
+                declared: offset_table.num_tables(),
+                available: (data.len() as u64 - 12) / 16,
+            },
+        )?)?;
+
+        let head_table = data
+            .clone()
+            .parse::<Head>(headers.require(RequiredTables::Head)?)?;
+
         Ok(Self {
             offset: offset_table,
-            head_table,
+            head_table: Some(head_table),
             headers,
+            warnings: Vec::new(),
         })
     }
+
+    /// Parses a table out of the shared buffer holding the whole font, tables which
+    /// keep a part of their bytes around hold a view into `data` rather than a copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::MissingRequiredTable` (or `MissingTable` for
+    /// tables which aren't required) if the directory doesn't list the table,
+    /// otherwise errors when the table lies outside of `data` or is malformed.
+    pub fn load_shared<T: Table>(&self, data: &SharedBytes) -> Result<T, VeroTypeError> {
+        data.clone().parse(self.headers.require_tag(T::TAG)?)
+    }
+
+    /// Reads and parses a table through it's `Table` implementation.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::MissingRequiredTable` (or `MissingTable` for
+    /// tables which aren't required) if the directory doesn't list the table,
+    /// otherwise errors when reading fails or the table is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use vero_type::{buffer::VeroBufReader, tables::{Tables, name::Name}};
+    ///
+    /// let file = File::open("font.ttf").unwrap();
+    /// let mut reader = VeroBufReader::from_buffer(file);
+    /// let tables = Tables::from_reader(&mut reader).unwrap();
+    ///
+    /// let name = tables.load::<Name, _>(&mut reader).unwrap();
+    /// println!("{} name records", name.records().len());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn load<T: Table, B: Read + Seek>(
+        &self,
+        reader: &mut VeroBufReader<B>,
+    ) -> Result<T, VeroTypeError> {
+        self.headers.load(reader)
+    }
+
+    /// Computes the head table's `checksum_adjustment` the whole font calls for,
+    /// `0xB1B0AFBA` minus the checksum of the whole file with the field itself
+    /// taken as zero. A font is intact when it matches `Head::checksum_adjustment`.
+    ///
+    /// The file is streamed through the reader a chunk at a time rather than
+    /// buffered, so it doesn't cost a font sized allocation. The reader is left
+    /// at the end of the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::MissingRequiredTable` if the directory doesn't
+    /// list a head table, or a `VeroTypeError` if reading fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use vero_type::{buffer::VeroBufReader, tables::Tables};
+    ///
+    /// let mut reader = VeroBufReader::from_buffer(File::open("font.ttf").unwrap());
+    /// let tables = Tables::from_reader(&mut reader).unwrap();
+    ///
+    /// let intact = tables.checksum_adjustment(&mut reader).unwrap()
+    ///     == tables.head().unwrap().checksum_adjustment();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn checksum_adjustment<B: Read + Seek>(
+        &self,
+        reader: &mut VeroBufReader<B>,
+    ) -> Result<u32, VeroTypeError> {
+        /// The bytes summed per read, a multiple of 4 so every chunk starts on a word
+        const CHUNK_LEN: usize = 64 * 1024;
+
+        let head = self.headers.require(RequiredTables::Head)?;
+        let field = u64::from(head.offset()) + CHECKSUM_ADJUSTMENT_OFFSET as u64;
+        let field = field..field + 4;
+
+        let len = reader.len()?;
+        reader.seek_to(0)?;
+
+        let mut chunk = vec![0u8; CHUNK_LEN];
+        let mut sum = 0u32;
+        let mut start = 0u64;
+
+        while start < len {
+            let chunk_len = (len - start).min(CHUNK_LEN as u64) as usize;
+            let chunk = &mut chunk[..chunk_len];
+            reader.read_exact(chunk)?;
+
+            // Zero whatever part of the adjustment field lies within this chunk
+            let end = start + chunk_len as u64;
+            let (from, to) = (field.start.max(start), field.end.min(end));
+            if from < to {
+                chunk[(from - start) as usize..(to - start) as usize].fill(0);
+            }
+
+            sum = sum.wrapping_add(TableMetadata::recompute_checksum(chunk));
+            start = end;
+        }
+
+        Ok(CHECKSUM_MAGIC.wrapping_sub(sum))
+    }
+
+    /// Verifies the checksum of every table in the directory, listing each tag in
+    /// directory order with whether it's stored checksum matches the recomputed one,
+    /// see `TableMetadata::verify_checksum`.
+    ///
+    /// A table which can't be read (lying past the end of the font, ...) is reported
+    /// as not matching, so the report always covers the whole directory.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use vero_type::{buffer::VeroBufReader, tables::Tables};
+    ///
+    /// let mut reader = VeroBufReader::from_buffer(File::open("font.ttf").unwrap());
+    /// let tables = Tables::directory_from_reader(&mut reader).unwrap();
+    ///
+    /// for (tag, valid) in tables.checksum_report(&mut reader) {
+    ///     println!("{tag}: {}", if valid { "ok" } else { "mismatch" });
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn checksum_report<B: Read + Seek>(
+        &self,
+        reader: &mut VeroBufReader<B>,
+    ) -> Vec<(Tag, bool)> {
+        self.report_checksums(|tag, metadata| {
+            let bytes = metadata.read_bytes(reader).ok()?;
+
+            Some(metadata.verify_checksum(tag, &bytes))
+        })
+    }
+
+    /// Like `Tables::checksum_report`, reading the tables out of the shared
+    /// buffer holding the whole font.
+    pub fn checksum_report_shared(&self, data: &SharedBytes) -> Vec<(Tag, bool)> {
+        self.report_checksums(|tag, metadata| {
+            let bytes = metadata.slice(data).ok()?;
+
+            Some(metadata.verify_checksum(tag, &bytes))
+        })
+    }
+
+    /// Lists every table of the directory with the outcome of `verify`,
+    /// `None` meaning the table couldn't be read
+    fn report_checksums(
+        &self,
+        mut verify: impl FnMut(Tag, &TableMetadata) -> Option<bool>,
+    ) -> Vec<(Tag, bool)> {
+        self.headers
+            .tags()
+            .iter()
+            .filter_map(|&tag| {
+                let metadata = self.headers.get_by_tag(tag)?;
+
+                Some((tag, verify(tag, metadata).unwrap_or(false)))
+            })
+            .collect()
+    }
+
+    /// Returns a bounded `Read + Seek` view over the raw bytes of a single table,
+    /// useful for feeding tables this crate doesn't parse (`CFF `, `SVG `, ...)
+    /// into a specialized parser without slicing them out by hand.
+    ///
+    /// The view takes it's positions relative to the start of the table and never
+    /// reads past the end of it. `reader` is any reader over the whole font,
+    /// a `&mut File` works as well as an owned one.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::MissingRequiredTable` (or `MissingTable` for
+    /// tables which aren't required) if the directory doesn't list the table.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::{fs::File, io::Read};
+    /// use vero_type::{buffer::VeroBufReader, tables::Tables};
+    ///
+    /// let mut file = File::open("font.otf").unwrap();
+    /// let tables = Tables::directory_from_reader(&mut VeroBufReader::from_buffer(&mut file)).unwrap();
+    ///
+    /// let mut cff = Vec::new();
+    /// tables.table_reader(b"CFF ", &mut file).unwrap().read_to_end(&mut cff).unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn table_reader<B: Read + Seek>(
+        &self,
+        tag: impl Into<Tag>,
+        reader: B,
+    ) -> Result<TableReader<B>, VeroTypeError> {
+        let metadata = self.headers.require_tag(tag.into())?;
+
+        Ok(TableReader::new(
+            reader,
+            metadata.offset().into(),
+            metadata.length().into(),
+        ))
+    }
+
+    /// Returns the offset table
+    pub fn offset(&self) -> &OffsetTable {
+        &self.offset
+    }
+
+    /// Returns the table directory
+    pub fn headers(&self) -> &TablesHeaders {
+        &self.headers
+    }
+
+    /// Returns the table directory mutably, to remove tables before re-emitting
+    /// the font with `FontWriter::from_tables`
+    pub fn headers_mut(&mut self) -> &mut TablesHeaders {
+        &mut self.headers
+    }
+
+    /// Returns the head table, only `None` when parsed leniently and the table
+    /// failed to parse, or when only the directory was read
+    pub fn head(&self) -> Option<&Head> {
+        self.head_table.as_ref()
+    }
+
+    /// Returns the errors of the tables which failed to parse in lenient mode,
+    /// always empty in strict mode
+    pub fn warnings(&self) -> &[VeroTypeError] {
+        &self.warnings
+    }
+
+    /// Checks that the outline tables agree with the sfnt version, a font claiming
+    /// CFF outlines with `OTTO` mustn't carry `glyf` or `loca` tables and a font
+    /// claiming TrueType outlines mustn't carry `CFF ` or `CFF2` tables.
+    ///
+    /// Fonts which were badly converted between the two formats trip this check.
+    /// Other sfnt versions, such as Apple's `typ1`, aren't checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TableEncodingError::OutlineFormatMismatch` with the first table in
+    /// the directory which doesn't belong to the claimed outline format.
+    pub fn validate_outline_format(&self) -> Result<(), TableEncodingError> {
+        let sfnt_version = self.offset.scalar_type();
+        let foreign = match &sfnt_version.to_be_bytes() {
+            b"OTTO" => [Tag::new(*b"glyf"), Tag::new(*b"loca")],
+            [0x00, 0x01, 0x00, 0x00] | b"true" => [Tag::new(*b"CFF "), Tag::new(*b"CFF2")],
+            _ => return Ok(()),
+        };
+
+        match self.headers.tags().iter().find(|tag| foreign.contains(tag)) {
+            Some(&table) => Err(TableEncodingError::OutlineFormatMismatch {
+                sfnt_version,
+                table,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Controls how `Tables::from_reader_with_options` handles tables which fail to parse
+#[derive(Debug, Clone, Copy)]
+pub struct ParseOptions {
+    /// When `true` the first table which fails to parse aborts the whole parse,
+    /// when `false` the table is skipped and it's error is collected as a warning
+    pub strict: bool,
+}
+
+impl Default for ParseOptions {
+    /// Strict parsing, matching `Tables::from_reader`
+    fn default() -> Self {
+        Self { strict: true }
+    }
+}
+
+impl ParseOptions {
+    /// Applies the strictness to the outcome of a table parse, in lenient mode
+    /// an error is pushed onto `warnings` and the table is recorded as `None`
+    #[cfg(feature = "std")]
+    pub(crate) fn recover<T>(
+        &self,
+        result: Result<T, VeroTypeError>,
+        warnings: &mut Vec<VeroTypeError>,
+    ) -> Result<Option<T>, VeroTypeError> {
+        match result {
+            Ok(table) => Ok(Some(table)),
+            Err(error) if !self.strict => {
+                warnings.push(error);
+                Ok(None)
+            }
+            Err(error) => Err(error),
+        }
+    }
 }
 
 /// Represents the table headers and maps a table tag to it's offset
@@ -199,6 +981,13 @@ pub struct TablesHeaders {
     /// RequiredTables enum and it's Metadata, the metadata disgards the tag field
     /// as it's represented as the key of the entry.
     inner: BTreeMap<RequiredTables, TableMetadata>,
+
+    /// The metadata of the tables which aren't required, keyed by their tag
+    others: BTreeMap<Tag, TableMetadata>,
+
+    /// The tags of every table in the directory, required or not,
+    /// in the order they appear in the directory
+    tags: Vec<Tag>,
 }
 
 impl TablesHeaders {
@@ -210,7 +999,7 @@ impl TablesHeaders {
     ///
     /// The method parses each 16-byte chunk into `TableMetadata` and stores it in a `BTreeMap`,
     /// keyed by the corresponding `RequiredTables` enum variant. Tables with tags that do not match
-    /// any variant of `RequiredTables` are currently ignored (see TODO in the code).
+    /// any variant of `RequiredTables` are kept separately, see `TablesHeaders::get_by_tag`.
     ///
     /// # Arguments
     ///
@@ -227,17 +1016,19 @@ impl TablesHeaders {
     /// * **`TableMetadata::from_buffer` Error:** If an error occurs while parsing a 16-byte chunk
     ///   into a `TableMetadata` instance. This could indicate an issue with the format of the table
     ///   header data.
-    /// * **`RequiredTables::try_from` Error:** Although the current implementation doesn't explicitly
-    ///   propagate this as an error, the `try_from` conversion from the 4-byte tag to `RequiredTables`
-    ///   might fail if the tag is not recognized. In the current code, such tables are skipped.
+    /// * **`TableEncodingError::DirectoryTooLarge`:** If the reader doesn't have `num_tables`
+    ///   entries left, checked before the directory is allocated so a corrupt count is cheap.
+    /// * **`TableEncodingError::DuplicateTable`:** If the same tag is listed more than once,
+    ///   since there's no telling which of the entries is the genuine one.
     ///
     /// # Returns
     ///
     /// A `Result` containing:
     ///
-    /// * `Ok(Self)`: A new `TablesHeaders` instance containing a `BTreeMap` of `TableMetadata`,
-    ///   keyed by `RequiredTables`.
+    /// * `Ok(Self)`: A new `TablesHeaders` instance containing the `TableMetadata` of every
+    ///   table, the required ones keyed by `RequiredTables` and the others by their tag.
     /// * `Err(VeroTypeError)`: An error that occurred during the process.
+    #[cfg(feature = "std")]
     pub fn from_reader<B: Read + Seek>(
         reader: &mut VeroBufReader<B>,
         num_tables: u16,
@@ -247,29 +1038,68 @@ impl TablesHeaders {
         // to get the buffer of all of the tables we would need
         // to multiply the num_tables by 16 bytes
         // then process the headers in chuncks of 16 bytes
+        // A corrupt count is caught before allocating rather than by a failing read
+        let available = reader.bytes_remaining()? / 16;
+        if u64::from(num_tables) > available {
+            return Err(TableEncodingError::DirectoryTooLarge {
+                declared: num_tables,
+                available,
+            }
+            .into());
+        }
+
         let mut buffer = vec![0u8; usize::from(num_tables) * 16];
         reader.read_exact(&mut buffer)?;
 
+        Self::from_buffer(&buffer)
+    }
+
+    /// Constructs a `TablesHeaders` instance from the raw directory entries,
+    /// the 16 byte entries which follow the offset table.
+    ///
+    /// # Errors
+    ///
+    /// Errors if an entry is truncated or a tag is listed more than once,
+    /// see `TablesHeaders::from_reader`.
+    pub fn from_buffer(buffer: &[u8]) -> Result<Self, VeroTypeError> {
+        let num_tables = buffer.len() / 16;
+
         // Initialize the headers binary tree map
         let mut headers: BTreeMap<RequiredTables, TableMetadata> = BTreeMap::new();
+        let mut others: BTreeMap<Tag, TableMetadata> = BTreeMap::new();
+        let mut tags = Vec::with_capacity(num_tables);
 
         // divide the buffer into chunks of 16 bytes where every entry is a different table
-        let chunks = buffer.chunks(16).collect::<Vec<&[u8]>>();
+        let chunks = exact_chunks(buffer, 16)?;
 
         // Iterate over every raw table data and parse it to it's metadata
-        // TODO: Handle tables which are not required
+        let mut seen = BTreeSet::new();
+
         for raw_table in chunks {
-            let tag = &raw_table[0..4];
+            let tag = Tag::new(raw_table[0..4].try_into()?);
 
-            if let Ok(table_type) = RequiredTables::try_from(tag) {
-                let metadata = TableMetadata::from_buffer(raw_table)?;
+            // A tag listed twice is a sign of a corrupt or tampered directory,
+            // refuse to silently pick one of the entries
+            if !seen.insert(tag) {
+                return Err(TableEncodingError::DuplicateTable(tag).into());
+            }
+
+            tags.push(tag);
+            let metadata = TableMetadata::from_buffer(raw_table)?;
 
+            if let Ok(table_type) = RequiredTables::try_from(tag) {
                 // Add the entry to the headers BTreeMap
                 headers.insert(table_type, metadata);
+            } else {
+                others.insert(tag, metadata);
             }
         }
 
-        Ok(Self { inner: headers })
+        Ok(Self {
+            inner: headers,
+            others,
+            tags,
+        })
     }
 
     /// Retrieves the `TableMetadata` for a specific required table.
@@ -291,6 +1121,152 @@ impl TablesHeaders {
     pub fn get(&self, k: RequiredTables) -> Option<&TableMetadata> {
         self.inner.get(&k)
     }
+
+    /// Like `TablesHeaders::get`, but a missing table is a `MissingRequiredTable` error
+    pub(crate) fn require(&self, k: RequiredTables) -> Result<&TableMetadata, VeroTypeError> {
+        self.get(k)
+            .ok_or(TableEncodingError::MissingRequiredTable(k).into())
+    }
+
+    /// Reads the bytes of a table and parses them, see `Tables::load`
+    #[cfg(feature = "std")]
+    pub(crate) fn load<T: Table, B: Read + Seek>(
+        &self,
+        reader: &mut VeroBufReader<B>,
+    ) -> Result<T, VeroTypeError> {
+        let metadata = self.require_tag(T::TAG)?;
+
+        traced(T::TAG, metadata, || Ok(T::parse(&metadata.read_bytes(reader)?)?))
+    }
+
+    /// Like `TablesHeaders::get_by_tag`, but a missing table is a `MissingRequiredTable`
+    /// error, or `MissingTable` for tables which aren't required
+    pub(crate) fn require_tag(&self, tag: Tag) -> Result<&TableMetadata, VeroTypeError> {
+        match RequiredTables::try_from(tag) {
+            Ok(table) => self.require(table),
+            Err(()) => self
+                .others
+                .get(&tag)
+                .ok_or(TableEncodingError::MissingTable(tag).into()),
+        }
+    }
+
+    /// Retrieves the `TableMetadata` of any table, required or not, by it's tag.
+    pub fn get_by_tag(&self, tag: impl Into<Tag>) -> Option<&TableMetadata> {
+        let tag = tag.into();
+
+        match RequiredTables::try_from(tag) {
+            Ok(table) => self.get(table),
+            Err(()) => self.others.get(&tag),
+        }
+    }
+
+    /// Returns the tag of every table in the directory in directory order,
+    /// including the tables which aren't required.
+    pub fn tags(&self) -> &[Tag] {
+        &self.tags
+    }
+
+    /// Checks the directory as a whole, every table has to lie within the
+    /// `file_len` bytes of the file and no two tables may share a byte.
+    ///
+    /// Every problem is reported rather than just the first one, tables past the end
+    /// of the file in directory order and then the overlapping pairs in file order.
+    /// Empty tables never overlap. An empty result means the directory is consistent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::{TableEncodingError, TablesHeaders};
+    ///
+    /// let mut directory = Vec::new();
+    /// // (tag, checksum, offset, length), the name table starts within the cmap table
+    /// for (tag, offset, length) in [(b"cmap", 44u32, 8u32), (b"name", 48, 6)] {
+    ///     directory.extend_from_slice(tag);
+    ///     directory.extend_from_slice(&0u32.to_be_bytes());
+    ///     directory.extend_from_slice(&offset.to_be_bytes());
+    ///     directory.extend_from_slice(&length.to_be_bytes());
+    /// }
+    /// let headers = TablesHeaders::from_buffer(&directory).unwrap();
+    ///
+    /// let errors = headers.validate_ranges(52);
+    /// assert!(matches!(
+    ///     errors.as_slice(),
+    ///     [
+    ///         TableEncodingError::TableOutOfBounds { end: 54, .. },
+    ///         TableEncodingError::OverlappingTables(cmap, name),
+    ///     ] if *cmap == "cmap" && *name == "name"
+    /// ));
+    /// ```
+    pub fn validate_ranges(&self, file_len: u64) -> Vec<TableEncodingError> {
+        let mut errors = Vec::new();
+        let mut ranges = Vec::with_capacity(self.tags.len());
+
+        for &tag in &self.tags {
+            let Some(metadata) = self.get_by_tag(tag) else {
+                continue;
+            };
+
+            let start = u64::from(metadata.offset());
+            let end = start + u64::from(metadata.length());
+
+            if end > file_len {
+                errors.push(TableEncodingError::TableOutOfBounds {
+                    tag,
+                    end,
+                    file_len,
+                });
+            }
+
+            if start < end {
+                ranges.push((start, end, tag));
+            }
+        }
+
+        // In file order a table overlaps with an earlier one exactly when it
+        // starts before the furthest end seen so far
+        ranges.sort_unstable();
+        let mut furthest: Option<(u64, Tag)> = None;
+
+        for (start, end, tag) in ranges {
+            if let Some((furthest_end, furthest_tag)) = furthest {
+                if start < furthest_end {
+                    errors.push(TableEncodingError::OverlappingTables(furthest_tag, tag));
+                }
+
+                if end <= furthest_end {
+                    continue;
+                }
+            }
+
+            furthest = Some((end, tag));
+        }
+
+        errors
+    }
+
+    /// Removes a table from the directory, returning it's metadata if it was listed.
+    ///
+    /// Only the directory is changed, the table's bytes stay where they are in the
+    /// font. Combined with `FontWriter::from_tables` it strips tables out of a font,
+    /// such as the `fpgm`, `prep`, `cvt ` and `gasp` hinting tables.
+    pub fn remove(&mut self, tag: impl Into<Tag>) -> Option<TableMetadata> {
+        let tag = tag.into();
+        let metadata = match RequiredTables::try_from(tag) {
+            Ok(table) => self.inner.remove(&table),
+            Err(()) => self.others.remove(&tag),
+        };
+
+        self.tags.retain(|&listed| listed != tag);
+        metadata
+    }
+
+    /// Iterates over the required tables listed in the directory along with
+    /// their metadata, in the order of `RequiredTables`. The other tables are
+    /// reached through `TablesHeaders::tags` and `TablesHeaders::get_by_tag`.
+    pub fn iter(&self) -> Iter<'_, RequiredTables, TableMetadata> {
+        self.inner.iter()
+    }
 }
 
 impl IntoIterator for TablesHeaders {
@@ -303,8 +1279,18 @@ impl IntoIterator for TablesHeaders {
     }
 }
 
+impl<'a> IntoIterator for &'a TablesHeaders {
+    type Item = (&'a RequiredTables, &'a TableMetadata);
+
+    type IntoIter = Iter<'a, RequiredTables, TableMetadata>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
 /// Represents metadata for a table within a larger data structure.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, Default)]
 pub struct TableMetadata {
     /// The checksum of the table. This value can be used to verify the
     /// integrity of the table data.
@@ -338,20 +1324,20 @@ impl TableMetadata {
     /// # Examples
     ///
     /// ```
-    /// use vero_buf_reader::TableEncodingError;
+    /// use vero_type::tables::TableMetadata;
     ///
     /// let buffer: [u8; 16] = [
     ///     0x00, 0x00, 0x00, 0x00, // Reserved
     ///     0x12, 0x34, 0x56, 0x78, // Checksum: 0x12345678
-    ///     0x00, 0x01, 0x00, 0x00, // Offset: 0x00010000 (4096)
+    ///     0x00, 0x01, 0x00, 0x00, // Offset: 0x00010000 (65536)
     ///     0x00, 0x00, 0x0A, 0x00, // Length: 0x00000A00 (2560)
     /// ];
     ///
     /// match TableMetadata::from_buffer(&buffer) {
     ///     Ok(metadata) => {
-    ///         assert_eq!(metadata.checksum, 0x12345678);
-    ///         assert_eq!(metadata.offset, 0x00010000);
-    ///         assert_eq!(metadata.length, 0x00000A00);
+    ///         assert_eq!(metadata.checksum(), 0x12345678);
+    ///         assert_eq!(metadata.offset(), 0x00010000);
+    ///         assert_eq!(metadata.length(), 0x00000A00);
     ///     }
     ///     Err(e) => {
     ///         eprintln!("Error creating TableMetadata: {:?}", e);
@@ -373,4 +1359,125 @@ impl TableMetadata {
             length: u32::from_be_bytes(buf[12..16].try_into().unwrap()),
         })
     }
+
+    /// Computes the checksum of a table's bytes, as stored in the table directory.
+    ///
+    /// The bytes are zero padded to a 4 byte boundary and summed as big-endian
+    /// `u32` values with wrapping addition. After editing a table this is the
+    /// value its directory entry needs to be updated with.
+    ///
+    /// Note that the head table's checksum is computed with it's
+    /// `checksum_adjustment` field set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::TableMetadata;
+    ///
+    /// // 0x00010002 + 0x03000000 (the trailing byte padded with zeros)
+    /// assert_eq!(TableMetadata::recompute_checksum(&[0, 1, 0, 2, 3]), 0x03010002);
+    ///
+    /// // the sum wraps around instead of overflowing
+    /// assert_eq!(TableMetadata::recompute_checksum(&[0xFF; 8]), 0xFFFFFFFE);
+    /// ```
+    pub fn recompute_checksum(table_bytes: &[u8]) -> u32 {
+        table_bytes.chunks(4).fold(0u32, |sum, chunk| {
+            let mut word = [0u8; 4];
+            word[..chunk.len()].copy_from_slice(chunk);
+
+            sum.wrapping_add(u32::from_be_bytes(word))
+        })
+    }
+
+    /// Checks if the checksum stored in the directory matches the one
+    /// recomputed out of the table's bytes, see `TableMetadata::recompute_checksum`.
+    ///
+    /// The head table's `checksum_adjustment` is left out of the sum, it's
+    /// written after the checksum of the head table is computed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::TableMetadata;
+    ///
+    /// // (tag, checksum, offset, length)
+    /// let entry = [*b"name", 0x00010002u32.to_be_bytes(), [0; 4], 8u32.to_be_bytes()].concat();
+    /// let metadata = TableMetadata::from_buffer(&entry).unwrap();
+    ///
+    /// assert!(metadata.verify_checksum(*b"name", &[0, 1, 0, 2, 0, 0, 0, 0]));
+    /// assert!(!metadata.verify_checksum(*b"name", &[0, 1, 0, 3, 0, 0, 0, 0]));
+    /// ```
+    pub fn verify_checksum(&self, tag: impl Into<Tag>, table_bytes: &[u8]) -> bool {
+        let mut checksum = Self::recompute_checksum(table_bytes);
+
+        // checksum_adjustment is the third aligned word of the head table
+        if tag.into() == Head::TAG
+            && let Ok(adjustment) = be_u32(table_bytes, 8)
+        {
+            checksum = checksum.wrapping_sub(adjustment);
+        }
+
+        checksum == self.checksum
+    }
+
+    /// Reads the bytes of the table out of the font
+    #[cfg(feature = "std")]
+    pub(crate) fn read_bytes<B: Read + Seek>(
+        &self,
+        reader: &mut VeroBufReader<B>,
+    ) -> Result<Vec<u8>, VeroTypeError> {
+        let range = self.range()?;
+
+        // Refuse a table running past the end of the stream before
+        // allocating a buffer as large as an adversarial length asks for
+        let stream_len = reader.len()?;
+        if range.end as u64 > stream_len {
+            return Err(TableEncodingError::InvalidBufferLength(
+                range.end,
+                usize::try_from(stream_len).unwrap_or(usize::MAX),
+            )
+            .into());
+        }
+
+        reader.seek_to(self.offset.into())?;
+        let mut buf = vec![0u8; range.len()];
+
+        reader.read_exact(&mut buf)?;
+
+        Ok(buf)
+    }
+
+    /// Returns a view of the table's bytes in the shared buffer holding the whole font
+    pub(crate) fn slice(&self, data: &SharedBytes) -> Result<SharedBytes, TableEncodingError> {
+        let range = self.range()?;
+        let end = range.end;
+
+        data.slice(range)
+            .ok_or(TableEncodingError::InvalidBufferLength(end, data.len()))
+    }
+
+    /// Returns the range of bytes the table spans within the font file
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::RangeOverflow` if the end of the table
+    /// doesn't fit in a `usize`, which can only happen on 32 bit platforms.
+    pub fn range(&self) -> Result<Range<usize>, TableEncodingError> {
+        checked_range(self.offset, self.length)
+    }
+
+    /// Returns the checksum of the table
+    pub fn checksum(&self) -> u32 {
+        self.checksum
+    }
+
+    /// Returns the offset of the table from the beginning of the file
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns the length of the table in bytes (excluding padding)
+    pub fn length(&self) -> u32 {
+        self.length
+    }
 }
@@ -1,8 +1,15 @@
-use std::io::{Read, Seek};
+use alloc::{string::String, vec::Vec};
+use core::cmp::Ordering;
 
-use crate::{VeroTypeError, buffer::VeroBufReader};
+use crate::{shared::SharedBytes, tag::Tag};
 
-use super::TableMetadata;
+use super::{Table, TableEncodingError, be_u16, decode_utf16be, exact_chunks};
+
+/// The name id of the font family name, shared by at most 4 styles
+const FAMILY_NAME_ID: u16 = 1;
+
+/// The name id of the typographic family name, shared by every style of the family
+const TYPOGRAPHIC_FAMILY_NAME_ID: u16 = 16;
 
 /// Represents the [name table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6name.html)
 #[derive(Debug)]
@@ -19,51 +26,238 @@ pub struct Name {
     /// A vector of size <count> of the name records
     name_records: Vec<NameRecord>,
 
+    /// The language-tag records, only present in format 1 tables
+    lang_tag_records: Vec<LangTagRecord>,
+
     /// The name can't be represented as a String since
     /// there's no guarantee for it to be all valid ASCII chars
-    name: Vec<u8>,
+    name: SharedBytes,
+}
+
+impl Table for Name {
+    const TAG: Tag = Tag::new(*b"name");
+
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        Self::from_buffer(buf)
+    }
+
+    fn parse_shared(data: &SharedBytes) -> Result<Self, TableEncodingError> {
+        let (mut name, storage) = Self::read(data)?;
+        // `read` clamped the storage offset to the table
+        name.name = data.slice(storage..data.len()).unwrap_or_default();
+
+        Ok(name)
+    }
 }
 
 impl Name {
-    pub(crate) fn from_reader<B: Read + Seek>(
-        reader: &mut VeroBufReader<B>,
-        metadata: &TableMetadata,
-    ) -> Result<Self, VeroTypeError> {
-        reader.seek_to(metadata.offset.into())?;
-        let mut buf = vec![0u8; metadata.length as usize];
+    /// Constructs the name table from the raw table bytes.
+    ///
+    /// Parsing never panics on malformed input, the record array and the
+    /// string storage are bounds checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TableEncodingError::InvalidBufferLength` if the buffer can't
+    /// hold the 6 byte header (or the language-tag records of a format 1 table)
+    /// or the table ends in the middle of a name record,
+    /// `TableEncodingError::RecordCountMismatch` if the table holds fewer
+    /// records than the header declares,
+    /// or `TableEncodingError::UnsupportedNameFormat` for a format other than 0 or 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::name::Name;
+    ///
+    /// // format 0, no records, string storage right after the header
+    /// let name = Name::from_buffer(&[0, 0, 0, 0, 0, 6]).unwrap();
+    /// assert!(name.records().is_empty());
+    ///
+    /// // a truncated header is an error rather than a panic
+    /// assert!(Name::from_buffer(&[0, 0, 0]).is_err());
+    ///
+    /// // and so is a format this crate doesn't know
+    /// assert!(Name::from_buffer(&[0, 2, 0, 0, 0, 6]).is_err());
+    /// ```
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        let (mut name, storage) = Self::read(buf)?;
+        name.name = buf[storage..].into();
 
-        reader.read_exact(&mut buf)?;
+        Ok(name)
+    }
 
-        let format = u16::from_be_bytes(buf[0..2].try_into()?);
-        let count = u16::from_be_bytes(buf[2..4].try_into()?);
-        let string_offset = u16::from_be_bytes(buf[4..6].try_into()?);
+    /// Parses everything but the string storage, returning the table
+    /// with an empty storage and the offset the storage starts at
+    fn read(buf: &[u8]) -> Result<(Self, usize), TableEncodingError> {
+        if buf.len() < 6 {
+            return Err(TableEncodingError::InvalidBufferLength(6, buf.len()));
+        }
+
+        let format = match TableFormat::from(be_u16(buf, 0)?) {
+            TableFormat::Unknown(format) => {
+                return Err(TableEncodingError::UnsupportedNameFormat(format));
+            }
+            format => format,
+        };
+        let count = be_u16(buf, 2)?;
+        let string_offset = be_u16(buf, 4)?;
 
         // well, we know that a name record is 12 bytes, we also know where
         // the record array starts and where it ends by doing offset + (count * 12)
-        let end_of_array: usize = usize::from(6 + count * 12);
+        // a truncated table can't hold all of them, so clamp to the buffer
+        // and tell a record cut in half apart from missing records
+        let end_of_array = (6 + usize::from(count) * 12).min(buf.len());
         let array_buffer = &buf[6..end_of_array];
-        // TODO: look into safety
-        let records = array_buffer
-            .chunks(12)
+        let records = exact_chunks(array_buffer, 12)?
             .map(NameRecord::from_buffer)
-            .map(Result::unwrap)
-            .collect::<Vec<NameRecord>>();
-        
-        let string_buffer = &buf[end_of_array..];
-        
-        Ok(Self {
-            format: TableFormat::from(format),
+            .collect::<Result<Vec<NameRecord>, TableEncodingError>>()?;
+
+        // fewer records than the header declares is a classic corruption signature
+        if records.len() != usize::from(count) {
+            return Err(TableEncodingError::RecordCountMismatch {
+                expected: count,
+                found: records.len(),
+            });
+        }
+
+        // format 1 follows the name records with a count of language-tag records
+        // and the records themselves (4 bytes each)
+        let lang_tag_records = match format {
+            TableFormat::OpenType => {
+                let lang_tag_count = usize::from(be_u16(buf, end_of_array)?);
+                let lang_tag_start = end_of_array + 2;
+
+                (0..lang_tag_count)
+                    .map(|index| LangTagRecord::from_buffer(buf, lang_tag_start + index * 4))
+                    .collect::<Result<Vec<LangTagRecord>, TableEncodingError>>()?
+            }
+            _ => Vec::new(),
+        };
+
+        // The storage starts wherever the header says rather than right after the
+        // records, there may be padding in between. An offset past the end of the
+        // table leaves an empty storage which no record decodes out of.
+        let storage = usize::from(string_offset).min(buf.len());
+
+        let name = Self {
+            format,
             count,
             string_offset,
             name_records: records,
-            name: string_buffer.to_vec()
-        })
+            lang_tag_records,
+            name: SharedBytes::default(),
+        };
+
+        Ok((name, storage))
+    }
+
+    /// Returns the format of the name table
+    pub fn format(&self) -> &TableFormat {
+        &self.format
+    }
+
+    /// Returns the amount of name records declared in the table header,
+    /// always the length of `Name::records`
+    pub fn count(&self) -> u16 {
+        self.count
+    }
+
+    /// Returns the offset in bytes to the string storage
+    pub fn string_offset(&self) -> u16 {
+        self.string_offset
+    }
+
+    /// Returns the raw string storage, records point into it through
+    /// `NameRecord::raw_bytes` for callers who want to apply their own decoding.
+    pub fn string_data(&self) -> &[u8] {
+        &self.name
+    }
+
+    /// Returns the parsed name records
+    pub fn records(&self) -> &[NameRecord] {
+        &self.name_records
+    }
+
+    /// Returns the name records sorted by name id, then platform id, then language id,
+    /// a deterministic order whatever the order of the records in the file is
+    pub fn records_sorted(&self) -> Vec<&NameRecord> {
+        let mut records: Vec<_> = self.name_records.iter().collect();
+        records.sort();
+
+        records
+    }
+
+    /// Returns the language-tag records, empty unless the table is format 1
+    pub fn lang_tag_records(&self) -> &[LangTagRecord] {
+        &self.lang_tag_records
+    }
+
+    /// Returns the decoded BCP 47 language tags of a format 1 table
+    /// in the order they appear in the table, tags pointing outside
+    /// of the string storage are skipped.
+    pub fn language_tags(&self) -> Vec<String> {
+        self.lang_tag_records
+            .iter()
+            .filter_map(|record| record.decode(&self.name))
+            .collect()
+    }
+
+    /// Resolves the BCP 47 language tag of a name record's language id,
+    /// language ids starting at 0x8000 index into the language-tag records.
+    ///
+    /// Returns `None` for the predefined language ids and for unknown tags.
+    pub fn language_tag(&self, language_id: u16) -> Option<String> {
+        let index = usize::from(language_id.checked_sub(0x8000)?);
+
+        self.lang_tag_records.get(index)?.decode(&self.name)
+    }
+
+    /// Returns the decoded string of the first record matching `name_id`
+    /// which can be decoded, see `NameRecord::decode` for the supported encodings.
+    pub fn find(&self, name_id: u16) -> Option<String> {
+        self.name_records
+            .iter()
+            .filter(|record| record.name_id == name_id)
+            .find_map(|record| record.decode(&self.name))
+    }
+
+    /// Returns the decoded string of the record matching `name_id`,
+    /// preferring the English Windows record (3, _, 0x409), then the English
+    /// Macintosh Roman record (1, 0, 0) and then any record which can be decoded.
+    pub fn find_preferred(&self, name_id: u16) -> Option<String> {
+        let candidates = || {
+            self.name_records
+                .iter()
+                .filter(move |record| record.name_id == name_id)
+        };
+
+        candidates()
+            .filter(|record| record.is_windows_english())
+            .chain(candidates().filter(|record| record.is_mac_english()))
+            .chain(candidates())
+            .find_map(|record| record.decode(&self.name))
+    }
+
+    /// Returns the family name a font menu should group the font under, the
+    /// typographic family name (name id 16) if there's one and the legacy font
+    /// family name (name id 1) otherwise, each with the preference of `Name::find_preferred`.
+    ///
+    /// Legacy family names are limited to 4 styles so a family like "Open Sans" lists
+    /// it's semibold under an "Open Sans SemiBold" family, the typographic family name
+    /// puts it back under "Open Sans".
+    pub fn best_family_name(&self) -> Option<String> {
+        self.find_preferred(TYPOGRAPHIC_FAMILY_NAME_ID)
+            .or_else(|| self.find_preferred(FAMILY_NAME_ID))
     }
 }
 
-/// Represents a name record
-#[derive(Debug)]
-struct NameRecord {
+/// Represents a name record.
+///
+/// Records order by name id, then platform id, then language id, see
+/// `Name::records_sorted`. The encoding, length and offset only break ties.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NameRecord {
     /// Platform identifier code.
     platform_id: PlatformId,
 
@@ -85,29 +279,190 @@ struct NameRecord {
     offset: u16,
 }
 
+impl Ord for NameRecord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+impl PartialOrd for NameRecord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl NameRecord {
-    fn from_buffer(buf: &[u8]) -> Result<Self, VeroTypeError> {
+    fn from_buffer(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        let platform_id = PlatformId::from(be_u16(buf, 0)?);
+
         Ok(Self {
-            platform_id: PlatformId::from(u16::from_be_bytes(buf[0..2].try_into()?)),
-            platform_specific_id: PlatformSpecificId::from(u16::from_be_bytes(
-                buf[2..4].try_into()?,
-            )),
-            language_id: u16::from_be_bytes(buf[4..6].try_into()?),
-            name_id: u16::from_be_bytes(buf[6..8].try_into()?),
-            length: u16::from_be_bytes(buf[8..10].try_into()?),
-            offset: u16::from_be_bytes(buf[10..12].try_into()?),
+            platform_id,
+            platform_specific_id: PlatformSpecificId::from_platform(
+                platform_id,
+                be_u16(buf, 2)?,
+            ),
+            language_id: be_u16(buf, 4)?,
+            name_id: be_u16(buf, 6)?,
+            length: be_u16(buf, 8)?,
+            offset: be_u16(buf, 10)?,
         })
     }
+
+    /// Returns the platform identifier
+    pub fn platform_id(&self) -> &PlatformId {
+        &self.platform_id
+    }
+
+    /// Returns the platform-specific encoding identifier
+    pub fn platform_specific_id(&self) -> &PlatformSpecificId {
+        &self.platform_specific_id
+    }
+
+    /// Returns the language identifier
+    pub fn language_id(&self) -> u16 {
+        self.language_id
+    }
+
+    /// Returns the name identifier
+    pub fn name_id(&self) -> u16 {
+        self.name_id
+    }
+
+    /// Returns the name string length in bytes
+    pub fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// Returns the name string offset in bytes from the string storage
+    pub fn offset(&self) -> u16 {
+        self.offset
+    }
+
+    /// The fields in the order records are sorted by
+    fn sort_key(&self) -> (u16, u16, u16, u16, u16, u16) {
+        (
+            self.name_id,
+            self.platform_id.code(),
+            self.language_id,
+            self.platform_specific_id.code(),
+            self.length,
+            self.offset,
+        )
+    }
+
+    /// Checks if the record is a Windows record in US English
+    fn is_windows_english(&self) -> bool {
+        self.platform_id == PlatformId::Microsoft && self.language_id == 0x0409
+    }
+
+    /// Checks if the record is a Macintosh Roman record in English
+    fn is_mac_english(&self) -> bool {
+        self.platform_id == PlatformId::Macintosh
+            && self.platform_specific_id == PlatformSpecificId::MacScript(MacScript::Roman)
+            && self.language_id == 0
+    }
+
+    /// Returns the undecoded bytes of the record's string, sliced out of the
+    /// string storage (`Name::string_data`) without copying.
+    ///
+    /// Returns `None` if the record points outside of the storage.
+    pub fn raw_bytes<'a>(&self, pool: &'a [u8]) -> Option<&'a [u8]> {
+        let start = usize::from(self.offset);
+
+        pool.get(start..start + usize::from(self.length))
+    }
+
+    /// Decodes the record's string out of the name table string storage.
+    ///
+    /// Unicode and Microsoft records are UTF-16BE encoded and Macintosh records
+    /// with the Roman script are Mac OS Roman encoded, any other encoding or a
+    /// record pointing outside of the storage yields `None`.
+    pub fn decode(&self, storage: &[u8]) -> Option<String> {
+        let bytes = self.raw_bytes(storage)?;
+
+        match (&self.platform_id, &self.platform_specific_id) {
+            (PlatformId::Unicode, _) | (PlatformId::Microsoft, _) => Some(decode_utf16be(bytes)),
+            (PlatformId::Macintosh, PlatformSpecificId::MacScript(MacScript::Roman)) => Some(
+                bytes
+                    .iter()
+                    .map(|&byte| match byte {
+                        0..0x80 => char::from(byte),
+                        _ => MAC_ROMAN[usize::from(byte - 0x80)],
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
 }
 
-/// Represents the platform identifier
+/// Represents a language-tag record of a format 1 name table,
+/// pointing to a UTF-16BE encoded BCP 47 language tag in the string storage
 #[derive(Debug)]
+pub struct LangTagRecord {
+    /// Language-tag string length in bytes
+    length: u16,
+
+    /// Language-tag string offset in bytes from the string storage
+    offset: u16,
+}
+
+impl LangTagRecord {
+    fn from_buffer(buf: &[u8], at: usize) -> Result<Self, TableEncodingError> {
+        Ok(Self {
+            length: be_u16(buf, at)?,
+            offset: be_u16(buf, at + 2)?,
+        })
+    }
+
+    /// Returns the language-tag string length in bytes
+    pub fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// Returns the language-tag string offset in bytes from the string storage
+    pub fn offset(&self) -> u16 {
+        self.offset
+    }
+
+    /// Decodes the UTF-16BE language tag out of the name table string storage
+    pub fn decode(&self, storage: &[u8]) -> Option<String> {
+        let start = usize::from(self.offset);
+        let bytes = storage.get(start..start + usize::from(self.length))?;
+
+        Some(decode_utf16be(bytes))
+    }
+}
+
+/// The upper half (0x80 - 0xFF) of the Mac OS Roman character set
+const MAC_ROMAN: [char; 128] = [
+    '\u{00C4}', '\u{00C5}', '\u{00C7}', '\u{00C9}', '\u{00D1}', '\u{00D6}', '\u{00DC}', '\u{00E1}',
+    '\u{00E0}', '\u{00E2}', '\u{00E4}', '\u{00E3}', '\u{00E5}', '\u{00E7}', '\u{00E9}', '\u{00E8}',
+    '\u{00EA}', '\u{00EB}', '\u{00ED}', '\u{00EC}', '\u{00EE}', '\u{00EF}', '\u{00F1}', '\u{00F3}',
+    '\u{00F2}', '\u{00F4}', '\u{00F6}', '\u{00F5}', '\u{00FA}', '\u{00F9}', '\u{00FB}', '\u{00FC}',
+    '\u{2020}', '\u{00B0}', '\u{00A2}', '\u{00A3}', '\u{00A7}', '\u{2022}', '\u{00B6}', '\u{00DF}',
+    '\u{00AE}', '\u{00A9}', '\u{2122}', '\u{00B4}', '\u{00A8}', '\u{2260}', '\u{00C6}', '\u{00D8}',
+    '\u{221E}', '\u{00B1}', '\u{2264}', '\u{2265}', '\u{00A5}', '\u{00B5}', '\u{2202}', '\u{2211}',
+    '\u{220F}', '\u{03C0}', '\u{222B}', '\u{00AA}', '\u{00BA}', '\u{03A9}', '\u{00E6}', '\u{00F8}',
+    '\u{00BF}', '\u{00A1}', '\u{00AC}', '\u{221A}', '\u{0192}', '\u{2248}', '\u{2206}', '\u{00AB}',
+    '\u{00BB}', '\u{2026}', '\u{00A0}', '\u{00C0}', '\u{00C3}', '\u{00D5}', '\u{0152}', '\u{0153}',
+    '\u{2013}', '\u{2014}', '\u{201C}', '\u{201D}', '\u{2018}', '\u{2019}', '\u{00F7}', '\u{25CA}',
+    '\u{00FF}', '\u{0178}', '\u{2044}', '\u{20AC}', '\u{2039}', '\u{203A}', '\u{FB01}', '\u{FB02}',
+    '\u{2021}', '\u{00B7}', '\u{201A}', '\u{201E}', '\u{2030}', '\u{00C2}', '\u{00CA}', '\u{00C1}',
+    '\u{00CB}', '\u{00C8}', '\u{00CD}', '\u{00CE}', '\u{00CF}', '\u{00CC}', '\u{00D3}', '\u{00D4}',
+    '\u{F8FF}', '\u{00D2}', '\u{00DA}', '\u{00DB}', '\u{00D9}', '\u{0131}', '\u{02C6}', '\u{02DC}',
+    '\u{00AF}', '\u{02D8}', '\u{02D9}', '\u{02DA}', '\u{00B8}', '\u{02DD}', '\u{02DB}', '\u{02C7}',
+];
+
+/// Represents the platform identifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PlatformId {
     Unicode,
     Macintosh,
     Reserved,
     Microsoft,
-    Unknown,
+    /// Any other platform, with the original platform id
+    Unknown(u16),
 }
 
 impl From<u16> for PlatformId {
@@ -117,32 +472,357 @@ impl From<u16> for PlatformId {
             1 => Self::Macintosh,
             2 => Self::Reserved,
             3 => Self::Microsoft,
-            _ => Self::Unknown,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl PlatformId {
+    /// Returns the platform id as it's stored in the font, the inverse of `PlatformId::from`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::name::PlatformId;
+    ///
+    /// assert_eq!(PlatformId::from(3).code(), 3);
+    /// assert_eq!(PlatformId::from(7), PlatformId::Unknown(7));
+    /// assert_eq!(PlatformId::from(7).code(), 7);
+    /// ```
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::Unicode => 0,
+            Self::Macintosh => 1,
+            Self::Reserved => 2,
+            Self::Microsoft => 3,
+            Self::Unknown(code) => *code,
         }
     }
 }
 
 /// Represents the platform-specific identifier
-#[derive(Debug)]
+/// the same numeric encoding id means different things on every platform
+/// so use `PlatformSpecificId::from_platform` to decode it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PlatformSpecificId {
+    /// Unicode platform, Unicode 1.0 semantics
     Version1,
+    /// Unicode platform, Unicode 1.1 semantics
     Version1_1,
+    /// Unicode platform, ISO/IEC 10646 semantics
     #[warn(deprecated)]
     Iso10646,
+    /// Unicode platform, Unicode 2.0 and onwards semantics, BMP only
     Unicode2_0Bmp,
+    /// Unicode platform, Unicode 2.0 and onwards semantics, full repertoire
     Unicode2_0NonBmp,
-    Unknown,
+    /// Unicode platform, Unicode variation sequences (cmap format 14)
+    UnicodeVariationSequences,
+    /// Unicode platform, Unicode full repertoire (cmap format 13)
+    UnicodeFullRepertoire,
+
+    /// Macintosh platform, the encoding id is a script manager code
+    MacScript(MacScript),
+
+    /// Microsoft platform, symbol encoding
+    Symbol,
+    /// Microsoft platform, Unicode BMP (UCS-2)
+    UnicodeBmp,
+    /// Microsoft platform, Shift-JIS
+    ShiftJis,
+    /// Microsoft platform, PRC (GB2312)
+    Prc,
+    /// Microsoft platform, Big5
+    Big5,
+    /// Microsoft platform, Wansung
+    Wansung,
+    /// Microsoft platform, Johab
+    Johab,
+    /// Microsoft platform, Unicode full repertoire (UCS-4)
+    UnicodeUcs4,
+
+    /// An encoding id the platform doesn't define (or an unknown platform),
+    /// with the original encoding id
+    Unknown(u16),
 }
 
+impl PlatformSpecificId {
+    /// Decodes an encoding id according to the platform it belongs to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::name::{MacScript, PlatformId, PlatformSpecificId};
+    ///
+    /// assert_eq!(
+    ///     PlatformSpecificId::from_platform(PlatformId::Microsoft, 1),
+    ///     PlatformSpecificId::UnicodeBmp
+    /// );
+    /// assert_eq!(
+    ///     PlatformSpecificId::from_platform(PlatformId::Macintosh, 1),
+    ///     PlatformSpecificId::MacScript(MacScript::Japanese)
+    /// );
+    /// assert_eq!(
+    ///     PlatformSpecificId::from_platform(PlatformId::Unicode, 1),
+    ///     PlatformSpecificId::Version1_1
+    /// );
+    /// ```
+    pub fn from_platform(platform: PlatformId, value: u16) -> Self {
+        match platform {
+            PlatformId::Unicode => match value {
+                0 => Self::Version1,
+                1 => Self::Version1_1,
+                2 => Self::Iso10646,
+                3 => Self::Unicode2_0Bmp,
+                4 => Self::Unicode2_0NonBmp,
+                5 => Self::UnicodeVariationSequences,
+                6 => Self::UnicodeFullRepertoire,
+                _ => Self::Unknown(value),
+            },
+            PlatformId::Macintosh => match MacScript::from(value) {
+                MacScript::Unknown(_) => Self::Unknown(value),
+                script => Self::MacScript(script),
+            },
+            PlatformId::Microsoft => match value {
+                0 => Self::Symbol,
+                1 => Self::UnicodeBmp,
+                2 => Self::ShiftJis,
+                3 => Self::Prc,
+                4 => Self::Big5,
+                5 => Self::Wansung,
+                6 => Self::Johab,
+                10 => Self::UnicodeUcs4,
+                _ => Self::Unknown(value),
+            },
+            PlatformId::Reserved | PlatformId::Unknown(_) => Self::Unknown(value),
+        }
+    }
+
+    /// Returns the encoding id as it's stored in the font, the inverse of
+    /// `PlatformSpecificId::from_platform` for the platform it was decoded with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::name::{PlatformId, PlatformSpecificId};
+    ///
+    /// for platform in [PlatformId::Unicode, PlatformId::Macintosh, PlatformId::Microsoft] {
+    ///     for code in 0..40 {
+    ///         assert_eq!(PlatformSpecificId::from_platform(platform, code).code(), code);
+    ///     }
+    /// }
+    /// ```
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::Version1 | Self::Symbol => 0,
+            Self::Version1_1 | Self::UnicodeBmp => 1,
+            Self::Iso10646 | Self::ShiftJis => 2,
+            Self::Unicode2_0Bmp | Self::Prc => 3,
+            Self::Unicode2_0NonBmp | Self::Big5 => 4,
+            Self::UnicodeVariationSequences | Self::Wansung => 5,
+            Self::UnicodeFullRepertoire | Self::Johab => 6,
+            Self::UnicodeUcs4 => 10,
+            Self::MacScript(script) => script.code(),
+            Self::Unknown(code) => *code,
+        }
+    }
+
+    /// Returns whether the encoding id is a Unicode encoding on `platform`, every
+    /// defined encoding of the Unicode platform and the Microsoft Unicode BMP (UCS-2)
+    /// and full repertoire (UCS-4) encodings are. Symbol, the legacy CJK encodings
+    /// and the Macintosh script codes aren't.
+    ///
+    /// The encoding id is decoded again for `platform`, so an id decoded for
+    /// another platform (like through `PlatformSpecificId::from`) is classified correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::name::{PlatformId, PlatformSpecificId};
+    ///
+    /// assert!(PlatformSpecificId::UnicodeBmp.is_unicode(PlatformId::Microsoft));
+    /// assert!(!PlatformSpecificId::Symbol.is_unicode(PlatformId::Microsoft));
+    /// // encoding id 1 is Unicode 1.1 on the Unicode platform but Japanese on the Macintosh
+    /// assert!(PlatformSpecificId::from(1).is_unicode(PlatformId::Unicode));
+    /// assert!(!PlatformSpecificId::from(1).is_unicode(PlatformId::Macintosh));
+    /// ```
+    pub fn is_unicode(&self, platform: PlatformId) -> bool {
+        matches!(
+            Self::from_platform(platform, self.code()),
+            Self::Version1
+                | Self::Version1_1
+                | Self::Iso10646
+                | Self::Unicode2_0Bmp
+                | Self::Unicode2_0NonBmp
+                | Self::UnicodeVariationSequences
+                | Self::UnicodeFullRepertoire
+                | Self::UnicodeBmp
+                | Self::UnicodeUcs4
+        )
+    }
+
+    /// Returns a short human readable name of the encoding, for logs and font inspectors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::name::{PlatformId, PlatformSpecificId};
+    ///
+    /// let encoding = PlatformSpecificId::from_platform(PlatformId::Microsoft, 10);
+    ///
+    /// assert_eq!(encoding.encoding_label(), "Unicode full repertoire (UCS-4)");
+    /// ```
+    pub fn encoding_label(&self) -> &'static str {
+        match self {
+            Self::Version1 => "Unicode 1.0",
+            Self::Version1_1 => "Unicode 1.1",
+            Self::Iso10646 => "ISO/IEC 10646",
+            Self::Unicode2_0Bmp => "Unicode 2.0 BMP",
+            Self::Unicode2_0NonBmp => "Unicode 2.0 full repertoire",
+            Self::UnicodeVariationSequences => "Unicode variation sequences",
+            Self::UnicodeFullRepertoire => "Unicode full repertoire",
+            Self::MacScript(_) => "Macintosh script",
+            Self::Symbol => "Symbol",
+            Self::UnicodeBmp => "Unicode BMP (UCS-2)",
+            Self::ShiftJis => "Shift-JIS",
+            Self::Prc => "PRC (GB2312)",
+            Self::Big5 => "Big5",
+            Self::Wansung => "Wansung",
+            Self::Johab => "Johab",
+            Self::UnicodeUcs4 => "Unicode full repertoire (UCS-4)",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+}
+
+/// Decodes an encoding id as if it belonged to the Unicode platform,
+/// prefer `PlatformSpecificId::from_platform` which takes the platform into account.
 impl From<u16> for PlatformSpecificId {
+    fn from(value: u16) -> Self {
+        Self::from_platform(PlatformId::Unicode, value)
+    }
+}
+
+/// Represents the Macintosh script manager codes,
+/// the encoding ids of the Macintosh platform
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MacScript {
+    Roman,
+    Japanese,
+    ChineseTraditional,
+    Korean,
+    Arabic,
+    Hebrew,
+    Greek,
+    Russian,
+    RSymbol,
+    Devanagari,
+    Gurmukhi,
+    Gujarati,
+    Oriya,
+    Bengali,
+    Tamil,
+    Telugu,
+    Kannada,
+    Malayalam,
+    Sinhalese,
+    Burmese,
+    Khmer,
+    Thai,
+    Laotian,
+    Georgian,
+    Armenian,
+    ChineseSimplified,
+    Tibetan,
+    Mongolian,
+    Geez,
+    Slavic,
+    Vietnamese,
+    Sindhi,
+    Uninterpreted,
+    /// Any other script code, with the original code
+    Unknown(u16),
+}
+
+impl From<u16> for MacScript {
     fn from(value: u16) -> Self {
         match value {
-            0 => Self::Version1,
-            1 => Self::Version1_1,
-            2 => Self::Iso10646,
-            3 => Self::Unicode2_0Bmp,
-            4 => Self::Unicode2_0NonBmp,
-            _ => Self::Unknown,
+            0 => Self::Roman,
+            1 => Self::Japanese,
+            2 => Self::ChineseTraditional,
+            3 => Self::Korean,
+            4 => Self::Arabic,
+            5 => Self::Hebrew,
+            6 => Self::Greek,
+            7 => Self::Russian,
+            8 => Self::RSymbol,
+            9 => Self::Devanagari,
+            10 => Self::Gurmukhi,
+            11 => Self::Gujarati,
+            12 => Self::Oriya,
+            13 => Self::Bengali,
+            14 => Self::Tamil,
+            15 => Self::Telugu,
+            16 => Self::Kannada,
+            17 => Self::Malayalam,
+            18 => Self::Sinhalese,
+            19 => Self::Burmese,
+            20 => Self::Khmer,
+            21 => Self::Thai,
+            22 => Self::Laotian,
+            23 => Self::Georgian,
+            24 => Self::Armenian,
+            25 => Self::ChineseSimplified,
+            26 => Self::Tibetan,
+            27 => Self::Mongolian,
+            28 => Self::Geez,
+            29 => Self::Slavic,
+            30 => Self::Vietnamese,
+            31 => Self::Sindhi,
+            32 => Self::Uninterpreted,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl MacScript {
+    /// Returns the script manager code, the inverse of `MacScript::from`
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::Roman => 0,
+            Self::Japanese => 1,
+            Self::ChineseTraditional => 2,
+            Self::Korean => 3,
+            Self::Arabic => 4,
+            Self::Hebrew => 5,
+            Self::Greek => 6,
+            Self::Russian => 7,
+            Self::RSymbol => 8,
+            Self::Devanagari => 9,
+            Self::Gurmukhi => 10,
+            Self::Gujarati => 11,
+            Self::Oriya => 12,
+            Self::Bengali => 13,
+            Self::Tamil => 14,
+            Self::Telugu => 15,
+            Self::Kannada => 16,
+            Self::Malayalam => 17,
+            Self::Sinhalese => 18,
+            Self::Burmese => 19,
+            Self::Khmer => 20,
+            Self::Thai => 21,
+            Self::Laotian => 22,
+            Self::Georgian => 23,
+            Self::Armenian => 24,
+            Self::ChineseSimplified => 25,
+            Self::Tibetan => 26,
+            Self::Mongolian => 27,
+            Self::Geez => 28,
+            Self::Slavic => 29,
+            Self::Vietnamese => 30,
+            Self::Sindhi => 31,
+            Self::Uninterpreted => 32,
+            Self::Unknown(code) => *code,
         }
     }
 }
@@ -150,7 +830,7 @@ impl From<u16> for PlatformSpecificId {
 /// Represents a table format
 /// the name table can have 2 formats
 /// 0 => TrueType
-/// 1 => OpenType (which is not supported on Apple platforms)
+/// 1 => OpenType (which is not supported on Apple platforms), adds language-tag records
 /// Unknown is there for safety but it really shouldn't appear
 #[derive(Debug)]
 pub enum TableFormat {
@@ -168,3 +848,14 @@ impl From<u16> for TableFormat {
         }
     }
 }
+
+impl TableFormat {
+    /// Returns the format as it's stored in the font, the inverse of `TableFormat::from`
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::TrueType => 0,
+            Self::OpenType => 1,
+            Self::Unknown(code) => *code,
+        }
+    }
+}
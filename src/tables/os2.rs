@@ -0,0 +1,271 @@
+use crate::tag::Tag;
+
+use super::{Table, TableEncodingError, be_i16, be_u16};
+
+/// Represents the fsSelection field of the 'OS/2' table.
+/// Each method corresponds to a specific bit in the 16-bit value.
+#[derive(Debug, Clone, Copy)]
+pub struct FsSelection {
+    bits: u16,
+}
+
+impl FsSelection {
+    /// Constructs a `FsSelection` instance from a raw `u16` value.
+    pub fn from_bits(bits: u16) -> Self {
+        FsSelection { bits }
+    }
+
+    /// Checks if the glyphs are italic (bit 0).
+    pub fn italic(&self) -> bool {
+        (self.bits & 0b0000_0000_0000_0001) != 0
+    }
+
+    /// Checks if the glyphs are underscored (bit 1).
+    pub fn underscore(&self) -> bool {
+        (self.bits & 0b0000_0000_0000_0010) != 0
+    }
+
+    /// Checks if the glyphs have their foreground and background reversed (bit 2).
+    pub fn negative(&self) -> bool {
+        (self.bits & 0b0000_0000_0000_0100) != 0
+    }
+
+    /// Checks if the glyphs are outlined (hollow) (bit 3).
+    pub fn outlined(&self) -> bool {
+        (self.bits & 0b0000_0000_0000_1000) != 0
+    }
+
+    /// Checks if the glyphs are overstruck (bit 4).
+    pub fn strikeout(&self) -> bool {
+        (self.bits & 0b0000_0000_0001_0000) != 0
+    }
+
+    /// Checks if the glyphs are emboldened (bit 5).
+    pub fn bold(&self) -> bool {
+        (self.bits & 0b0000_0000_0010_0000) != 0
+    }
+
+    /// Checks if the glyphs are in the standard weight and style (bit 6).
+    pub fn regular(&self) -> bool {
+        (self.bits & 0b0000_0000_0100_0000) != 0
+    }
+
+    /// Checks if the typographic metrics should be used for the line metrics (bit 7).
+    pub fn use_typo_metrics(&self) -> bool {
+        (self.bits & 0b0000_0000_1000_0000) != 0
+    }
+
+    /// Checks if the font names follow the weight, width and slope family model (bit 8).
+    pub fn wws(&self) -> bool {
+        (self.bits & 0b0000_0001_0000_0000) != 0
+    }
+
+    /// Checks if the font is an oblique style (bit 9).
+    pub fn oblique(&self) -> bool {
+        (self.bits & 0b0000_0010_0000_0000) != 0
+    }
+
+    /// Returns the raw bits of the flags.
+    pub fn bits(&self) -> u16 {
+        self.bits
+    }
+}
+
+/// A representation of the [OS/2 table](https://learn.microsoft.com/en-us/typography/opentype/spec/os2)
+/// which holds the metrics and classification Windows (and most layout engines) rely on
+#[derive(Debug)]
+pub struct Os2 {
+    /// The version of the OS/2 table, 0 to 5
+    version: u16,
+
+    /// The average advance width of the lowercase glyphs
+    x_avg_char_width: i16,
+
+    /// The visual weight of the glyphs, 100 to 900
+    weight_class: u16,
+
+    /// The relative width of the glyphs, 1 (ultra-condensed) to 9 (ultra-expanded)
+    width_class: u16,
+
+    /// The embedding licensing rights of the font
+    fs_type: u16,
+
+    /// The thickness of the strikeout stroke
+    strikeout_size: i16,
+
+    /// The position of the top of the strikeout stroke relative to the baseline
+    strikeout_position: i16,
+
+    /// The tag of the font vendor
+    vendor_id: Tag,
+
+    /// The font style flags
+    fs_selection: FsSelection,
+
+    /// The lowest BMP code point the font maps
+    first_char_index: u16,
+
+    /// The highest BMP code point the font maps
+    last_char_index: u16,
+
+    /// The typographic ascender
+    typo_ascender: i16,
+
+    /// The typographic descender, usually negative
+    typo_descender: i16,
+
+    /// The typographic line gap
+    typo_line_gap: i16,
+
+    /// The ascender metric for Windows, a clipping boundary
+    win_ascent: u16,
+
+    /// The descender metric for Windows, a positive clipping boundary below the baseline
+    win_descent: u16,
+
+    /// The height of the lowercase x, only in version 2 and above
+    x_height: Option<i16>,
+
+    /// The height of the uppercase H, only in version 2 and above
+    cap_height: Option<i16>,
+}
+
+impl Table for Os2 {
+    const TAG: Tag = Tag::new(*b"OS/2");
+
+    /// Parses the OS/2 table from it's raw bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::InvalidBufferLength` if the table is shorter than
+    /// the 78 bytes of version 0, or shorter than 96 bytes from version 2 on.
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        if buf.len() < 78 {
+            return Err(TableEncodingError::InvalidBufferLength(78, buf.len()));
+        }
+
+        let version = be_u16(buf, 0)?;
+
+        // version 1 adds the code page ranges (bytes 78..86),
+        // version 2 adds the x and cap heights after them
+        let (x_height, cap_height) = match version {
+            0 | 1 => (None, None),
+            _ => (Some(be_i16(buf, 86)?), Some(be_i16(buf, 88)?)),
+        };
+
+        // bytes 10..26 are the subscript and superscript metrics, 30..58 the
+        // family class, panose and unicode ranges
+        Ok(Self {
+            version,
+            x_avg_char_width: be_i16(buf, 2)?,
+            weight_class: be_u16(buf, 4)?,
+            width_class: be_u16(buf, 6)?,
+            fs_type: be_u16(buf, 8)?,
+            strikeout_size: be_i16(buf, 26)?,
+            strikeout_position: be_i16(buf, 28)?,
+            vendor_id: Tag::new([buf[58], buf[59], buf[60], buf[61]]),
+            fs_selection: FsSelection::from_bits(be_u16(buf, 62)?),
+            first_char_index: be_u16(buf, 64)?,
+            last_char_index: be_u16(buf, 66)?,
+            typo_ascender: be_i16(buf, 68)?,
+            typo_descender: be_i16(buf, 70)?,
+            typo_line_gap: be_i16(buf, 72)?,
+            win_ascent: be_u16(buf, 74)?,
+            win_descent: be_u16(buf, 76)?,
+            x_height,
+            cap_height,
+        })
+    }
+}
+
+impl Os2 {
+    /// Returns the version of the OS/2 table.
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the average advance width of the lowercase glyphs.
+    pub fn x_avg_char_width(&self) -> i16 {
+        self.x_avg_char_width
+    }
+
+    /// Returns the visual weight of the glyphs, 400 is regular and 700 bold.
+    pub fn weight_class(&self) -> u16 {
+        self.weight_class
+    }
+
+    /// Returns the relative width of the glyphs, 5 is normal.
+    pub fn width_class(&self) -> u16 {
+        self.width_class
+    }
+
+    /// Returns the raw embedding licensing rights of the font.
+    pub fn fs_type(&self) -> u16 {
+        self.fs_type
+    }
+
+    /// Returns the thickness of the strikeout stroke.
+    pub fn strikeout_size(&self) -> i16 {
+        self.strikeout_size
+    }
+
+    /// Returns the position of the top of the strikeout stroke relative to the baseline.
+    pub fn strikeout_position(&self) -> i16 {
+        self.strikeout_position
+    }
+
+    /// Returns the tag of the font vendor.
+    pub fn vendor_id(&self) -> Tag {
+        self.vendor_id
+    }
+
+    /// Returns the font style flags.
+    pub fn fs_selection(&self) -> FsSelection {
+        self.fs_selection
+    }
+
+    /// Returns the lowest BMP code point the font maps.
+    pub fn first_char_index(&self) -> u16 {
+        self.first_char_index
+    }
+
+    /// Returns the highest BMP code point the font maps.
+    pub fn last_char_index(&self) -> u16 {
+        self.last_char_index
+    }
+
+    /// Returns the typographic ascender.
+    pub fn typo_ascender(&self) -> i16 {
+        self.typo_ascender
+    }
+
+    /// Returns the typographic descender, usually negative.
+    pub fn typo_descender(&self) -> i16 {
+        self.typo_descender
+    }
+
+    /// Returns the typographic line gap.
+    pub fn typo_line_gap(&self) -> i16 {
+        self.typo_line_gap
+    }
+
+    /// Returns the Windows ascender.
+    pub fn win_ascent(&self) -> u16 {
+        self.win_ascent
+    }
+
+    /// Returns the Windows descender, positive below the baseline unlike the other descenders.
+    pub fn win_descent(&self) -> u16 {
+        self.win_descent
+    }
+
+    /// Returns the height of the lowercase x, `None` before version 2.
+    pub fn x_height(&self) -> Option<i16> {
+        self.x_height
+    }
+
+    /// Returns the height of the uppercase H, `None` before version 2.
+    pub fn cap_height(&self) -> Option<i16> {
+        self.cap_height
+    }
+}
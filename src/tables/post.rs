@@ -0,0 +1,473 @@
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use crate::tag::Tag;
+
+use super::{Table, TableEncodingError, be_i16, be_u16, be_u32, fixed_to_f32};
+
+/// A representation of the [post table](https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6post.html)
+/// which holds PostScript information such as the glyph names
+#[derive(Debug)]
+pub struct Post {
+    /// The version of the post table, 0x00010000, 0x00020000, 0x00025000 or 0x00030000
+    version: u32,
+
+    /// Italic angle in degrees, a 16.16 fixed point number
+    italic_angle: i32,
+
+    /// Suggested distance of the top of the underline from the baseline
+    underline_position: i16,
+
+    /// Suggested values for the underline thickness
+    underline_thickness: i16,
+
+    /// Set to 0 if the font is proportionally spaced, non-zero if the font is monospaced
+    is_fixed_pitch: u32,
+
+    /// The name of every glyph in glyph id order, empty for versions without names
+    glyph_names: Vec<String>,
+
+    /// Maps a glyph name back to it's glyph id, built on the first lookup
+    #[cfg(feature = "std")]
+    glyph_ids: OnceLock<BTreeMap<String, u16>>,
+
+    /// Maps a glyph name back to it's glyph id, without std there's no `Sync`
+    /// lazy cell so it's built when the table is parsed
+    #[cfg(not(feature = "std"))]
+    glyph_ids: BTreeMap<String, u16>,
+}
+
+impl Table for Post {
+    const TAG: Tag = Tag::new(*b"post");
+
+    /// Parses the post table from it's raw bytes.
+    ///
+    /// Version 1.0 names the glyphs after the standard Macintosh glyph order and
+    /// version 2.0 stores the names, every other version (such as 3.0) has no names.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::InvalidBufferLength` if the header or
+    /// the version 2.0 glyph name indices are truncated.
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        if buf.len() < 32 {
+            return Err(TableEncodingError::InvalidBufferLength(32, buf.len()));
+        }
+
+        let version = be_u32(buf, 0)?;
+
+        // bytes 16..32 are the memory usage hints for downloading the font as a Type 42 font
+        let glyph_names = match version {
+            0x0001_0000 => STANDARD_GLYPH_NAMES
+                .iter()
+                .map(|name| String::from(*name))
+                .collect(),
+            0x0002_0000 => parse_glyph_names(buf)?,
+            _ => Vec::new(),
+        };
+
+        #[cfg(feature = "std")]
+        let glyph_ids = OnceLock::new();
+        #[cfg(not(feature = "std"))]
+        let glyph_ids = reverse_glyph_names(&glyph_names);
+
+        Ok(Self {
+            version,
+            italic_angle: be_u32(buf, 4)? as i32,
+            underline_position: be_i16(buf, 8)?,
+            underline_thickness: be_i16(buf, 10)?,
+            is_fixed_pitch: be_u32(buf, 12)?,
+            glyph_names,
+            glyph_ids,
+        })
+    }
+}
+
+impl Post {
+    /// Returns the version of the post table
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the italic angle in degrees, as a raw 16.16 fixed point number
+    pub fn italic_angle(&self) -> i32 {
+        self.italic_angle
+    }
+
+    /// Returns the italic angle in counter-clockwise degrees from the vertical,
+    /// negative for fonts leaning to the right and zero for upright fonts.
+    pub fn italic_angle_degrees(&self) -> f32 {
+        fixed_to_f32(self.italic_angle)
+    }
+
+    /// Returns the suggested distance of the top of the underline from the baseline
+    pub fn underline_position(&self) -> i16 {
+        self.underline_position
+    }
+
+    /// Returns the suggested underline thickness
+    pub fn underline_thickness(&self) -> i16 {
+        self.underline_thickness
+    }
+
+    /// Checks if the font is monospaced
+    pub fn is_fixed_pitch(&self) -> bool {
+        self.is_fixed_pitch != 0
+    }
+
+    /// Returns the name of every glyph in glyph id order,
+    /// empty for versions of the table which don't name the glyphs
+    pub fn glyph_names(&self) -> &[String] {
+        &self.glyph_names
+    }
+
+    /// Returns the name of a glyph
+    pub fn glyph_name(&self, glyph_id: u16) -> Option<&str> {
+        self.glyph_names
+            .get(usize::from(glyph_id))
+            .map(String::as_str)
+    }
+
+    /// Finds the glyph id of a glyph name, the reverse of `Post::glyph_name`.
+    ///
+    /// The reverse map is built on the first call and cached, or when the table is parsed
+    /// without the `std` feature. If several glyphs share a name the lowest glyph id wins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vero_type::tables::{Table, post::Post};
+    ///
+    /// // a version 1.0 table, the glyphs follow the standard Macintosh order
+    /// let mut table = vec![0, 1, 0, 0];
+    /// table.resize(32, 0);
+    /// let post = Post::parse(&table).unwrap();
+    ///
+    /// assert_eq!(post.glyph_id("A"), Some(36));
+    /// assert_eq!(post.glyph_id("nonexistent"), None);
+    /// ```
+    pub fn glyph_id(&self, name: &str) -> Option<u16> {
+        #[cfg(feature = "std")]
+        let glyph_ids = self
+            .glyph_ids
+            .get_or_init(|| reverse_glyph_names(&self.glyph_names));
+        #[cfg(not(feature = "std"))]
+        let glyph_ids = &self.glyph_ids;
+
+        glyph_ids.get(name).copied()
+    }
+}
+
+/// Maps every glyph name back to it's glyph id, the lowest id of glyphs sharing a name
+fn reverse_glyph_names(glyph_names: &[String]) -> BTreeMap<String, u16> {
+    let mut glyph_ids = BTreeMap::new();
+
+    for (glyph_id, name) in (0..=u16::MAX).zip(glyph_names) {
+        glyph_ids.entry(name.clone()).or_insert(glyph_id);
+    }
+
+    glyph_ids
+}
+
+/// Parses the glyph names of a version 2.0 table, `buf` is the whole table
+fn parse_glyph_names(buf: &[u8]) -> Result<Vec<String>, TableEncodingError> {
+    let num_glyphs = usize::from(be_u16(buf, 32)?);
+
+    let indices = (0..num_glyphs)
+        .map(|glyph| be_u16(buf, 34 + glyph * 2))
+        .collect::<Result<Vec<u16>, TableEncodingError>>()?;
+
+    // The custom names follow the indices as pascal strings, a length byte then the name
+    let mut custom_names = Vec::new();
+    let mut at = 34 + num_glyphs * 2;
+
+    while let Some(&length) = buf.get(at) {
+        let end = at + 1 + usize::from(length);
+
+        // a truncated last name is dropped, the glyphs pointing to it go unnamed
+        let Some(name) = buf.get(at + 1..end) else {
+            break;
+        };
+
+        custom_names.push(String::from_utf8_lossy(name).into_owned());
+        at = end;
+    }
+
+    // Indices below 258 refer to the standard names, the rest to the custom names
+    Ok(indices
+        .iter()
+        .map(|&index| match usize::from(index) {
+            index if index < STANDARD_GLYPH_NAMES.len() => {
+                String::from(STANDARD_GLYPH_NAMES[index])
+            }
+            index => custom_names
+                .get(index - STANDARD_GLYPH_NAMES.len())
+                .cloned()
+                .unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// The names of the 258 glyphs of the standard Macintosh character set, in order
+const STANDARD_GLYPH_NAMES: [&str; 258] = [
+    ".notdef",
+    ".null",
+    "nonmarkingreturn",
+    "space",
+    "exclam",
+    "quotedbl",
+    "numbersign",
+    "dollar",
+    "percent",
+    "ampersand",
+    "quotesingle",
+    "parenleft",
+    "parenright",
+    "asterisk",
+    "plus",
+    "comma",
+    "hyphen",
+    "period",
+    "slash",
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "colon",
+    "semicolon",
+    "less",
+    "equal",
+    "greater",
+    "question",
+    "at",
+    "A",
+    "B",
+    "C",
+    "D",
+    "E",
+    "F",
+    "G",
+    "H",
+    "I",
+    "J",
+    "K",
+    "L",
+    "M",
+    "N",
+    "O",
+    "P",
+    "Q",
+    "R",
+    "S",
+    "T",
+    "U",
+    "V",
+    "W",
+    "X",
+    "Y",
+    "Z",
+    "bracketleft",
+    "backslash",
+    "bracketright",
+    "asciicircum",
+    "underscore",
+    "grave",
+    "a",
+    "b",
+    "c",
+    "d",
+    "e",
+    "f",
+    "g",
+    "h",
+    "i",
+    "j",
+    "k",
+    "l",
+    "m",
+    "n",
+    "o",
+    "p",
+    "q",
+    "r",
+    "s",
+    "t",
+    "u",
+    "v",
+    "w",
+    "x",
+    "y",
+    "z",
+    "braceleft",
+    "bar",
+    "braceright",
+    "asciitilde",
+    "Adieresis",
+    "Aring",
+    "Ccedilla",
+    "Eacute",
+    "Ntilde",
+    "Odieresis",
+    "Udieresis",
+    "aacute",
+    "agrave",
+    "acircumflex",
+    "adieresis",
+    "atilde",
+    "aring",
+    "ccedilla",
+    "eacute",
+    "egrave",
+    "ecircumflex",
+    "edieresis",
+    "iacute",
+    "igrave",
+    "icircumflex",
+    "idieresis",
+    "ntilde",
+    "oacute",
+    "ograve",
+    "ocircumflex",
+    "odieresis",
+    "otilde",
+    "uacute",
+    "ugrave",
+    "ucircumflex",
+    "udieresis",
+    "dagger",
+    "degree",
+    "cent",
+    "sterling",
+    "section",
+    "bullet",
+    "paragraph",
+    "germandbls",
+    "registered",
+    "copyright",
+    "trademark",
+    "acute",
+    "dieresis",
+    "notequal",
+    "AE",
+    "Oslash",
+    "infinity",
+    "plusminus",
+    "lessequal",
+    "greaterequal",
+    "yen",
+    "mu",
+    "partialdiff",
+    "summation",
+    "product",
+    "pi",
+    "integral",
+    "ordfeminine",
+    "ordmasculine",
+    "Omega",
+    "ae",
+    "oslash",
+    "questiondown",
+    "exclamdown",
+    "logicalnot",
+    "radical",
+    "florin",
+    "approxequal",
+    "Delta",
+    "guillemotleft",
+    "guillemotright",
+    "ellipsis",
+    "nonbreakingspace",
+    "Agrave",
+    "Atilde",
+    "Otilde",
+    "OE",
+    "oe",
+    "endash",
+    "emdash",
+    "quotedblleft",
+    "quotedblright",
+    "quoteleft",
+    "quoteright",
+    "divide",
+    "lozenge",
+    "ydieresis",
+    "Ydieresis",
+    "fraction",
+    "currency",
+    "guilsinglleft",
+    "guilsinglright",
+    "fi",
+    "fl",
+    "daggerdbl",
+    "periodcentered",
+    "quotesinglbase",
+    "quotedblbase",
+    "perthousand",
+    "Acircumflex",
+    "Ecircumflex",
+    "Aacute",
+    "Edieresis",
+    "Egrave",
+    "Iacute",
+    "Icircumflex",
+    "Idieresis",
+    "Igrave",
+    "Oacute",
+    "Ocircumflex",
+    "apple",
+    "Ograve",
+    "Uacute",
+    "Ucircumflex",
+    "Ugrave",
+    "dotlessi",
+    "circumflex",
+    "tilde",
+    "macron",
+    "breve",
+    "dotaccent",
+    "ring",
+    "cedilla",
+    "hungarumlaut",
+    "ogonek",
+    "caron",
+    "Lslash",
+    "lslash",
+    "Scaron",
+    "scaron",
+    "Zcaron",
+    "zcaron",
+    "brokenbar",
+    "Eth",
+    "eth",
+    "Yacute",
+    "yacute",
+    "Thorn",
+    "thorn",
+    "minus",
+    "multiply",
+    "onesuperior",
+    "twosuperior",
+    "threesuperior",
+    "onehalf",
+    "onequarter",
+    "threequarters",
+    "franc",
+    "Gbreve",
+    "gbreve",
+    "Idotaccent",
+    "Scedilla",
+    "scedilla",
+    "Cacute",
+    "cacute",
+    "Ccaron",
+    "ccaron",
+    "dcroat",
+];
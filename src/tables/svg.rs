@@ -0,0 +1,234 @@
+use alloc::{borrow::Cow, string::String, vec::Vec};
+
+use miniz_oxide::inflate::{TINFLStatus, decompress_to_vec_with_limit};
+
+use crate::{shared::SharedBytes, tag::Tag};
+
+use super::{Table, TableEncodingError, be_u16, be_u32};
+
+/// The magic bytes every gzip stream starts with
+const GZIP_MAGIC: [u8; 2] = [0x1F, 0x8B];
+
+/// The gzip header flags which add optional fields before the compressed data
+const GZIP_FHCRC: u8 = 0x02;
+const GZIP_FEXTRA: u8 = 0x04;
+const GZIP_FNAME: u8 = 0x08;
+const GZIP_FCOMMENT: u8 = 0x10;
+
+/// The largest size in bytes a gzip compressed document may inflate to,
+/// deflate expands about 1000 to 1 so a small table could otherwise exhaust memory
+pub const MAX_INFLATED_DOCUMENT_SIZE: usize = 16 * 1024 * 1024;
+
+/// A representation of the [SVG table](https://learn.microsoft.com/en-us/typography/opentype/spec/svg)
+/// which stores color glyphs, emoji for example, as SVG documents
+#[derive(Debug)]
+pub struct Svg {
+    /// The version of the SVG table, set to 0
+    version: u16,
+
+    /// The document records, sorted by their glyph ranges
+    records: Vec<SvgDocumentRecord>,
+
+    /// The raw table, the documents are decoded on demand
+    data: SharedBytes,
+}
+
+impl Table for Svg {
+    const TAG: Tag = Tag::new(*b"SVG ");
+
+    fn parse(buf: &[u8]) -> Result<Self, TableEncodingError> {
+        Self::from_shared(buf.into())
+    }
+
+    fn parse_shared(data: &SharedBytes) -> Result<Self, TableEncodingError> {
+        Self::from_shared(data.clone())
+    }
+}
+
+impl Svg {
+    /// Parses the header and the document records, the documents themselves
+    /// are only decoded when looked up, see `Svg::svg_document`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::InvalidBufferLength` if the document list
+    /// or one of it's records lies outside of the table.
+    fn from_shared(data: SharedBytes) -> Result<Self, TableEncodingError> {
+        let buf: &[u8] = &data;
+
+        let version = be_u16(buf, 0)?;
+        let list_offset = be_u32(buf, 2)? as usize;
+        // bytes 6..10 are reserved
+
+        // The document offsets are relative to the start of the document list
+        let list = buf
+            .get(list_offset..)
+            .ok_or(TableEncodingError::InvalidBufferLength(
+                list_offset,
+                buf.len(),
+            ))?;
+        let num_entries = usize::from(be_u16(list, 0)?);
+
+        let mut records = (0..num_entries)
+            .map(|index| {
+                let at = 2 + index * 12;
+
+                Ok(SvgDocumentRecord {
+                    start_glyph_id: be_u16(list, at)?,
+                    end_glyph_id: be_u16(list, at + 2)?,
+                    offset: list_offset.saturating_add(be_u32(list, at + 4)? as usize),
+                    length: be_u32(list, at + 8)? as usize,
+                })
+            })
+            .collect::<Result<Vec<SvgDocumentRecord>, TableEncodingError>>()?;
+
+        // The spec mandates sorted records, sort anyway so lookups can binary search
+        records.sort_by_key(|record| record.start_glyph_id);
+
+        Ok(Self {
+            version,
+            records,
+            data,
+        })
+    }
+
+    /// Returns the version of the SVG table
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Returns the document records of the table
+    pub fn records(&self) -> &[SvgDocumentRecord] {
+        &self.records
+    }
+
+    /// Returns the SVG document which draws the glyph, or `None` if the glyph has
+    /// no SVG version. Plain documents are borrowed from the table while gzip
+    /// compressed ones are inflated on every call, cache them if they're reused.
+    ///
+    /// A single document may draw several glyphs, each of them is an element
+    /// with the id `glyph<glyph_id>` inside it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::InvalidBufferLength` if the document lies outside
+    /// of the table, or a `TableEncodingError::MalformedSvgDocument` if it isn't valid
+    /// UTF-8, fails to inflate or inflates past `MAX_INFLATED_DOCUMENT_SIZE` bytes.
+    pub fn svg_document(&self, glyph_id: u16) -> Result<Option<Cow<'_, str>>, TableEncodingError> {
+        let index = self
+            .records
+            .partition_point(|record| record.start_glyph_id <= glyph_id);
+
+        let Some(record) = index
+            .checked_sub(1)
+            .and_then(|index| self.records.get(index))
+        else {
+            return Ok(None);
+        };
+
+        if glyph_id > record.end_glyph_id {
+            return Ok(None);
+        }
+
+        let end = record.offset.saturating_add(record.length);
+        let raw =
+            self.data
+                .get(record.offset..end)
+                .ok_or(TableEncodingError::InvalidBufferLength(
+                    end,
+                    self.data.len(),
+                ))?;
+
+        decode_document(raw).map(Some)
+    }
+}
+
+/// A record of the SVG document list, mapping a range of glyphs to a document
+#[derive(Debug)]
+pub struct SvgDocumentRecord {
+    /// The first glyph drawn by the document
+    start_glyph_id: u16,
+
+    /// The last glyph drawn by the document, inclusive
+    end_glyph_id: u16,
+
+    /// The offset of the document from the start of the table
+    offset: usize,
+
+    /// The length of the document in bytes, compressed if it's gzip compressed
+    length: usize,
+}
+
+impl SvgDocumentRecord {
+    /// Returns the first glyph drawn by the document
+    pub fn start_glyph_id(&self) -> u16 {
+        self.start_glyph_id
+    }
+
+    /// Returns the last glyph drawn by the document, inclusive
+    pub fn end_glyph_id(&self) -> u16 {
+        self.end_glyph_id
+    }
+}
+
+/// Decodes a document as UTF-8, inflating it first if it's gzip compressed
+fn decode_document(raw: &[u8]) -> Result<Cow<'_, str>, TableEncodingError> {
+    let invalid = || TableEncodingError::MalformedSvgDocument("the document isn't valid UTF-8");
+
+    if raw.starts_with(&GZIP_MAGIC) {
+        String::from_utf8(inflate_gzip(raw)?)
+            .map(Cow::Owned)
+            .map_err(|_| invalid())
+    } else {
+        core::str::from_utf8(raw)
+            .map(Cow::Borrowed)
+            .map_err(|_| invalid())
+    }
+}
+
+/// Inflates a gzip stream, skipping over it's header (RFC 1952)
+fn inflate_gzip(raw: &[u8]) -> Result<Vec<u8>, TableEncodingError> {
+    let truncated = || TableEncodingError::MalformedSvgDocument("the gzip header is truncated");
+
+    let (&method, &flags) = raw.get(2).zip(raw.get(3)).ok_or_else(truncated)?;
+
+    // Deflate is the only compression method gzip defines
+    if method != 8 {
+        return Err(TableEncodingError::MalformedSvgDocument(
+            "the gzip compression method isn't deflate",
+        ));
+    }
+
+    // The fixed header is 10 bytes: magic, method, flags, mtime, xfl and os
+    let mut at = 10;
+
+    if flags & GZIP_FEXTRA != 0 {
+        let extra = raw.get(at..at + 2).ok_or_else(truncated)?;
+        at += 2 + usize::from(u16::from_le_bytes([extra[0], extra[1]]));
+    }
+
+    // The file name and the comment are both zero terminated
+    for flag in [GZIP_FNAME, GZIP_FCOMMENT] {
+        if flags & flag != 0 {
+            let field = raw.get(at..).ok_or_else(truncated)?;
+            at += field
+                .iter()
+                .position(|&byte| byte == 0)
+                .ok_or_else(truncated)?
+                + 1;
+        }
+    }
+
+    if flags & GZIP_FHCRC != 0 {
+        at += 2;
+    }
+
+    let deflated = raw.get(at..).ok_or_else(truncated)?;
+
+    decompress_to_vec_with_limit(deflated, MAX_INFLATED_DOCUMENT_SIZE).map_err(|error| {
+        TableEncodingError::MalformedSvgDocument(match error.status {
+            TINFLStatus::HasMoreOutput => "the document inflates past the size limit",
+            _ => "the document fails to inflate",
+        })
+    })
+}
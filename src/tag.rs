@@ -0,0 +1,132 @@
+use core::{fmt, str::FromStr};
+
+use thiserror::Error;
+
+/// A 4 byte tag identifying a table, a variation axis, a script and so on.
+///
+/// Tags are compared byte for byte, `Display` shows the printable ASCII bytes
+/// as is and escapes anything else.
+///
+/// # Examples
+///
+/// ```
+/// use vero_type::tag::Tag;
+///
+/// let tag: Tag = "cvt".parse().unwrap();
+///
+/// assert_eq!(tag, Tag::new(*b"cvt "));
+/// assert_eq!(tag, "cvt ");
+/// assert_eq!(tag.to_string(), "cvt ");
+/// assert_eq!(Tag::new([0, 1, 0, 0]).to_string(), "\\x00\\x01\\x00\\x00");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Tag([u8; 4]);
+
+impl Tag {
+    /// Creates a tag out of it's raw bytes
+    pub const fn new(bytes: [u8; 4]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the raw bytes of the tag
+    pub const fn to_bytes(self) -> [u8; 4] {
+        self.0
+    }
+
+    /// Returns the raw bytes of the tag as a reference
+    pub const fn as_bytes(&self) -> &[u8; 4] {
+        &self.0
+    }
+}
+
+impl From<[u8; 4]> for Tag {
+    fn from(bytes: [u8; 4]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<&[u8; 4]> for Tag {
+    fn from(bytes: &[u8; 4]) -> Self {
+        Self(*bytes)
+    }
+}
+
+impl From<Tag> for [u8; 4] {
+    fn from(tag: Tag) -> Self {
+        tag.0
+    }
+}
+
+impl AsRef<[u8]> for Tag {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Represents the errors which may occur when parsing a `Tag` out of a string
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TagError {
+    #[error("A tag must be 1 to 4 bytes long, got {0} bytes")]
+    InvalidLength(usize),
+
+    #[error("A tag must be made of printable ASCII characters")]
+    NotAscii,
+}
+
+impl FromStr for Tag {
+    type Err = TagError;
+
+    /// Parses a tag out of 1 to 4 printable ASCII characters,
+    /// shorter tags are padded with spaces so `"cvt"` is the `cvt ` tag.
+    fn from_str(s: &str) -> Result<Self, TagError> {
+        if !(1..=4).contains(&s.len()) {
+            return Err(TagError::InvalidLength(s.len()));
+        }
+
+        if !s
+            .bytes()
+            .all(|byte| byte == b' ' || byte.is_ascii_graphic())
+        {
+            return Err(TagError::NotAscii);
+        }
+
+        let mut bytes = [b' '; 4];
+        bytes[..s.len()].copy_from_slice(s.as_bytes());
+
+        Ok(Self(bytes))
+    }
+}
+
+impl PartialEq<&str> for Tag {
+    /// Compares the tag with a string the way `Tag::from_str` parses it,
+    /// so `"cvt"` equals the `cvt ` tag.
+    fn eq(&self, other: &&str) -> bool {
+        other.parse::<Self>().is_ok_and(|tag| tag == *self)
+    }
+}
+
+impl PartialEq<Tag> for &str {
+    fn eq(&self, other: &Tag) -> bool {
+        other == self
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            if byte == b' ' || byte.is_ascii_graphic() {
+                write!(f, "{}", char::from(byte))?;
+            } else {
+                write!(f, "\\x{byte:02X}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tag(\"{self}\")")
+    }
+}
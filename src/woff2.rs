@@ -0,0 +1,773 @@
+//! Decoding of [WOFF2](https://www.w3.org/TR/WOFF2/) web fonts back into a regular sfnt file.
+//!
+//! A WOFF2 file is a compact directory followed by a single Brotli compressed block
+//! holding every table, the `glyf`, `loca` and `hmtx` tables may also be stored in a
+//! transformed form which is reversed while decoding. The decoded file is laid out by
+//! `FontWriter` so it can be handed to `Font::from_shared` like any other font.
+
+use std::io::{self, Cursor, Read};
+
+use alloc::vec::Vec;
+use brotli_decompressor::Decompressor;
+use thiserror::Error;
+
+use crate::{
+    buffer::{VeroBufReader, VeroBufReaderError},
+    tables::{
+        TableEncodingError, be_u16, be_u32, checked_range,
+        glyf::{
+            MORE_COMPONENTS, ON_CURVE_POINT, OVERLAP_SIMPLE, REPEAT_FLAG, WE_HAVE_INSTRUCTIONS,
+            X_IS_SAME_OR_POSITIVE, X_SHORT_VECTOR, Y_IS_SAME_OR_POSITIVE, Y_SHORT_VECTOR,
+            component_record_len,
+        },
+    },
+    tag::Tag,
+    writer::FontWriter,
+};
+
+/// 'wOF2', the signature every WOFF2 file starts with
+const SIGNATURE: u32 = 0x774F4632;
+
+/// 'ttcf', the flavor of a font collection
+const COLLECTION_FLAVOR: u32 = 0x74746366;
+
+/// The size of the WOFF2 header, the table directory follows it
+const HEADER_SIZE: u64 = 48;
+
+/// The tag index stored in a directory entry when an explicit tag follows the flags
+const EXPLICIT_TAG_INDEX: u8 = 63;
+
+/// The size of the transformed glyf table header, the glyph streams follow it
+const GLYF_HEADER_SIZE: usize = 36;
+
+/// The tables a directory entry can refer to by index instead of spelling out the tag
+const KNOWN_TAGS: [Tag; 63] = [
+    Tag::new(*b"cmap"),
+    Tag::new(*b"head"),
+    Tag::new(*b"hhea"),
+    Tag::new(*b"hmtx"),
+    Tag::new(*b"maxp"),
+    Tag::new(*b"name"),
+    Tag::new(*b"OS/2"),
+    Tag::new(*b"post"),
+    Tag::new(*b"cvt "),
+    Tag::new(*b"fpgm"),
+    Tag::new(*b"glyf"),
+    Tag::new(*b"loca"),
+    Tag::new(*b"prep"),
+    Tag::new(*b"CFF "),
+    Tag::new(*b"VORG"),
+    Tag::new(*b"EBDT"),
+    Tag::new(*b"EBLC"),
+    Tag::new(*b"gasp"),
+    Tag::new(*b"hdmx"),
+    Tag::new(*b"kern"),
+    Tag::new(*b"LTSH"),
+    Tag::new(*b"PCLT"),
+    Tag::new(*b"VDMX"),
+    Tag::new(*b"vhea"),
+    Tag::new(*b"vmtx"),
+    Tag::new(*b"BASE"),
+    Tag::new(*b"GDEF"),
+    Tag::new(*b"GPOS"),
+    Tag::new(*b"GSUB"),
+    Tag::new(*b"EBSC"),
+    Tag::new(*b"JSTF"),
+    Tag::new(*b"MATH"),
+    Tag::new(*b"CBDT"),
+    Tag::new(*b"CBLC"),
+    Tag::new(*b"COLR"),
+    Tag::new(*b"CPAL"),
+    Tag::new(*b"SVG "),
+    Tag::new(*b"sbix"),
+    Tag::new(*b"acnt"),
+    Tag::new(*b"avar"),
+    Tag::new(*b"bdat"),
+    Tag::new(*b"bloc"),
+    Tag::new(*b"bsln"),
+    Tag::new(*b"cvar"),
+    Tag::new(*b"fdsc"),
+    Tag::new(*b"feat"),
+    Tag::new(*b"fmtx"),
+    Tag::new(*b"fvar"),
+    Tag::new(*b"gvar"),
+    Tag::new(*b"hsty"),
+    Tag::new(*b"just"),
+    Tag::new(*b"lcar"),
+    Tag::new(*b"mort"),
+    Tag::new(*b"morx"),
+    Tag::new(*b"opbd"),
+    Tag::new(*b"prop"),
+    Tag::new(*b"trak"),
+    Tag::new(*b"Zapf"),
+    Tag::new(*b"Silf"),
+    Tag::new(*b"Glat"),
+    Tag::new(*b"Gloc"),
+    Tag::new(*b"Feat"),
+    Tag::new(*b"Sill"),
+];
+
+const GLYF: Tag = Tag::new(*b"glyf");
+const LOCA: Tag = Tag::new(*b"loca");
+const HMTX: Tag = Tag::new(*b"hmtx");
+const HHEA: Tag = Tag::new(*b"hhea");
+
+/// Represents the errors which may occur when decoding a WOFF2 file
+#[derive(Error, Debug)]
+pub enum Woff2Error {
+    #[error("Expected the 'wOF2' signature, got {0:#010X}")]
+    InvalidSignature(u32),
+
+    #[error("WOFF2 font collections aren't supported")]
+    UnsupportedCollection,
+
+    #[error("Transform version {1} of the {0} table isn't supported")]
+    UnsupportedTransform(Tag, u8),
+
+    #[error("Failed to decompress the font data, {0}")]
+    Decompression(io::Error),
+
+    #[error("The font data decompressed into {found} bytes but the directory expects {expected}")]
+    DecompressedSizeMismatch { expected: u64, found: u64 },
+
+    #[error("The transformed {0} table is malformed, {1}")]
+    MalformedTransform(Tag, &'static str),
+
+    #[error(transparent)]
+    VeroBufReaderError(#[from] VeroBufReaderError),
+
+    #[error(transparent)]
+    TableEncodingError(#[from] TableEncodingError),
+}
+
+/// A table directory entry
+#[derive(Debug)]
+struct Woff2Table {
+    tag: Tag,
+
+    /// The version of the transform applied to the table
+    transform_version: u8,
+
+    /// Whether the table is stored transformed (version 0 of glyf and loca is a transform,
+    /// for every other table it's the null transform)
+    transformed: bool,
+
+    /// The length of the table as stored in the decompressed block
+    length: u32,
+}
+
+/// Decodes a WOFF2 file into a regular sfnt file.
+///
+/// The transformed `glyf` and `loca` tables (and a transformed `hmtx` table) are
+/// rebuilt into their standard form, every other table is copied as is.
+///
+/// # Errors
+///
+/// Returns a `Woff2Error::InvalidSignature` if the data isn't a WOFF2 file,
+/// a `Woff2Error::UnsupportedCollection` for font collections, and a
+/// `Woff2Error::MalformedTransform` if a transformed table can't be rebuilt.
+///
+/// # Examples
+///
+/// ```no_run
+/// use vero_type::{font::Font, woff2};
+///
+/// let data = std::fs::read("font.woff2").unwrap();
+/// let font = Font::from_shared(woff2::decode(&data).unwrap()).unwrap();
+///
+/// println!("{:?}", font.family_name());
+/// ```
+pub fn decode(data: &[u8]) -> Result<Vec<u8>, Woff2Error> {
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(data));
+
+    let signature = reader.read_u32()?;
+    if signature != SIGNATURE {
+        return Err(Woff2Error::InvalidSignature(signature));
+    }
+
+    let flavor = reader.read_u32()?;
+    if flavor == COLLECTION_FLAVOR {
+        return Err(Woff2Error::UnsupportedCollection);
+    }
+
+    // length
+    reader.skip(4)?;
+    let num_tables = reader.read_u16()?;
+    // reserved, totalSfntSize
+    reader.skip(6)?;
+    let total_compressed_size = reader.read_u32()?;
+    reader.seek_to(HEADER_SIZE)?;
+
+    let mut tables = Vec::with_capacity(num_tables as usize);
+    for _ in 0..num_tables {
+        tables.push(read_table_entry(&mut reader)?);
+    }
+
+    // the table directory is only a few bytes per table, it's end fits in a u32
+    let position = u32::try_from(reader.position()?).unwrap_or(u32::MAX);
+    let range = checked_range(position, total_compressed_size)?;
+    let end = range.end;
+    let compressed = data
+        .get(range)
+        .ok_or(TableEncodingError::InvalidBufferLength(end, data.len()))?;
+
+    let expected: u64 = tables.iter().map(|table| u64::from(table.length)).sum();
+    let mut decompressed = Vec::new();
+    // Reading one byte past the expected size is enough to tell it doesn't match
+    // without inflating an arbitrarily large block
+    Decompressor::new(compressed, 4096)
+        .take(expected + 1)
+        .read_to_end(&mut decompressed)
+        .map_err(Woff2Error::Decompression)?;
+
+    if decompressed.len() as u64 != expected {
+        return Err(Woff2Error::DecompressedSizeMismatch {
+            expected,
+            found: decompressed.len() as u64,
+        });
+    }
+
+    let mut offset = 0;
+    let mut raw = Vec::with_capacity(tables.len());
+    for table in &tables {
+        let length = table.length as usize;
+        raw.push((table, &decompressed[offset..offset + length]));
+        offset += length;
+    }
+
+    let mut writer = FontWriter::new(flavor);
+    let mut x_mins = None;
+
+    // The glyf table comes before loca and hmtx as they're rebuilt out of it
+    if let Some((glyf, buf)) = raw.iter().find(|(table, _)| table.tag == GLYF)
+        && glyf.transformed
+    {
+        if !raw
+            .iter()
+            .any(|(table, _)| table.tag == LOCA && table.transformed)
+        {
+            return Err(Woff2Error::MalformedTransform(
+                LOCA,
+                "the glyf table is transformed but loca isn't",
+            ));
+        }
+
+        let glyphs = reconstruct_glyf(buf)?;
+        writer.table(GLYF, glyphs.glyf);
+        writer.table(LOCA, glyphs.loca);
+        x_mins = Some(glyphs.x_mins);
+    }
+
+    for (table, buf) in &raw {
+        if !table.transformed {
+            writer.table(table.tag, buf.to_vec());
+            continue;
+        }
+
+        match table.tag {
+            GLYF => {}
+            LOCA if x_mins.is_some() => {}
+            LOCA => {
+                return Err(Woff2Error::MalformedTransform(
+                    LOCA,
+                    "the loca table is transformed but glyf isn't",
+                ));
+            }
+            HMTX => {
+                let x_mins = x_mins.as_deref().ok_or(Woff2Error::MalformedTransform(
+                    HMTX,
+                    "the bearings can't be derived without a transformed glyf table",
+                ))?;
+                let hhea = raw
+                    .iter()
+                    .find(|(table, _)| table.tag == HHEA)
+                    .ok_or(TableEncodingError::MissingTable(HHEA))?
+                    .1;
+
+                writer.table(HMTX, reconstruct_hmtx(buf, be_u16(hhea, 34)?, x_mins)?);
+            }
+            tag => {
+                return Err(Woff2Error::UnsupportedTransform(
+                    tag,
+                    table.transform_version,
+                ));
+            }
+        }
+    }
+
+    Ok(writer.to_bytes()?)
+}
+
+/// Reads a single table directory entry
+fn read_table_entry(reader: &mut VeroBufReader<Cursor<&[u8]>>) -> Result<Woff2Table, Woff2Error> {
+    let flags = reader.read_u8()?;
+
+    let tag = match flags & 0x3F {
+        EXPLICIT_TAG_INDEX => Tag::new(reader.read_u32()?.to_be_bytes()),
+        index => KNOWN_TAGS[index as usize],
+    };
+
+    let transform_version = flags >> 6;
+    let transformed = match tag {
+        GLYF | LOCA => transform_version != 3,
+        _ => transform_version != 0,
+    };
+
+    let supported = match tag {
+        GLYF | LOCA => matches!(transform_version, 0 | 3),
+        HMTX => transform_version <= 1,
+        _ => transform_version == 0,
+    };
+    if !supported {
+        return Err(Woff2Error::UnsupportedTransform(tag, transform_version));
+    }
+
+    let orig_length = reader.read_uint_base128()?;
+    let length = match transformed {
+        true => reader.read_uint_base128()?,
+        false => orig_length,
+    };
+
+    Ok(Woff2Table {
+        tag,
+        transform_version,
+        transformed,
+        length,
+    })
+}
+
+/// The tables rebuilt out of a transformed glyf table
+struct Glyphs {
+    glyf: Vec<u8>,
+    loca: Vec<u8>,
+
+    /// The x_min of every glyph, the bearings of a transformed hmtx table are derived from it
+    x_mins: Vec<i16>,
+}
+
+type StreamReader<'a> = VeroBufReader<Cursor<&'a [u8]>>;
+
+/// The streams a transformed glyf table is split into
+struct GlyfStreams<'a> {
+    n_contour: StreamReader<'a>,
+    n_points: StreamReader<'a>,
+    flag: StreamReader<'a>,
+    glyph: StreamReader<'a>,
+    composite: StreamReader<'a>,
+    bbox_bitmap: &'a [u8],
+    bbox: StreamReader<'a>,
+    instruction: StreamReader<'a>,
+    overlap_bitmap: Option<&'a [u8]>,
+}
+
+/// Returns whether the bit of the given glyph is set in a bitmap, the first glyph
+/// is the most significant bit of the first byte
+fn bit_set(bitmap: &[u8], glyph_id: u16) -> bool {
+    bitmap[glyph_id as usize / 8] & (0x80 >> (glyph_id % 8)) != 0
+}
+
+/// Rebuilds the glyf and loca tables out of a transformed glyf table
+fn reconstruct_glyf(buf: &[u8]) -> Result<Glyphs, Woff2Error> {
+    if buf.len() < GLYF_HEADER_SIZE {
+        return Err(TableEncodingError::InvalidBufferLength(GLYF_HEADER_SIZE, buf.len()).into());
+    }
+
+    let option_flags = be_u16(buf, 2)?;
+    let num_glyphs = be_u16(buf, 4)?;
+    let index_format = be_u16(buf, 6)?;
+
+    let mut at = GLYF_HEADER_SIZE;
+    let mut next_stream = |length: usize| -> Result<&[u8], Woff2Error> {
+        let stream = buf
+            .get(at..at + length)
+            .ok_or(Woff2Error::MalformedTransform(
+                GLYF,
+                "a stream runs past the table",
+            ))?;
+        at += length;
+        Ok(stream)
+    };
+
+    let mut sizes = [0usize; 7];
+    for (index, size) in sizes.iter_mut().enumerate() {
+        *size = be_u32(buf, 8 + index * 4)? as usize;
+    }
+
+    let stream_reader = |stream| VeroBufReader::from_buffer(Cursor::new(stream));
+    let bitmap_len = 4 * ((num_glyphs as usize).div_ceil(32));
+
+    let n_contour = stream_reader(next_stream(sizes[0])?);
+    let n_points = stream_reader(next_stream(sizes[1])?);
+    let flag = stream_reader(next_stream(sizes[2])?);
+    let glyph = stream_reader(next_stream(sizes[3])?);
+    let composite = stream_reader(next_stream(sizes[4])?);
+    let bbox = next_stream(sizes[5])?;
+    let instruction = stream_reader(next_stream(sizes[6])?);
+    let overlap_bitmap = match option_flags & 1 != 0 {
+        true => Some(next_stream((num_glyphs as usize).div_ceil(8))?),
+        false => None,
+    };
+
+    if bbox.len() < bitmap_len {
+        return Err(Woff2Error::MalformedTransform(
+            GLYF,
+            "the bounding box stream is shorter than it's bitmap",
+        ));
+    }
+
+    let mut streams = GlyfStreams {
+        n_contour,
+        n_points,
+        flag,
+        glyph,
+        composite,
+        bbox_bitmap: &bbox[..bitmap_len],
+        bbox: stream_reader(&bbox[bitmap_len..]),
+        instruction,
+        overlap_bitmap,
+    };
+
+    let mut glyf = Vec::new();
+    let mut offsets = Vec::with_capacity(num_glyphs as usize + 1);
+    let mut x_mins = Vec::with_capacity(num_glyphs as usize);
+
+    for glyph_id in 0..num_glyphs {
+        offsets.push(glyf.len());
+
+        let x_min = reconstruct_glyph(&mut streams, glyph_id, &mut glyf)?;
+        x_mins.push(x_min);
+
+        // Every glyph starts on a 4 byte boundary which suits both loca formats
+        glyf.resize(glyf.len().next_multiple_of(4), 0);
+    }
+    offsets.push(glyf.len());
+
+    let mut loca = Vec::new();
+    for offset in offsets {
+        match index_format {
+            0 => {
+                let offset = u16::try_from(offset / 2).map_err(|_| {
+                    Woff2Error::MalformedTransform(GLYF, "the glyphs don't fit short offsets")
+                })?;
+                loca.extend_from_slice(&offset.to_be_bytes());
+            }
+            _ => loca.extend_from_slice(&(offset as u32).to_be_bytes()),
+        }
+    }
+
+    Ok(Glyphs { glyf, loca, x_mins })
+}
+
+/// Rebuilds a single glyph onto the end of `glyf` and returns it's x_min
+fn reconstruct_glyph(
+    streams: &mut GlyfStreams,
+    glyph_id: u16,
+    glyf: &mut Vec<u8>,
+) -> Result<i16, Woff2Error> {
+    let number_of_contours = streams.n_contour.read_i16()?;
+    let explicit_bbox = bit_set(streams.bbox_bitmap, glyph_id);
+
+    if number_of_contours == 0 {
+        if explicit_bbox {
+            return Err(Woff2Error::MalformedTransform(
+                GLYF,
+                "an empty glyph has a bounding box",
+            ));
+        }
+
+        return Ok(0);
+    }
+
+    if number_of_contours < 0 {
+        if !explicit_bbox {
+            return Err(Woff2Error::MalformedTransform(
+                GLYF,
+                "a composite glyph has no bounding box",
+            ));
+        }
+
+        let bbox = read_bbox(&mut streams.bbox)?;
+        glyf.extend_from_slice(&number_of_contours.to_be_bytes());
+        for value in bbox {
+            glyf.extend_from_slice(&value.to_be_bytes());
+        }
+
+        let mut have_instructions = false;
+        loop {
+            let flags = streams.composite.read_u16()?;
+            let mut record = alloc::vec![0; component_record_len(flags)];
+            record[..2].copy_from_slice(&flags.to_be_bytes());
+            streams.composite.read_exact(&mut record[2..])?;
+            glyf.extend_from_slice(&record);
+
+            have_instructions |= flags & WE_HAVE_INSTRUCTIONS != 0;
+            if flags & MORE_COMPONENTS == 0 {
+                break;
+            }
+        }
+
+        if have_instructions {
+            copy_instructions(streams, glyf)?;
+        }
+
+        return Ok(bbox[0]);
+    }
+
+    let mut end_points = Vec::with_capacity(number_of_contours as usize);
+    let mut num_points = 0u16;
+    for _ in 0..number_of_contours {
+        num_points = num_points
+            .checked_add(streams.n_points.read_255_uint16()?)
+            .filter(|&total| total > 0)
+            .ok_or(Woff2Error::MalformedTransform(
+                GLYF,
+                "the contours don't have a valid number of points",
+            ))?;
+        end_points.push(num_points - 1);
+    }
+
+    let mut points = Vec::with_capacity(num_points as usize);
+    let (mut x, mut y) = (0i16, 0i16);
+    for _ in 0..num_points {
+        let flag = streams.flag.read_u8()?;
+        let (dx, dy) = read_triplet(&mut streams.glyph, flag & 0x7F)?;
+
+        x = x.wrapping_add(dx);
+        y = y.wrapping_add(dy);
+        points.push((x, y, flag & 0x80 == 0));
+    }
+
+    let bbox = match explicit_bbox {
+        true => read_bbox(&mut streams.bbox)?,
+        false => points.iter().fold(
+            [i16::MAX, i16::MAX, i16::MIN, i16::MIN],
+            |[x_min, y_min, x_max, y_max], &(x, y, _)| {
+                [x_min.min(x), y_min.min(y), x_max.max(x), y_max.max(y)]
+            },
+        ),
+    };
+
+    glyf.extend_from_slice(&number_of_contours.to_be_bytes());
+    for value in bbox {
+        glyf.extend_from_slice(&value.to_be_bytes());
+    }
+    for end_point in end_points {
+        glyf.extend_from_slice(&end_point.to_be_bytes());
+    }
+
+    copy_instructions(streams, glyf)?;
+
+    let overlap = streams
+        .overlap_bitmap
+        .is_some_and(|bitmap| bit_set(bitmap, glyph_id));
+    encode_points(&points, overlap, glyf);
+
+    Ok(bbox[0])
+}
+
+/// Reads an explicit bounding box out of the bbox stream
+fn read_bbox(bbox: &mut StreamReader) -> Result<[i16; 4], Woff2Error> {
+    Ok([
+        bbox.read_i16()?,
+        bbox.read_i16()?,
+        bbox.read_i16()?,
+        bbox.read_i16()?,
+    ])
+}
+
+/// Copies the instructions of a glyph, the length comes from the glyph stream
+/// and the bytes from the instruction stream
+fn copy_instructions(streams: &mut GlyfStreams, glyf: &mut Vec<u8>) -> Result<(), Woff2Error> {
+    let length = streams.glyph.read_255_uint16()?;
+    let mut instructions = alloc::vec![0; length as usize];
+    streams.instruction.read_exact(&mut instructions)?;
+
+    glyf.extend_from_slice(&length.to_be_bytes());
+    glyf.extend_from_slice(&instructions);
+
+    Ok(())
+}
+
+/// Reads the coordinate deltas of a point encoded as a triplet, the flag
+/// (without it's on curve bit) tells how many bytes the deltas take and how they're packed
+fn read_triplet(glyph: &mut StreamReader, flag: u8) -> Result<(i16, i16), Woff2Error> {
+    // The low bits of the flag carry the signs, a set bit is a positive delta
+    let with_sign = |bit: u8, value: i32| match bit & 1 != 0 {
+        true => value,
+        false => -value,
+    };
+    let flag_value = i32::from(flag);
+
+    let (dx, dy) = match flag {
+        0..10 => {
+            let b0 = i32::from(glyph.read_u8()?);
+            (0, with_sign(flag, ((flag_value & 14) << 7) + b0))
+        }
+        10..20 => {
+            let b0 = i32::from(glyph.read_u8()?);
+            (with_sign(flag, (((flag_value - 10) & 14) << 7) + b0), 0)
+        }
+        20..84 => {
+            let b0 = flag_value - 20;
+            let b1 = i32::from(glyph.read_u8()?);
+            (
+                with_sign(flag, 1 + (b0 & 0x30) + (b1 >> 4)),
+                with_sign(flag >> 1, 1 + ((b0 & 0x0C) << 2) + (b1 & 0x0F)),
+            )
+        }
+        84..120 => {
+            let b0 = flag_value - 84;
+            let b1 = i32::from(glyph.read_u8()?);
+            let b2 = i32::from(glyph.read_u8()?);
+            (
+                with_sign(flag, 1 + ((b0 / 12) << 8) + b1),
+                with_sign(flag >> 1, 1 + (((b0 % 12) >> 2) << 8) + b2),
+            )
+        }
+        120..124 => {
+            let b1 = i32::from(glyph.read_u8()?);
+            let b2 = i32::from(glyph.read_u8()?);
+            let b3 = i32::from(glyph.read_u8()?);
+            (
+                with_sign(flag, (b1 << 4) + (b2 >> 4)),
+                with_sign(flag >> 1, ((b2 & 0x0F) << 8) + b3),
+            )
+        }
+        _ => {
+            let dx = i32::from(glyph.read_u16()?);
+            let dy = i32::from(glyph.read_u16()?);
+            (with_sign(flag, dx), with_sign(flag >> 1, dy))
+        }
+    };
+
+    // Coordinates wrap around like the glyf parser accumulates them
+    Ok((dx as i16, dy as i16))
+}
+
+/// Encodes the points of a simple glyph as flags followed by the x and y coordinates,
+/// using short vectors and repeated flags where possible
+fn encode_points(points: &[(i16, i16, bool)], overlap: bool, glyf: &mut Vec<u8>) {
+    let mut flags: Vec<u8> = Vec::with_capacity(points.len());
+    let mut xs = Vec::new();
+    let mut ys = Vec::new();
+
+    let (mut last_x, mut last_y) = (0i16, 0i16);
+    let mut last_flag = None;
+    let mut repeat = 0u8;
+
+    for (index, &(x, y, on_curve)) in points.iter().enumerate() {
+        let mut flag = match on_curve {
+            true => ON_CURVE_POINT,
+            false => 0,
+        };
+        if index == 0 && overlap {
+            flag |= OVERLAP_SIMPLE;
+        }
+
+        flag |= encode_coordinate(
+            x.wrapping_sub(last_x),
+            X_SHORT_VECTOR,
+            X_IS_SAME_OR_POSITIVE,
+            &mut xs,
+        );
+        flag |= encode_coordinate(
+            y.wrapping_sub(last_y),
+            Y_SHORT_VECTOR,
+            Y_IS_SAME_OR_POSITIVE,
+            &mut ys,
+        );
+        (last_x, last_y) = (x, y);
+
+        if last_flag == Some(flag) && repeat < u8::MAX {
+            if repeat == 0 {
+                *flags.last_mut().unwrap() |= REPEAT_FLAG;
+                flags.push(1);
+            } else {
+                *flags.last_mut().unwrap() += 1;
+            }
+            repeat += 1;
+        } else {
+            flags.push(flag);
+            last_flag = Some(flag);
+            repeat = 0;
+        }
+    }
+
+    glyf.extend_from_slice(&flags);
+    glyf.extend_from_slice(&xs);
+    glyf.extend_from_slice(&ys);
+}
+
+/// Writes a single coordinate delta and returns the flag bits describing it
+fn encode_coordinate(delta: i16, short: u8, same_or_positive: u8, out: &mut Vec<u8>) -> u8 {
+    match delta {
+        0 => same_or_positive,
+        -255..=255 => {
+            out.push(delta.unsigned_abs() as u8);
+            match delta > 0 {
+                true => short | same_or_positive,
+                false => short,
+            }
+        }
+        _ => {
+            out.extend_from_slice(&delta.to_be_bytes());
+            0
+        }
+    }
+}
+
+/// Rebuilds a transformed hmtx table, the left side bearings which were dropped
+/// are the x_min of their glyph
+fn reconstruct_hmtx(buf: &[u8], num_h_metrics: u16, x_mins: &[i16]) -> Result<Vec<u8>, Woff2Error> {
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(buf));
+    let flags = reader.read_u8()?;
+
+    if flags & 0xFC != 0 {
+        return Err(Woff2Error::MalformedTransform(
+            HMTX,
+            "reserved flags are set",
+        ));
+    }
+    if flags & 0x03 == 0 {
+        return Err(Woff2Error::MalformedTransform(
+            HMTX,
+            "none of the left side bearings were dropped",
+        ));
+    }
+
+    let num_h_metrics = num_h_metrics as usize;
+    if num_h_metrics == 0 || num_h_metrics > x_mins.len() {
+        return Err(Woff2Error::MalformedTransform(
+            HMTX,
+            "the number of horizontal metrics doesn't match the glyphs",
+        ));
+    }
+
+    let mut advances = Vec::with_capacity(num_h_metrics);
+    for _ in 0..num_h_metrics {
+        advances.push(reader.read_u16()?);
+    }
+
+    let mut bearings = Vec::with_capacity(x_mins.len());
+    for (glyph_id, &x_min) in x_mins.iter().enumerate() {
+        let derived = match glyph_id < num_h_metrics {
+            true => flags & 0x01 != 0,
+            false => flags & 0x02 != 0,
+        };
+
+        bearings.push(match derived {
+            true => x_min,
+            false => reader.read_i16()?,
+        });
+    }
+
+    let mut hmtx = Vec::with_capacity(num_h_metrics * 2 + x_mins.len() * 2);
+    for (glyph_id, bearing) in bearings.into_iter().enumerate() {
+        if let Some(advance) = advances.get(glyph_id) {
+            hmtx.extend_from_slice(&advance.to_be_bytes());
+        }
+        hmtx.extend_from_slice(&bearing.to_be_bytes());
+    }
+
+    Ok(hmtx)
+}
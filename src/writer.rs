@@ -0,0 +1,208 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{
+    tables::{TableEncodingError, TableMetadata, Tables},
+    tag::Tag,
+};
+
+/// The value the checksum of the whole font must add up to,
+/// the head table's `checksum_adjustment` is chosen to make it so
+pub(crate) const CHECKSUM_MAGIC: u32 = 0xB1B0AFBA;
+
+/// The offset of the `checksum_adjustment` field within the head table
+pub(crate) const CHECKSUM_ADJUSTMENT_OFFSET: usize = 8;
+
+/// Assembles raw tables into a complete sfnt file.
+///
+/// The tables are laid out in tag order (as the directory must be sorted),
+/// each one starting on a 4 byte boundary. The writer takes care of the offset
+/// table, the directory entries and their checksums, and the head table's
+/// `checksum_adjustment`, the table bytes themselves are written as given.
+///
+/// # Examples
+///
+/// ```
+/// use vero_type::{tables::OffsetTable, writer::FontWriter};
+///
+/// let mut writer = FontWriter::new(0x00010000);
+/// writer.table(*b"name", vec![0, 0, 0, 0, 0, 6]);
+/// writer.table(*b"cmap", vec![0, 0, 0, 0]);
+///
+/// let font = writer.to_bytes().unwrap();
+/// let offset = OffsetTable::from_buffer(&font[..12]).unwrap();
+///
+/// assert_eq!(offset.num_tables(), 2);
+/// assert_eq!(offset.search_range(), 32);
+/// // the directory is sorted, cmap comes first
+/// assert_eq!(&font[12..16], b"cmap");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FontWriter {
+    /// The sfnt version of the file, 0x00010000 for TrueType outlines, 'OTTO' for CFF outlines
+    scalar_type: u32,
+
+    /// The raw bytes of the tables keyed by their tag
+    tables: BTreeMap<Tag, Vec<u8>>,
+}
+
+impl FontWriter {
+    /// Creates a writer without any tables for a file of the given sfnt version
+    pub fn new(scalar_type: u32) -> Self {
+        Self {
+            scalar_type,
+            tables: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a writer holding a copy of every table listed in the directory of `tables`,
+    /// read out of `data`, the whole font file the directory was read from.
+    ///
+    /// Tables removed with `TablesHeaders::remove` are left out, so the written font
+    /// omits them with the offsets and checksums of the rest fixed up.
+    ///
+    /// # Errors
+    ///
+    /// Errors with `TableEncodingError::InvalidBufferLength` if a table
+    /// extends past the end of `data`, or `TableEncodingError::RangeOverflow`
+    /// if it's end doesn't fit in a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use std::io::Cursor;
+    /// use vero_type::{buffer::VeroBufReader, tables::Tables, writer::FontWriter};
+    ///
+    /// let mut writer = FontWriter::new(0x00010000);
+    /// writer.table(*b"name", vec![0, 0, 0, 0, 0, 6]);
+    /// writer.table(*b"prep", vec![0xB0, 0x00]);
+    /// let font = writer.to_bytes().unwrap();
+    ///
+    /// let mut reader = VeroBufReader::from_buffer(Cursor::new(&font));
+    /// let mut tables = Tables::directory_from_reader(&mut reader).unwrap();
+    /// tables.headers_mut().remove(*b"prep");
+    ///
+    /// let stripped = FontWriter::from_tables(&tables, &font).unwrap();
+    /// assert!(stripped.tags().eq(&["name"]));
+    /// # }
+    /// ```
+    pub fn from_tables(tables: &Tables, data: &[u8]) -> Result<Self, TableEncodingError> {
+        let mut writer = Self::new(tables.offset().scalar_type());
+        let headers = tables.headers();
+
+        for &tag in headers.tags() {
+            let Some(metadata) = headers.get_by_tag(tag) else {
+                continue;
+            };
+
+            let range = metadata.range()?;
+            let end = range.end;
+            let bytes = data
+                .get(range)
+                .ok_or(TableEncodingError::InvalidBufferLength(end, data.len()))?;
+
+            writer.table(tag, bytes.to_vec());
+        }
+
+        Ok(writer)
+    }
+
+    /// Removes a table from the font, returning it's bytes if it was added
+    pub fn remove(&mut self, tag: impl Into<Tag>) -> Option<Vec<u8>> {
+        self.tables.remove(&tag.into())
+    }
+
+    /// Adds a table to the font, replacing any table previously added with the same tag
+    pub fn table(&mut self, tag: impl Into<Tag>, bytes: Vec<u8>) -> &mut Self {
+        self.tables.insert(tag.into(), bytes);
+        self
+    }
+
+    /// Returns the tags of the tables added so far, in directory order
+    pub fn tags(&self) -> impl Iterator<Item = &Tag> {
+        self.tables.keys()
+    }
+
+    /// Lays out the tables and returns the complete font file.
+    ///
+    /// If a head table was added, it's `checksum_adjustment` is overwritten so the
+    /// whole file sums to 0xB1B0AFBA, a head table too short to hold the field is
+    /// written untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TableEncodingError::TooManyTables` if more than 65535 tables were added,
+    /// or a `TableEncodingError::TableTooLarge` if a table's offset or length doesn't fit
+    /// in the 32 bits of a directory entry.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, TableEncodingError> {
+        let num_tables = u16::try_from(self.tables.len())
+            .map_err(|_| TableEncodingError::TooManyTables(self.tables.len()))?;
+        let (search_range, entry_selector, range_shift) = binary_search_params(num_tables);
+
+        let mut font = Vec::new();
+        font.extend_from_slice(&self.scalar_type.to_be_bytes());
+        font.extend_from_slice(&num_tables.to_be_bytes());
+        font.extend_from_slice(&search_range.to_be_bytes());
+        font.extend_from_slice(&entry_selector.to_be_bytes());
+        font.extend_from_slice(&range_shift.to_be_bytes());
+
+        // the table data starts right after the 16 byte directory entries
+        let mut offset = 12 + usize::from(num_tables) * 16;
+        let mut head_offset = None;
+
+        for (tag, bytes) in &self.tables {
+            let checksum = match tag.as_bytes() {
+                b"head" if bytes.len() >= CHECKSUM_ADJUSTMENT_OFFSET + 4 => {
+                    head_offset = Some(offset);
+
+                    // the head checksum is computed with checksum_adjustment zeroed
+                    let mut head = bytes.clone();
+                    head[CHECKSUM_ADJUSTMENT_OFFSET..CHECKSUM_ADJUSTMENT_OFFSET + 4].fill(0);
+                    TableMetadata::recompute_checksum(&head)
+                }
+                _ => TableMetadata::recompute_checksum(bytes),
+            };
+
+            let too_large = |_| TableEncodingError::TableTooLarge(*tag);
+            let table_offset = u32::try_from(offset).map_err(too_large)?;
+            let length = u32::try_from(bytes.len()).map_err(too_large)?;
+
+            font.extend_from_slice(tag.as_bytes());
+            font.extend_from_slice(&checksum.to_be_bytes());
+            font.extend_from_slice(&table_offset.to_be_bytes());
+            font.extend_from_slice(&length.to_be_bytes());
+
+            offset += bytes.len().next_multiple_of(4);
+        }
+
+        for bytes in self.tables.values() {
+            font.extend_from_slice(bytes);
+            font.resize(font.len().next_multiple_of(4), 0);
+        }
+
+        if let Some(head_offset) = head_offset {
+            let field = head_offset + CHECKSUM_ADJUSTMENT_OFFSET
+                ..head_offset + CHECKSUM_ADJUSTMENT_OFFSET + 4;
+            font[field.clone()].fill(0);
+
+            let adjustment = CHECKSUM_MAGIC.wrapping_sub(TableMetadata::recompute_checksum(&font));
+            font[field].copy_from_slice(&adjustment.to_be_bytes());
+        }
+
+        Ok(font)
+    }
+}
+
+/// Computes the `search_range`, `entry_selector` and `range_shift` of the offset table
+fn binary_search_params(num_tables: u16) -> (u16, u16, u16) {
+    if num_tables == 0 {
+        return (0, 0, 0);
+    }
+
+    // the largest power of 2 which is <= num_tables
+    let entry_selector = num_tables.ilog2() as u16;
+    let search_range = (1u16 << entry_selector).wrapping_mul(16);
+    let range_shift = num_tables.wrapping_mul(16).wrapping_sub(search_range);
+
+    (search_range, entry_selector, range_shift)
+}
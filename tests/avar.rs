@@ -0,0 +1,90 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{
+    buffer::VeroBufReader,
+    font::Font,
+    tables::{Table, avar::Avar},
+};
+
+/// Builds an avar table out of `(from, to)` mappings per axis, in F2DOT14
+fn avar_table(axes: &[&[(i16, i16)]]) -> Vec<u8> {
+    let mut table = Vec::new();
+    table.extend_from_slice(&1u16.to_be_bytes());
+    table.extend_from_slice(&0u16.to_be_bytes());
+    table.extend_from_slice(&0u16.to_be_bytes()); // reserved
+    table.extend_from_slice(&(axes.len() as u16).to_be_bytes());
+
+    for mappings in axes {
+        table.extend_from_slice(&(mappings.len() as u16).to_be_bytes());
+        for (from, to) in mappings.iter() {
+            table.extend_from_slice(&from.to_be_bytes());
+            table.extend_from_slice(&to.to_be_bytes());
+        }
+    }
+
+    table
+}
+
+/// Maps -1.0, 0.0, 0.5 and 1.0 to -1.0, 0.0, 0.8 and 1.0
+const WGHT_MAPPINGS: &[(i16, i16)] = &[(-16384, -16384), (0, 0), (8192, 13107), (16384, 16384)];
+
+fn parse(font: Vec<u8>) -> Result<Font, vero_type::VeroTypeError> {
+    Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font)))
+}
+
+#[test]
+fn segment_maps_are_parsed() {
+    let avar = Avar::parse(&avar_table(&[WGHT_MAPPINGS, &[]])).unwrap();
+
+    assert_eq!(avar.major_version(), 1);
+    assert_eq!(avar.minor_version(), 0);
+    assert_eq!(avar.axis_count(), 2);
+    assert_eq!(avar.segment_maps()[0].mappings().len(), 4);
+    assert_eq!(avar.segment_maps()[0].mappings()[1], (0.0, 0.0));
+    assert!(avar.segment_maps()[1].mappings().is_empty());
+}
+
+#[test]
+fn coordinates_are_remapped_piecewise_linearly() {
+    let avar = Avar::parse(&avar_table(&[WGHT_MAPPINGS])).unwrap();
+
+    assert_eq!(avar.remap(0, -1.0), -1.0);
+    assert_eq!(avar.remap(0, -0.5), -0.5);
+    assert_eq!(avar.remap(0, 0.0), 0.0);
+    assert!((avar.remap(0, 0.25) - 0.4).abs() < 1e-4);
+    assert!((avar.remap(0, 0.5) - 0.8).abs() < 1e-4);
+    assert!((avar.remap(0, 0.75) - 0.9).abs() < 1e-4);
+    assert_eq!(avar.remap(0, 1.0), 1.0);
+}
+
+#[test]
+fn axes_without_mappings_are_kept_as_is() {
+    let avar = Avar::parse(&avar_table(&[WGHT_MAPPINGS, &[]])).unwrap();
+
+    assert_eq!(avar.remap(1, 0.3), 0.3);
+    assert_eq!(avar.remap(5, -0.3), -0.3);
+}
+
+#[test]
+fn truncated_segment_maps_are_an_error() {
+    let mut table = avar_table(&[WGHT_MAPPINGS]);
+    table.truncate(table.len() - 2);
+
+    assert!(Avar::parse(&table).is_err());
+}
+
+#[test]
+fn avar_is_loaded_with_fvar() {
+    let fvar = common::wght_fvar_table();
+    let avar = avar_table(&[WGHT_MAPPINGS]);
+    let font = parse(common::minimal_font(&[(b"fvar", &fvar), (b"avar", &avar)])).unwrap();
+
+    assert!((font.avar().unwrap().remap(0, 0.5) - 0.8).abs() < 1e-4);
+
+    let font = parse(common::minimal_font(&[(b"avar", &avar)])).unwrap();
+    assert!(font.avar().is_none());
+}
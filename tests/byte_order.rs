@@ -0,0 +1,131 @@
+//! Locks in the big-endian byte order of the readers, every value below
+//! decodes to something different when read little-endian.
+
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{
+    buffer::VeroBufReader,
+    tables::{
+        Tables,
+        head::{HEAD_MAGIC_NUMBER, LocaFormat},
+    },
+};
+
+#[test]
+fn head_fields_are_read_big_endian() {
+    let mut head = Vec::new();
+    head.extend_from_slice(&0x0001_0000u32.to_be_bytes()); // version
+    head.extend_from_slice(&0x0002_8000u32.to_be_bytes()); // font revision
+    head.extend_from_slice(&0x1234_5678u32.to_be_bytes()); // checksum adjustment
+    head.extend_from_slice(&HEAD_MAGIC_NUMBER.to_be_bytes());
+    head.extend_from_slice(&0x000Bu16.to_be_bytes()); // flags
+    head.extend_from_slice(&2048u16.to_be_bytes()); // units per em
+    head.extend_from_slice(&3_600_000_000i64.to_be_bytes()); // created
+    head.extend_from_slice(&(-2i64).to_be_bytes()); // modified
+    head.extend_from_slice(&(-1126i16).to_be_bytes()); // x min
+    head.extend_from_slice(&(-555i16).to_be_bytes()); // y min
+    head.extend_from_slice(&2466i16.to_be_bytes()); // x max
+    head.extend_from_slice(&2146i16.to_be_bytes()); // y max
+    head.extend_from_slice(&0x0001u16.to_be_bytes()); // mac style
+    head.extend_from_slice(&9u16.to_be_bytes()); // lowest rec ppem
+    head.extend_from_slice(&2i16.to_be_bytes()); // font direction hint
+    head.extend_from_slice(&1i16.to_be_bytes()); // index to loc format
+    head.extend_from_slice(&0i16.to_be_bytes()); // glyph data format
+
+    let font = common::build_font(&[(b"head", &head), (b"name", &common::EMPTY_NAME)]);
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(font));
+    let tables = Tables::from_reader(&mut reader).unwrap();
+    let head = tables.head().unwrap();
+
+    assert_eq!(tables.offset().scalar_type(), 0x0001_0000);
+    assert_eq!(tables.offset().num_tables(), 2);
+    assert_eq!(tables.headers().tags(), ["head", "name"]);
+    assert_eq!(head.magic_number(), HEAD_MAGIC_NUMBER);
+    assert_eq!(head.font_revision(), 0x0002_8000);
+    assert_eq!(head.checksum_adjustment(), 0x1234_5678);
+    assert_eq!(head.flags().bits(), 0x000B);
+    assert_eq!(head.units_per_em(), 2048);
+    assert_eq!(head.created(), 3_600_000_000);
+    assert_eq!(head.modified(), -2);
+    assert_eq!(head.x_min(), -1126);
+    assert_eq!(head.y_min(), -555);
+    assert_eq!(head.x_max(), 2466);
+    assert_eq!(head.y_max(), 2146);
+    assert_eq!(head.index_to_loc_format(), 1);
+    assert_eq!(head.loca_format(), LocaFormat::Long);
+}
+
+#[test]
+fn numeric_readers_are_big_endian() {
+    let data = vec![0x12, 0x34, 0x56, 0x78, 0xFF, 0xFE];
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(data));
+
+    assert_eq!(reader.read_u32().unwrap(), 0x1234_5678);
+    assert_eq!(reader.read_i16().unwrap(), -2);
+}
+
+fn reader(data: &[u8]) -> VeroBufReader<Cursor<Vec<u8>>> {
+    VeroBufReader::from_buffer(Cursor::new(data.to_vec()))
+}
+
+#[test]
+fn unsigned_readers_decode_known_vectors() {
+    assert_eq!(reader(&[0x00]).read_u8().unwrap(), 0);
+    assert_eq!(reader(&[0xFF]).read_u8().unwrap(), u8::MAX);
+    assert_eq!(reader(&[0x01, 0x02]).read_u16().unwrap(), 0x0102);
+    assert_eq!(reader(&[0xFF, 0xFF]).read_u16().unwrap(), u16::MAX);
+    assert_eq!(
+        reader(&[0xDE, 0xAD, 0xBE, 0xEF]).read_u32().unwrap(),
+        0xDEAD_BEEF
+    );
+    assert_eq!(
+        reader(&[0xFF, 0xFF, 0xFF, 0xFF]).read_u32().unwrap(),
+        u32::MAX
+    );
+}
+
+#[test]
+fn signed_readers_decode_known_vectors() {
+    assert_eq!(reader(&[0x7F]).read_i8().unwrap(), i8::MAX);
+    assert_eq!(reader(&[0x80]).read_i8().unwrap(), i8::MIN);
+    assert_eq!(reader(&[0xFF]).read_i8().unwrap(), -1);
+
+    assert_eq!(reader(&[0x7F, 0xFF]).read_i16().unwrap(), i16::MAX);
+    assert_eq!(reader(&[0x80, 0x00]).read_i16().unwrap(), i16::MIN);
+    assert_eq!(reader(&[0xFB, 0x9A]).read_i16().unwrap(), -1126);
+
+    assert_eq!(
+        reader(&[0x7F, 0xFF, 0xFF, 0xFF]).read_i32().unwrap(),
+        i32::MAX
+    );
+    assert_eq!(
+        reader(&[0x80, 0x00, 0x00, 0x00]).read_i32().unwrap(),
+        i32::MIN
+    );
+    assert_eq!(reader(&[0xFF, 0xFF, 0xFF, 0xFE]).read_i32().unwrap(), -2);
+}
+
+#[test]
+fn short_buffers_are_an_error_for_every_reader() {
+    assert!(reader(&[]).read_u8().is_err());
+    assert!(reader(&[]).read_i8().is_err());
+    assert!(reader(&[0x01]).read_u16().is_err());
+    assert!(reader(&[0x01]).read_i16().is_err());
+    assert!(reader(&[0x01, 0x02, 0x03]).read_u32().is_err());
+    assert!(reader(&[0x01, 0x02, 0x03]).read_i32().is_err());
+}
+
+#[test]
+fn reads_advance_past_the_value() {
+    let mut reader = reader(&[0x01, 0x00, 0x02, 0xFF, 0xFF, 0xFF, 0xFD, 0x03]);
+
+    assert_eq!(reader.read_u8().unwrap(), 1);
+    assert_eq!(reader.read_u16().unwrap(), 2);
+    assert_eq!(reader.read_i32().unwrap(), -3);
+    assert_eq!(reader.read_i8().unwrap(), 3);
+    assert!(reader.read_u8().is_err());
+}
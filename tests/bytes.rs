@@ -0,0 +1,18 @@
+#![cfg(feature = "bytes")]
+
+mod common;
+
+use bytes::Bytes;
+use vero_type::{buffer::VeroBufReader, font::Font};
+
+#[test]
+fn readers_share_the_buffer() {
+    let font = Bytes::from(common::minimal_font(&[]));
+
+    let first = Font::from_reader(&mut VeroBufReader::from_bytes(font.clone())).unwrap();
+    let mut reader = VeroBufReader::from_bytes(font.clone());
+    let second = Font::from_reader(&mut reader).unwrap();
+
+    assert_eq!(first.units_per_em(), second.units_per_em());
+    assert_eq!(reader.into_inner().into_inner().as_ptr(), font.as_ptr());
+}
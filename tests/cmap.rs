@@ -0,0 +1,394 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{
+    buffer::VeroBufReader,
+    font::Font,
+    tables::{
+        Table, TableEncodingError,
+        cmap::{Cmap, Coverage, Resolution},
+    },
+};
+
+#[test]
+fn subtables_can_be_picked_by_index() {
+    let font = common::minimal_font(&[]);
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+    let cmap = font.cmap();
+
+    assert_eq!(cmap.version(), 0);
+    assert_eq!(cmap.num_subtables(), 1);
+    assert_eq!(cmap.subtable(0).unwrap().glyph_index('B'), Some(2));
+    assert!(cmap.subtable(1).is_none());
+}
+
+#[test]
+fn unsupported_formats_are_reported() {
+    // a single (3, 1) subtable of format 6, which isn't supported
+    let mut table = vec![0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 12];
+    table.extend_from_slice(&[0, 6, 0, 10, 0, 0, 0, 0x41, 0, 0]);
+    let cmap = Cmap::parse(&table).unwrap();
+
+    assert!(cmap.best_subtable().is_none());
+    assert!(matches!(
+        cmap.try_best_subtable(),
+        Err(TableEncodingError::UnsupportedCmapFormat(6))
+    ));
+    assert!(matches!(
+        cmap.subtables()[0].try_glyph_index('A'),
+        Err(TableEncodingError::UnsupportedCmapFormat(6))
+    ));
+
+    // without any subtable there's simply no mapping
+    let empty = Cmap::parse(&[0, 0, 0, 0]).unwrap();
+    assert!(empty.try_best_subtable().unwrap().is_none());
+}
+
+/// A cmap with a single (3, 10) subtable of format 12 or 13
+/// made of `(start char code, end char code, start glyph id)` groups
+fn cmap_with_groups(format: u16, groups: &[(u32, u32, u32)]) -> Vec<u8> {
+    let mut table = vec![0, 0, 0, 1, 0, 3, 0, 10, 0, 0, 0, 12];
+    table.extend_from_slice(&format.to_be_bytes());
+    table.extend_from_slice(&0u16.to_be_bytes()); // reserved
+    table.extend_from_slice(&(16 + groups.len() as u32 * 12).to_be_bytes());
+    table.extend_from_slice(&0u32.to_be_bytes()); // language
+    table.extend_from_slice(&(groups.len() as u32).to_be_bytes());
+
+    for (start, end, glyph) in groups {
+        table.extend_from_slice(&start.to_be_bytes());
+        table.extend_from_slice(&end.to_be_bytes());
+        table.extend_from_slice(&glyph.to_be_bytes());
+    }
+
+    table
+}
+
+#[test]
+fn format_12_glyph_ids_which_overflow_are_unmapped() {
+    let cmap = Cmap::parse(&cmap_with_groups(12, &[(0x41, 0x42, u32::MAX)])).unwrap();
+
+    assert_eq!(cmap.glyph_index('A'), None);
+    assert_eq!(cmap.glyph_index('B'), None);
+
+    let cmap = Cmap::parse(&cmap_with_groups(12, &[(0x41, 0x43, 0xFFFE)])).unwrap();
+
+    assert_eq!(cmap.glyph_index('B'), Some(0xFFFF));
+    assert_eq!(cmap.glyph_index('C'), None);
+}
+
+#[test]
+fn format_13_maps_whole_ranges_to_one_glyph() {
+    // a single (0, 6) subtable of format 13 with two groups
+    let mut table = vec![0, 0, 0, 1, 0, 0, 0, 6, 0, 0, 0, 12];
+    table.extend_from_slice(&[0, 13, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 2]);
+    table.extend_from_slice(&[0, 0, 0, 0x41, 0, 0, 0, 0x5A, 0, 0, 0, 7]);
+    table.extend_from_slice(&[0, 0x01, 0xF6, 0x00, 0, 0x01, 0xF6, 0x4F, 0, 0, 0, 9]);
+    let cmap = Cmap::parse(&table).unwrap();
+
+    assert_eq!(cmap.glyph_index('A'), Some(7));
+    assert_eq!(cmap.glyph_index('Q'), Some(7));
+    assert_eq!(cmap.glyph_index('\u{1F600}'), Some(9));
+    assert_eq!(cmap.glyph_index('a'), None);
+}
+
+/// A (3, 2) Shift-JIS subtable of format 2, one byte codes from 0x20 to 0x7E
+/// map to the glyphs from 3 on, 0x8140 maps to glyph 101 and 0x8141 to `.notdef`
+fn shift_jis_cmap() -> Vec<u8> {
+    let mut table = vec![0, 0, 0, 1, 0, 3, 0, 2, 0, 0, 0, 12];
+    let glyphs = 0x5F + 2;
+    let length = 6 + 512 + 2 * 8 + glyphs * 2;
+    table.extend_from_slice(&[0, 2]);
+    table.extend_from_slice(&(length as u16).to_be_bytes());
+    table.extend_from_slice(&[0, 0]);
+
+    // every byte is a one byte code but 0x81, the lead byte of the second sub header
+    let mut keys = [0u16; 256];
+    keys[0x81] = 8;
+    table.extend(keys.iter().flat_map(|key| key.to_be_bytes()));
+
+    // the range offsets point from their own field into the glyph index array
+    // which follows the two sub headers, the first one at it's start and
+    // the second one past the 0x5F glyphs of the first one
+    table.extend_from_slice(&[0, 0x20, 0, 0x5F, 0, 0, 0, 10]);
+    table.extend_from_slice(&[0, 0x40, 0, 2, 0, 100, 0, 2 + 0x5F * 2]);
+    table.extend((0x20..0x7F).flat_map(|code: u16| (code - 0x1D).to_be_bytes()));
+    table.extend_from_slice(&[0, 1, 0, 0]);
+
+    table
+}
+
+#[test]
+fn format_2_maps_one_and_two_byte_codes() {
+    let cmap = Cmap::parse(&shift_jis_cmap()).unwrap();
+    let subtable = cmap.subtable(0).unwrap();
+
+    assert_eq!(subtable.format(), 2);
+    assert_eq!(subtable.code_glyph_index(0x20), Some(3));
+    assert_eq!(subtable.code_glyph_index(0x41), Some(36));
+    assert_eq!(subtable.code_glyph_index(0x8140), Some(101));
+    assert_eq!(subtable.code_glyph_index(0x8141), Some(0));
+}
+
+#[test]
+fn format_2_rejects_codes_outside_the_sub_headers() {
+    let cmap = Cmap::parse(&shift_jis_cmap()).unwrap();
+    let subtable = cmap.subtable(0).unwrap();
+
+    // past the entries of the sub headers
+    assert_eq!(subtable.code_glyph_index(0x7F), None);
+    assert_eq!(subtable.code_glyph_index(0x813F), None);
+    assert_eq!(subtable.code_glyph_index(0x8142), None);
+    // a lead byte alone and a second byte after a byte which isn't a lead byte
+    assert_eq!(subtable.code_glyph_index(0x81), None);
+    assert_eq!(subtable.code_glyph_index(0x8240), None);
+    assert_eq!(subtable.code_glyph_index(0x10000), None);
+}
+
+#[test]
+fn legacy_encodings_are_never_picked_for_chars() {
+    // a lone (3, 2) Shift-JIS subtable of format 2
+    let cmap = Cmap::parse(&shift_jis_cmap()).unwrap();
+
+    assert!(cmap.best_subtable().is_none());
+    assert!(cmap.try_best_subtable().unwrap().is_none());
+    assert_eq!(cmap.glyph_index(' '), None);
+    assert_eq!(cmap.glyph_index('A'), None);
+
+    let font = common::build_font(&[
+        (b"cmap", &shift_jis_cmap()),
+        (b"head", &common::head_table()),
+        (b"hhea", &common::hhea_table(2)),
+        (
+            b"hmtx",
+            &common::hmtx_table(&[(500, 10), (600, 20)], &[30, 40]),
+        ),
+        (b"maxp", &common::maxp_table(4)),
+        (b"name", &common::EMPTY_NAME),
+    ]);
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+
+    assert_eq!(font.cmap().glyph_index('A'), None);
+    assert!(!font.supports_char('A'));
+}
+
+#[test]
+fn unicode_subtables_are_recognized() {
+    // (3, 0), (1, 0), (3, 1) and (0, 3) records sharing a single format 6 mapping
+    let mut table = vec![0, 0, 0, 4];
+    for (platform, encoding) in [(3u16, 0u16), (1, 0), (3, 1), (0, 3)] {
+        table.extend_from_slice(&platform.to_be_bytes());
+        table.extend_from_slice(&encoding.to_be_bytes());
+        table.extend_from_slice(&36u32.to_be_bytes());
+    }
+    table.extend_from_slice(&[0, 6, 0, 10, 0, 0, 0, 0x41, 0, 0]);
+    let cmap = Cmap::parse(&table).unwrap();
+
+    let unicode: Vec<_> = cmap
+        .subtables()
+        .iter()
+        .map(|sub| sub.is_unicode())
+        .collect();
+    assert_eq!(unicode, [false, false, true, true]);
+}
+
+#[test]
+fn unsupported_chars_are_listed_once_in_order() {
+    // 'A' to 'C' have glyphs, '0' is explicitly mapped to .notdef
+    let cmap = Cmap::parse(&common::cmap_table(&[(0x30, 0x30, 0), (0x41, 0x43, 1)])).unwrap();
+
+    assert_eq!(cmap.coverage('B'), Coverage::Glyph(2));
+    assert_eq!(cmap.coverage('0'), Coverage::NotDef);
+    assert_eq!(cmap.coverage('D'), Coverage::Unmapped);
+    assert_eq!(cmap.coverage('\u{1F600}'), Coverage::Unmapped);
+
+    assert_eq!(
+        cmap.unsupported_chars("CAB0 BAD0\u{1F600}"),
+        ['0', ' ', 'D', '\u{1F600}']
+    );
+    assert!(cmap.unsupported_chars("ABCCBA").is_empty());
+    assert!(cmap.unsupported_chars("").is_empty());
+}
+
+/// The `(start, end, first glyph)` ranges of `common::cmap_table`
+type Ranges<'a> = &'a [(u16, u16, u16)];
+
+/// A cmap with a format 4 subtable per `(platform id, encoding id, ranges)`
+fn cmap_with_subtables(subtables: &[(u16, u16, Ranges)]) -> Vec<u8> {
+    let mut records = vec![0, 0];
+    records.extend_from_slice(&(subtables.len() as u16).to_be_bytes());
+    let mut data = Vec::new();
+
+    for (platform_id, encoding_id, ranges) in subtables {
+        let offset = 4 + subtables.len() * 8 + data.len();
+        records.extend_from_slice(&platform_id.to_be_bytes());
+        records.extend_from_slice(&encoding_id.to_be_bytes());
+        records.extend_from_slice(&(offset as u32).to_be_bytes());
+        // drop the header and the single encoding record
+        data.extend_from_slice(&common::cmap_table(ranges)[12..]);
+    }
+
+    records.extend(data);
+    records
+}
+
+#[test]
+fn resolution_names_the_subtable_and_the_mechanism() {
+    // a symbol subtable mapping 0xF041 to 0xF043, then a Unicode one mapping 'a' to 'c'
+    let cmap = Cmap::parse(&cmap_with_subtables(&[
+        (3, 0, &[(0xF041, 0xF043, 5)]),
+        (3, 1, &[(0x61, 0x63, 1)]),
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        cmap.resolve('b'),
+        Resolution::Base {
+            glyph_id: 2,
+            subtable: 1
+        }
+    );
+    // the Unicode subtable doesn't map 'B', the symbol subtable does at 0xF042
+    assert_eq!(
+        cmap.resolve('B'),
+        Resolution::SymbolOffset {
+            glyph_id: 6,
+            subtable: 0
+        }
+    );
+    assert_eq!(cmap.resolve('B').glyph_id(), Some(6));
+    assert_eq!(cmap.glyph_index('B'), None);
+    assert_eq!(cmap.resolve('D'), Resolution::Unmapped);
+    assert_eq!(cmap.resolve('\u{142}'), Resolution::Unmapped);
+}
+
+#[test]
+fn symbol_fonts_resolve_through_the_offset() {
+    let cmap = Cmap::parse(&cmap_with_subtables(&[(3, 0, &[(0xF020, 0xF0FF, 3)])])).unwrap();
+
+    assert_eq!(
+        cmap.resolve('!'),
+        Resolution::SymbolOffset {
+            glyph_id: 4,
+            subtable: 0
+        }
+    );
+    // characters already in the private use range map directly
+    assert_eq!(
+        cmap.resolve('\u{F021}'),
+        Resolution::Base {
+            glyph_id: 4,
+            subtable: 0
+        }
+    );
+    assert_eq!(cmap.resolve('\u{1F}'), Resolution::Unmapped);
+}
+
+#[test]
+fn fonts_map_characters_through_the_best_subtable_and_the_symbol_offset() {
+    let font = Font::from_shared(common::minimal_font(&[])).unwrap();
+
+    assert_eq!(font.glyph_for_char('B'), Some(2));
+    assert_eq!(font.glyph_for_char('B'), font.cmap().glyph_index('B'));
+    assert_eq!(font.glyph_for_char('Z'), None);
+
+    let cmap = cmap_with_subtables(&[(3, 0, &[(0xF020, 0xF0FF, 3)])]);
+    let font = common::build_font(&[
+        (b"cmap", &cmap),
+        (b"head", &common::head_table()),
+        (b"hhea", &common::hhea_table(2)),
+        (
+            b"hmtx",
+            &common::hmtx_table(&[(500, 10), (600, 20)], &[30, 40]),
+        ),
+        (b"maxp", &common::maxp_table(4)),
+        (b"name", &common::EMPTY_NAME),
+    ]);
+    let font = Font::from_shared(font).unwrap();
+
+    assert_eq!(font.glyph_for_char('!'), Some(4));
+    assert_eq!(font.glyph_for_char('\u{F021}'), Some(4));
+    assert_eq!(font.glyph_for_char('\u{1F}'), None);
+}
+
+#[test]
+fn fonts_support_characters_mapped_to_a_glyph_other_than_notdef() {
+    let font = Font::from_shared(common::minimal_font(&[])).unwrap();
+
+    assert!(font.supports_char('A'));
+    assert!(font.supports_char('C'));
+    assert!(!font.supports_char('D'));
+    assert!(!font.supports_char('\u{1F600}'));
+
+    // a character explicitly mapped to .notdef isn't supported
+    let cmap = common::cmap_table(&[(0x20, 0x20, 0), (0x41, 0x43, 1)]);
+    let font = common::build_font(&[
+        (b"cmap", &cmap),
+        (b"head", &common::head_table()),
+        (b"hhea", &common::hhea_table(2)),
+        (
+            b"hmtx",
+            &common::hmtx_table(&[(500, 10), (600, 20)], &[30, 40]),
+        ),
+        (b"maxp", &common::maxp_table(4)),
+        (b"name", &common::EMPTY_NAME),
+    ]);
+    let font = Font::from_shared(font).unwrap();
+
+    assert_eq!(font.glyph_for_char(' '), Some(0));
+    assert!(!font.supports_char(' '));
+    assert!(font.supports_char('B'));
+}
+
+#[test]
+fn mappings_stay_within_the_unicode_scalar_values() {
+    // a group running to the end of the u32 codes, only the codes whose glyph id
+    // fits in a u16 are mapped, less the surrogates
+    let cmap = Cmap::parse(&cmap_with_groups(12, &[(0x41, u32::MAX, 1)])).unwrap();
+    let mappings = cmap.mappings().collect::<Vec<_>>();
+
+    assert_eq!(mappings.len(), 0xFFFF - 0x800);
+    assert_eq!(mappings.first(), Some(&('A', 1)));
+    assert_eq!(mappings.last(), Some(&('\u{1003F}', 0xFFFF)));
+
+    // groups overlapping each other yield every character once
+    let groups = [(0x41, 0x43, 1), (0x42, 0x44, 20), (0x44, 0x45, 10)];
+    let cmap = Cmap::parse(&cmap_with_groups(12, &groups)).unwrap();
+    let chars = cmap.mappings().map(|(c, _)| c).collect::<String>();
+
+    assert_eq!(chars, "ABCDE");
+
+    // a group past the last scalar value maps nothing
+    let cmap = Cmap::parse(&cmap_with_groups(13, &[(0x11_0000, u32::MAX, 1)])).unwrap();
+    assert_eq!(cmap.mappings().count(), 0);
+}
+
+#[test]
+fn char_metrics_pair_mapped_characters_with_their_advance_widths() {
+    let font = Font::from_shared(common::minimal_font(&[])).unwrap();
+
+    assert_eq!(
+        font.char_metrics().collect::<Vec<_>>(),
+        [('A', 1, 600), ('B', 2, 600), ('C', 3, 600)]
+    );
+
+    // characters mapped to .notdef or past the horizontal metrics are skipped
+    let cmap = common::cmap_table(&[(0x20, 0x20, 0), (0x41, 0x41, 0), (0x5A, 0x5A, 9)]);
+    let font = common::build_font(&[
+        (b"cmap", &cmap),
+        (b"head", &common::head_table()),
+        (b"hhea", &common::hhea_table(2)),
+        (
+            b"hmtx",
+            &common::hmtx_table(&[(500, 10), (600, 20)], &[30, 40]),
+        ),
+        (b"maxp", &common::maxp_table(4)),
+        (b"name", &common::EMPTY_NAME),
+    ]);
+    let font = Font::from_shared(font).unwrap();
+
+    assert_eq!(font.cmap().mappings().collect::<Vec<_>>(), [('Z', 9)]);
+    assert_eq!(font.char_metrics().count(), 0);
+}
@@ -0,0 +1,187 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{
+    buffer::VeroBufReader,
+    font::Font,
+    tables::{Table, TableEncodingError, cblc::Cblc},
+};
+
+/// Builds a CBDT table out of `(metrics length, png)` bitmaps,
+/// returning it with the offset of every bitmap
+fn cbdt_table(bitmaps: &[(usize, &[u8])]) -> (Vec<u8>, Vec<u32>) {
+    let mut table = vec![0, 3, 0, 0];
+    let mut offsets = Vec::new();
+
+    for (metrics_length, png) in bitmaps {
+        offsets.push(table.len() as u32);
+        table.extend(std::iter::repeat_n(0, *metrics_length));
+        table.extend_from_slice(&(png.len() as u32).to_be_bytes());
+        table.extend_from_slice(png);
+    }
+
+    (table, offsets)
+}
+
+/// An index subtable header followed by it's body
+fn index_subtable(
+    index_format: u16,
+    image_format: u16,
+    image_data_offset: u32,
+    body: &[u8],
+) -> Vec<u8> {
+    let mut subtable = index_format.to_be_bytes().to_vec();
+    subtable.extend_from_slice(&image_format.to_be_bytes());
+    subtable.extend_from_slice(&image_data_offset.to_be_bytes());
+    subtable.extend_from_slice(body);
+    subtable
+}
+
+/// Builds a CBLC table out of `(ppem, first glyph, last glyph, index subtable)` strikes,
+/// every strike has a single index subtable
+fn cblc_table(strikes: &[(u8, u16, u16, Vec<u8>)]) -> Vec<u8> {
+    let mut table = vec![0, 3, 0, 0];
+    table.extend_from_slice(&(strikes.len() as u32).to_be_bytes());
+
+    let mut array_offset = 8 + strikes.len() as u32 * 48;
+    let mut arrays = Vec::new();
+
+    for (ppem, first, last, subtable) in strikes {
+        table.extend_from_slice(&array_offset.to_be_bytes());
+        table.extend_from_slice(&(8 + subtable.len() as u32).to_be_bytes()); // index tables size
+        table.extend_from_slice(&1u32.to_be_bytes()); // number of index subtables
+        table.extend_from_slice(&[0; 28]); // color ref and line metrics
+        table.extend_from_slice(&first.to_be_bytes());
+        table.extend_from_slice(&last.to_be_bytes());
+        table.extend_from_slice(&[*ppem, *ppem, 32, 1]);
+
+        arrays.extend_from_slice(&first.to_be_bytes());
+        arrays.extend_from_slice(&last.to_be_bytes());
+        arrays.extend_from_slice(&8u32.to_be_bytes());
+        arrays.extend_from_slice(subtable);
+        array_offset += 8 + subtable.len() as u32;
+    }
+
+    table.extend_from_slice(&arrays);
+    table
+}
+
+/// Glyph 1 has a bitmap in the 20 and 109 ppem strikes, glyph 3 in the 64 and 109 ppem strikes
+fn color_font() -> Vec<u8> {
+    let (cbdt, offsets) = cbdt_table(&[
+        (5, b"png 1 at 20"),
+        (8, b"png 1 at 109"),
+        (8, b"png 3 at 109"),
+        (0, b"png 3 at 64"),
+    ]);
+
+    // Format 1: 4 byte offsets, glyph 2 has no bitmap
+    let small = index_subtable(1, 17, offsets[0], &[0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 20]);
+
+    // Format 4: sparse glyphs with 2 byte offsets
+    let mut large = (2u32).to_be_bytes().to_vec();
+    for (glyph_id, offset) in [(1u16, 0u16), (3, 24), (0, 48)] {
+        large.extend_from_slice(&glyph_id.to_be_bytes());
+        large.extend_from_slice(&offset.to_be_bytes());
+    }
+    let large = index_subtable(4, 18, offsets[1], &large);
+
+    // Format 2: constant sized bitmaps
+    let medium = index_subtable(2, 19, offsets[3], &[0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    let cblc = cblc_table(&[(20, 1, 2, small), (109, 1, 3, large), (64, 3, 3, medium)]);
+
+    common::minimal_font(&[(b"CBDT", &cbdt), (b"CBLC", &cblc)])
+}
+
+#[test]
+fn strikes_are_enumerated() {
+    let font = color_font();
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+    let strikes = font.cblc().unwrap().strikes();
+
+    assert_eq!(
+        strikes
+            .iter()
+            .map(|strike| strike.ppem_y())
+            .collect::<Vec<_>>(),
+        [20, 109, 64]
+    );
+    assert_eq!(strikes[1].bit_depth(), 32);
+    assert_eq!(strikes[1].start_glyph_id(), 1);
+    assert_eq!(strikes[1].end_glyph_id(), 3);
+    assert!(strikes[0].location(1).is_some());
+    assert!(strikes[0].location(2).is_none());
+}
+
+#[test]
+fn the_closest_large_enough_strike_is_picked() {
+    let font = color_font();
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+
+    assert_eq!(font.color_bitmap(1, 16), Some(&b"png 1 at 20"[..]));
+    assert_eq!(font.color_bitmap(1, 20), Some(&b"png 1 at 20"[..]));
+    assert_eq!(font.color_bitmap(1, 21), Some(&b"png 1 at 109"[..]));
+    assert_eq!(font.color_bitmap(3, 32), Some(&b"png 3 at 64"[..]));
+    assert_eq!(font.color_bitmap(3, 100), Some(&b"png 3 at 109"[..]));
+
+    // Without a large enough strike the largest one is scaled down
+    assert_eq!(font.color_bitmap(1, 200), Some(&b"png 1 at 109"[..]));
+}
+
+#[test]
+fn glyphs_without_a_bitmap_have_none() {
+    let font = color_font();
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+
+    assert_eq!(font.color_bitmap(0, 20), None);
+    assert_eq!(font.color_bitmap(2, 20), None);
+
+    let font = common::minimal_font(&[]);
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+    assert_eq!(font.color_bitmap(1, 20), None);
+}
+
+#[test]
+fn unknown_index_formats_are_rejected() {
+    let cblc = cblc_table(&[(20, 1, 1, index_subtable(6, 17, 4, &[]))]);
+
+    assert!(matches!(
+        Cblc::parse(&cblc),
+        Err(TableEncodingError::UnsupportedBitmapIndexFormat(6))
+    ));
+}
+
+#[test]
+fn aliased_index_subtables_are_not_expanded() {
+    // A single strike whose 20000 index subtable entries all point at the same
+    // format 2 subtable covering every glyph id
+    let subtable = index_subtable(2, 17, 4, &[0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0]);
+    let num_subtables = 20_000u32;
+    let subtable_offset = num_subtables * 8;
+
+    let mut cblc = vec![0, 3, 0, 0, 0, 0, 0, 1];
+    cblc.extend_from_slice(&56u32.to_be_bytes()); // index subtable array offset
+    cblc.extend_from_slice(&(subtable_offset + subtable.len() as u32).to_be_bytes());
+    cblc.extend_from_slice(&num_subtables.to_be_bytes());
+    cblc.extend_from_slice(&[0; 28]);
+    cblc.extend_from_slice(&0u16.to_be_bytes());
+    cblc.extend_from_slice(&u16::MAX.to_be_bytes());
+    cblc.extend_from_slice(&[20, 20, 32, 1]);
+
+    for _ in 0..num_subtables {
+        cblc.extend_from_slice(&0u16.to_be_bytes());
+        cblc.extend_from_slice(&u16::MAX.to_be_bytes());
+        cblc.extend_from_slice(&subtable_offset.to_be_bytes());
+    }
+    cblc.extend_from_slice(&subtable);
+
+    let cblc = Cblc::parse(&cblc).unwrap();
+    let location = cblc.locate(u16::MAX, 20).unwrap();
+
+    assert_eq!(location.offset(), 4 + u32::from(u16::MAX) * 16);
+    assert_eq!(location.length(), 16);
+}
@@ -0,0 +1,183 @@
+//! Helpers shared by the integration tests
+
+#![allow(dead_code)]
+
+/// Assembles a minimal sfnt file out of `(tag, bytes)` tables, in the given order.
+/// Checksums are left as zero since the parser doesn't verify them.
+pub fn build_font(tables: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+    let mut font = Vec::new();
+    font.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+    font.extend_from_slice(&(tables.len() as u16).to_be_bytes());
+    font.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+
+    let mut offset = 12 + tables.len() as u32 * 16;
+
+    for (tag, bytes) in tables {
+        font.extend_from_slice(*tag);
+        font.extend_from_slice(&0u32.to_be_bytes());
+        font.extend_from_slice(&offset.to_be_bytes());
+        font.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+
+        offset += bytes.len().next_multiple_of(4) as u32;
+    }
+
+    for (_, bytes) in tables {
+        font.extend_from_slice(bytes);
+        font.resize(font.len().next_multiple_of(4), 0);
+    }
+
+    font
+}
+
+/// A name table without any records
+pub const EMPTY_NAME: [u8; 6] = [0, 0, 0, 0, 0, 6];
+
+/// A version 1.0 head table with 1000 units per em and long loca offsets
+pub fn head_table() -> Vec<u8> {
+    let mut head = Vec::new();
+    head.extend_from_slice(&0x0001_0000u32.to_be_bytes()); // version
+    head.extend_from_slice(&0x0001_0000u32.to_be_bytes()); // font revision
+    head.extend_from_slice(&0u32.to_be_bytes()); // checksum adjustment
+    head.extend_from_slice(&0x5F0F_3CF5u32.to_be_bytes()); // magic number
+    head.extend_from_slice(&0x0003u16.to_be_bytes()); // flags
+    head.extend_from_slice(&1000u16.to_be_bytes()); // units per em
+    head.extend_from_slice(&[0; 16]); // created and modified
+    head.extend_from_slice(&[0; 8]); // bounding box
+    head.extend_from_slice(&0u16.to_be_bytes()); // mac style
+    head.extend_from_slice(&8u16.to_be_bytes()); // lowest rec ppem
+    head.extend_from_slice(&2i16.to_be_bytes()); // font direction hint
+    head.extend_from_slice(&1i16.to_be_bytes()); // index to loc format
+    head.extend_from_slice(&0i16.to_be_bytes()); // glyph data format
+    head
+}
+
+/// A version 0.5 maxp table
+pub fn maxp_table(num_glyphs: u16) -> Vec<u8> {
+    let mut maxp = 0x0000_5000u32.to_be_bytes().to_vec();
+    maxp.extend_from_slice(&num_glyphs.to_be_bytes());
+    maxp
+}
+
+/// A version 1.0 maxp table with the given maximum component depth, every other limit is 0
+pub fn maxp_table_v1(num_glyphs: u16, max_component_depth: u16) -> Vec<u8> {
+    let mut maxp = 0x0001_0000u32.to_be_bytes().to_vec();
+    maxp.extend_from_slice(&num_glyphs.to_be_bytes());
+    maxp.extend_from_slice(&[0; 24]);
+    maxp.extend_from_slice(&max_component_depth.to_be_bytes());
+    maxp
+}
+
+/// A version 1.0 hhea table with an 800/-200 ascent/descent
+/// and `num_of_long_hor_metrics` advance widths
+pub fn hhea_table(num_of_long_hor_metrics: u16) -> Vec<u8> {
+    let mut hhea = Vec::new();
+    hhea.extend_from_slice(&0x0001_0000u32.to_be_bytes()); // version
+    hhea.extend_from_slice(&800i16.to_be_bytes()); // ascent
+    hhea.extend_from_slice(&(-200i16).to_be_bytes()); // descent
+    hhea.extend_from_slice(&0i16.to_be_bytes()); // line gap
+    hhea.extend_from_slice(&600u16.to_be_bytes()); // advance width max
+    hhea.extend_from_slice(&[0; 6]); // side bearings and x max extent
+    hhea.extend_from_slice(&1i16.to_be_bytes()); // caret slope rise
+    hhea.extend_from_slice(&0i16.to_be_bytes()); // caret slope run
+    hhea.extend_from_slice(&0i16.to_be_bytes()); // caret offset
+    hhea.extend_from_slice(&[0; 8]); // reserved
+    hhea.extend_from_slice(&0i16.to_be_bytes()); // metric data format
+    hhea.extend_from_slice(&num_of_long_hor_metrics.to_be_bytes());
+    hhea
+}
+
+/// A hmtx table out of `(advance width, lsb)` metrics followed by the lone `lsbs`
+pub fn hmtx_table(metrics: &[(u16, i16)], lsbs: &[i16]) -> Vec<u8> {
+    let mut hmtx = Vec::new();
+
+    for (advance_width, lsb) in metrics {
+        hmtx.extend_from_slice(&advance_width.to_be_bytes());
+        hmtx.extend_from_slice(&lsb.to_be_bytes());
+    }
+
+    for lsb in lsbs {
+        hmtx.extend_from_slice(&lsb.to_be_bytes());
+    }
+
+    hmtx
+}
+
+/// A cmap table with a single (3, 1) format 4 subtable mapping the
+/// `(start, end, first glyph)` ranges with deltas
+pub fn cmap_table(ranges: &[(u16, u16, u16)]) -> Vec<u8> {
+    // the final 0xFFFF segment is mandatory
+    let mut segments = ranges.to_vec();
+    segments.push((0xFFFF, 0xFFFF, 0));
+
+    let seg_count = segments.len() as u16;
+    let mut subtable = Vec::new();
+    subtable.extend_from_slice(&4u16.to_be_bytes());
+    subtable.extend_from_slice(&(16 + seg_count * 8).to_be_bytes());
+    subtable.extend_from_slice(&0u16.to_be_bytes());
+    subtable.extend_from_slice(&(seg_count * 2).to_be_bytes());
+    subtable.extend_from_slice(&[0; 6]);
+
+    for (_, end, _) in &segments {
+        subtable.extend_from_slice(&end.to_be_bytes());
+    }
+
+    subtable.extend_from_slice(&0u16.to_be_bytes());
+
+    for (start, _, _) in &segments {
+        subtable.extend_from_slice(&start.to_be_bytes());
+    }
+
+    for (start, _, glyph) in &segments {
+        let delta = match start {
+            0xFFFF => 1,
+            _ => glyph.wrapping_sub(*start),
+        };
+        subtable.extend_from_slice(&delta.to_be_bytes());
+    }
+
+    for _ in &segments {
+        subtable.extend_from_slice(&0u16.to_be_bytes());
+    }
+
+    let mut cmap = Vec::new();
+    cmap.extend_from_slice(&[0, 0, 0, 1, 0, 3, 0, 1, 0, 0, 0, 12]);
+    cmap.extend_from_slice(&subtable);
+    cmap
+}
+
+/// The tables every font parsed by `Font` needs, plus `extra`
+pub fn minimal_font(extra: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+    let head = head_table();
+    let maxp = maxp_table(4);
+    let cmap = cmap_table(&[(0x41, 0x43, 1)]);
+    let hhea = hhea_table(2);
+    let hmtx = hmtx_table(&[(500, 10), (600, 20)], &[30, 40]);
+
+    let mut tables: Vec<(&[u8; 4], &[u8])> = vec![
+        (b"cmap", &cmap),
+        (b"head", &head),
+        (b"hhea", &hhea),
+        (b"hmtx", &hmtx),
+        (b"maxp", &maxp),
+        (b"name", &EMPTY_NAME),
+    ];
+    tables.extend_from_slice(extra);
+
+    build_font(&tables)
+}
+
+/// Builds an fvar table with a single `wght` axis ranging from 100 to 900
+pub fn wght_fvar_table() -> Vec<u8> {
+    let mut table = Vec::new();
+    for value in [1u16, 0, 16, 2, 1, 20, 0, 4] {
+        table.extend_from_slice(&value.to_be_bytes());
+    }
+    table.extend_from_slice(b"wght");
+    for value in [100i32, 400, 900] {
+        table.extend_from_slice(&(value << 16).to_be_bytes());
+    }
+    table.extend_from_slice(&0u16.to_be_bytes());
+    table.extend_from_slice(&256u16.to_be_bytes());
+
+    table
+}
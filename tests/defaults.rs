@@ -0,0 +1,36 @@
+use vero_type::tables::{
+    OffsetTable, TableMetadata,
+    head::{HEAD_MAGIC_NUMBER, Head, LocaFormat, UNITS_PER_EM_RANGE},
+};
+
+#[test]
+fn the_default_head_is_a_valid_baseline() {
+    let head = Head::default();
+
+    assert_eq!(head.version(), 0x0001_0000);
+    assert_eq!(head.font_revision(), 0x0001_0000);
+    assert_eq!(head.magic_number(), HEAD_MAGIC_NUMBER);
+    assert_eq!(
+        head.flags().active(),
+        ["y_value_zero_is_baseline", "x_pos_leftmost_black_bit_lsb"]
+    );
+    assert!(UNITS_PER_EM_RANGE.contains(&head.units_per_em()));
+    assert_eq!(head.font_direction_hint(), 2);
+    assert_eq!(head.loca_format(), LocaFormat::Short);
+    assert_eq!(head.checksum_adjustment(), 0);
+}
+
+#[test]
+fn the_default_directory_is_empty() {
+    let offset = OffsetTable::default();
+
+    assert_eq!(offset.scalar_type(), 0x0001_0000);
+    assert_eq!(offset.num_tables(), 0);
+    assert_eq!(offset.directory_byte_size(), 12);
+
+    let metadata = TableMetadata::default();
+    assert_eq!(
+        (metadata.checksum(), metadata.offset(), metadata.length()),
+        (0, 0, 0)
+    );
+}
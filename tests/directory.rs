@@ -0,0 +1,343 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{
+    VeroTypeError,
+    buffer::VeroBufReader,
+    font::Font,
+    tables::{
+        RequiredTables, Table, TableEncodingError, TableMetadata, Tables, TablesHeaders, name::Name,
+    },
+    tag::Tag,
+};
+
+#[test]
+fn duplicate_tags_are_rejected() {
+    let font = common::build_font(&[
+        (b"name", &common::EMPTY_NAME),
+        (b"head", &[0; 54]),
+        (b"name", &common::EMPTY_NAME),
+    ]);
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(font));
+
+    let error = Tables::from_reader(&mut reader).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The table directory lists the 'name' table more than once"
+    );
+}
+
+#[test]
+fn tags_which_arent_utf8_are_kept() {
+    let font = common::build_font(&[
+        (b"head", &common::head_table()),
+        (&[0xFF, 0xFE, 0x00, 0x01], &[1, 2, 3, 4]),
+        (b"name", &common::EMPTY_NAME),
+    ]);
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(font));
+    let tables = Tables::from_reader(&mut reader).unwrap();
+
+    assert!(
+        tables
+            .headers()
+            .get_by_tag([0xFF, 0xFE, 0x00, 0x01])
+            .is_some()
+    );
+    assert_eq!(RequiredTables::try_from(&[0xFF, 0xFE][..]), Err(()));
+}
+
+#[test]
+fn required_tables_parse_from_strings() {
+    for tag in [
+        "cmap", "glyf", "head", "hhea", "hmtx", "loca", "maxp", "name", "post",
+    ] {
+        let table = RequiredTables::try_from(tag).unwrap();
+        assert_eq!(Ok(table), RequiredTables::try_from(tag.as_bytes()));
+    }
+
+    // the same error as the bytes, whatever the reason
+    for tag in ["", "cma", "cmap\0", "CMAP", "OS/2", "hëad"] {
+        assert_eq!(RequiredTables::try_from(tag), Err(()));
+    }
+}
+
+/// A table which is never present, to exercise `Tables::load` on a missing tag
+#[derive(Debug)]
+struct Absent;
+
+impl Table for Absent {
+    const TAG: Tag = Tag::new(*b"ABSN");
+
+    fn parse(_: &[u8]) -> Result<Self, TableEncodingError> {
+        Ok(Self)
+    }
+}
+
+#[test]
+fn tables_are_loaded_by_tag() {
+    let font = common::minimal_font(&[]);
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(font));
+    let tables = Tables::from_reader(&mut reader).unwrap();
+
+    assert!(
+        tables
+            .load::<Name, _>(&mut reader)
+            .unwrap()
+            .records()
+            .is_empty()
+    );
+    assert_eq!(
+        tables
+            .load::<Absent, _>(&mut reader)
+            .unwrap_err()
+            .to_string(),
+        "The font is missing the 'ABSN' table"
+    );
+}
+
+#[test]
+fn headers_can_be_iterated_by_reference() {
+    let font = common::minimal_font(&[(b"GSUB", &[0; 4])]);
+    let tables = Tables::from_shared(&font[..].into()).unwrap();
+
+    let mut listed = Vec::new();
+    for (table, metadata) in tables.headers() {
+        listed.push(*table);
+        assert_eq!(
+            tables.headers().get(*table).map(TableMetadata::offset),
+            Some(metadata.offset())
+        );
+    }
+
+    // only the required tables, the headers are still usable afterwards
+    assert_eq!(
+        listed,
+        [
+            RequiredTables::Cmap,
+            RequiredTables::Head,
+            RequiredTables::Hhea,
+            RequiredTables::Hmtx,
+            RequiredTables::Maxp,
+            RequiredTables::Name,
+        ]
+    );
+    assert_eq!(tables.headers().iter().count(), 6);
+    assert_eq!(tables.headers().tags().len(), 7);
+}
+
+#[test]
+fn directory_values_can_be_copied_out() {
+    let font = common::minimal_font(&[]);
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(font));
+    let tables = Tables::from_reader(&mut reader).unwrap();
+
+    let offset = *tables.offset();
+    let head = *tables.head().unwrap();
+    let metadata = *tables.headers().get(RequiredTables::Head).unwrap();
+    drop(tables);
+
+    assert_eq!(offset.num_tables(), 6);
+    assert_eq!(head.units_per_em(), 1000);
+    assert_eq!(metadata.length(), 54);
+}
+
+#[test]
+fn oversized_directory_counts_are_rejected_before_reading() {
+    let mut font = common::minimal_font(&[]);
+    // Claim far more tables than the file has room for
+    font[4..6].copy_from_slice(&u16::MAX.to_be_bytes());
+    let available = (font.len() as u64 - 12) / 16;
+
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(font.clone()));
+    let error = Tables::from_reader(&mut reader).unwrap_err();
+    assert!(matches!(
+        error,
+        VeroTypeError::TableEncodingError(TableEncodingError::DirectoryTooLarge {
+            declared: u16::MAX,
+            available: found,
+        }) if found == available
+    ));
+
+    let error = Tables::from_shared(&font.into()).unwrap_err();
+    assert!(matches!(
+        error,
+        VeroTypeError::TableEncodingError(TableEncodingError::DirectoryTooLarge { .. })
+    ));
+}
+
+#[test]
+fn truncated_directories_are_rejected() {
+    let font = common::minimal_font(&[]);
+    // Cut the file off in the middle of the third directory entry
+    let truncated = font[..12 + 2 * 16 + 8].to_vec();
+
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(truncated));
+    let error = Tables::from_reader(&mut reader).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The directory declares 6 tables but the file only has room for 2"
+    );
+}
+
+/// Headers out of `(tag, offset, length)` directory entries
+fn headers(entries: &[(&[u8; 4], u32, u32)]) -> TablesHeaders {
+    let mut directory = Vec::new();
+
+    for (tag, offset, length) in entries {
+        directory.extend_from_slice(*tag);
+        directory.extend_from_slice(&0u32.to_be_bytes());
+        directory.extend_from_slice(&offset.to_be_bytes());
+        directory.extend_from_slice(&length.to_be_bytes());
+    }
+
+    TablesHeaders::from_buffer(&directory).unwrap()
+}
+
+#[test]
+fn well_formed_directories_have_consistent_ranges() {
+    let font = common::minimal_font(&[]);
+    let tables =
+        Tables::directory_from_reader(&mut VeroBufReader::from_buffer(Cursor::new(&font))).unwrap();
+
+    assert!(
+        tables
+            .headers()
+            .validate_ranges(font.len() as u64)
+            .is_empty()
+    );
+    // cutting the last table short
+    assert!(matches!(
+        tables.headers().validate_ranges(font.len() as u64 - 4).as_slice(),
+        [TableEncodingError::TableOutOfBounds { tag, .. }] if *tag == "name"
+    ));
+}
+
+#[test]
+fn every_overlap_is_reported() {
+    // head overlaps the end of cmap and spans the whole of hhea, touching tables don't overlap
+    let headers = headers(&[
+        (b"cmap", 100, 20),
+        (b"head", 110, 54),
+        (b"hhea", 120, 36),
+        (b"name", 164, 6),
+        (b"post", 0xFFFF_FFFF, 32),
+    ]);
+
+    let errors = headers.validate_ranges(200);
+    let overlaps: Vec<_> = errors
+        .iter()
+        .filter_map(|error| match error {
+            TableEncodingError::OverlappingTables(a, b) => Some(format!("{a}/{b}")),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(overlaps, ["cmap/head", "head/hhea"]);
+    assert!(matches!(
+        errors[0],
+        TableEncodingError::TableOutOfBounds {
+            end: 0x1_0000_001F,
+            file_len: 200,
+            ..
+        }
+    ));
+    assert_eq!(errors.len(), 3);
+}
+
+#[test]
+fn empty_tables_never_overlap() {
+    let headers = headers(&[(b"cmap", 100, 20), (b"name", 110, 0), (b"post", 120, 0)]);
+
+    assert!(headers.validate_ranges(120).is_empty());
+    assert_eq!(
+        headers.validate_ranges(119)[0].to_string(),
+        "The 'cmap' table ends at byte 120, past the end of the 119 byte file"
+    );
+}
+
+#[test]
+fn directory_size_follows_the_number_of_tables() {
+    let font = Font::from_shared(common::minimal_font(&[(b"post", &[0; 32])])).unwrap();
+
+    assert_eq!(font.num_tables(), 7);
+    assert_eq!(font.directory_byte_size(), 12 + 7 * 16);
+    // the first table starts right after the directory
+    let first = font.tables().headers().get(RequiredTables::Cmap).unwrap();
+    assert_eq!(first.offset(), font.directory_byte_size());
+}
+
+#[test]
+fn table_ranges_never_truncate() {
+    let entry = |offset: u32, length: u32| {
+        let mut entry = b"name".to_vec();
+        entry.extend_from_slice(&0u32.to_be_bytes());
+        entry.extend_from_slice(&offset.to_be_bytes());
+        entry.extend_from_slice(&length.to_be_bytes());
+        TableMetadata::from_buffer(&entry).unwrap()
+    };
+
+    assert_eq!(entry(12, 6).range().unwrap(), 12..18);
+
+    let adversarial = entry(u32::MAX, u32::MAX);
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(adversarial.range().unwrap().end, 2 * u32::MAX as usize);
+    #[cfg(target_pointer_width = "32")]
+    assert!(matches!(
+        adversarial.range(),
+        Err(TableEncodingError::RangeOverflow {
+            offset: u32::MAX,
+            length: u32::MAX
+        })
+    ));
+
+    // either way the table lies outside of the font, the name entry is the 6th one
+    let mut font = common::minimal_font(&[]);
+    font[12 + 5 * 16 + 8..12 + 6 * 16].fill(0xFF);
+
+    assert!(Font::from_shared(font.clone()).is_err());
+    assert!(Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).is_err());
+}
+
+/// Reads the directory of `font` after replacing it's sfnt version
+fn directory_with_sfnt_version(mut font: Vec<u8>, sfnt_version: &[u8; 4]) -> Tables {
+    font[..4].copy_from_slice(sfnt_version);
+
+    Tables::directory_from_reader(&mut VeroBufReader::from_buffer(Cursor::new(&font))).unwrap()
+}
+
+#[test]
+fn outline_tables_have_to_match_the_sfnt_version() {
+    let truetype = common::minimal_font(&[(b"glyf", &[]), (b"loca", &[0; 10])]);
+    let cff = common::minimal_font(&[(b"CFF ", &[1, 0, 4, 4])]);
+
+    for sfnt_version in [&[0, 1, 0, 0], b"true"] {
+        let tables = directory_with_sfnt_version(truetype.clone(), sfnt_version);
+        assert!(tables.validate_outline_format().is_ok());
+    }
+    let tables = directory_with_sfnt_version(cff.clone(), b"OTTO");
+    assert!(tables.validate_outline_format().is_ok());
+
+    let tables = directory_with_sfnt_version(truetype.clone(), b"OTTO");
+    let error = tables.validate_outline_format().unwrap_err();
+    assert!(matches!(
+        error,
+        TableEncodingError::OutlineFormatMismatch { sfnt_version: 0x4F54_544F, table } if table == "glyf"
+    ));
+    assert_eq!(
+        error.to_string(),
+        "The sfnt version 0x4f54544f doesn't match the 'glyf' outline table"
+    );
+
+    let tables = directory_with_sfnt_version(cff.clone(), &[0, 1, 0, 0]);
+    assert!(matches!(
+        tables.validate_outline_format(),
+        Err(TableEncodingError::OutlineFormatMismatch { sfnt_version: 0x0001_0000, table }) if table == "CFF "
+    ));
+
+    // other sfnt versions aren't checked
+    let tables = directory_with_sfnt_version(cff, b"typ1");
+    assert!(tables.validate_outline_format().is_ok());
+}
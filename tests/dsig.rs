@@ -0,0 +1,62 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{buffer::VeroBufReader, font::Font};
+
+/// Builds a DSIG table header with the given signature count and flags
+fn dsig_table(num_signatures: u16, flags: u16) -> Vec<u8> {
+    let mut table = Vec::new();
+    table.extend_from_slice(&1u32.to_be_bytes());
+    table.extend_from_slice(&num_signatures.to_be_bytes());
+    table.extend_from_slice(&flags.to_be_bytes());
+
+    for _ in 0..num_signatures {
+        table.extend_from_slice(&1u32.to_be_bytes()); // format
+        table.extend_from_slice(&0u32.to_be_bytes()); // length
+        table.extend_from_slice(&0u32.to_be_bytes()); // offset
+    }
+
+    table
+}
+
+fn parse(font: Vec<u8>) -> Font {
+    Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap()
+}
+
+#[test]
+fn signatures_are_counted() {
+    let font = parse(common::minimal_font(&[(b"DSIG", &dsig_table(2, 1))]));
+    let dsig = font.dsig().unwrap();
+
+    assert_eq!(dsig.version(), 1);
+    assert_eq!(dsig.num_signatures(), 2);
+    assert_eq!(dsig.flags(), 1);
+    assert!(dsig.has_signature());
+    assert!(font.is_signed());
+}
+
+#[test]
+fn placeholder_table_is_not_signed() {
+    let font = parse(common::minimal_font(&[(b"DSIG", &dsig_table(0, 0))]));
+
+    assert!(!font.dsig().unwrap().has_signature());
+    assert!(!font.is_signed());
+}
+
+#[test]
+fn fonts_without_the_table_are_not_signed() {
+    let font = parse(common::minimal_font(&[]));
+
+    assert!(font.dsig().is_none());
+    assert!(!font.is_signed());
+}
+
+#[test]
+fn truncated_header_is_an_error() {
+    let font = common::minimal_font(&[(b"DSIG", &[0, 0, 0, 1, 0])]);
+
+    assert!(Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).is_err());
+}
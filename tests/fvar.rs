@@ -0,0 +1,90 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{buffer::VeroBufReader, font::Font};
+
+/// Builds an fvar table out of `(tag, min, default, max, flags)` axes,
+/// with the values as whole numbers and `axis_size` bytes per axis record
+fn fvar_table(axes: &[(&[u8; 4], i16, i16, i16, u16)], axis_size: u16) -> Vec<u8> {
+    let mut table = Vec::new();
+    table.extend_from_slice(&1u16.to_be_bytes());
+    table.extend_from_slice(&0u16.to_be_bytes());
+    table.extend_from_slice(&16u16.to_be_bytes()); // axes array offset
+    table.extend_from_slice(&2u16.to_be_bytes()); // reserved
+    table.extend_from_slice(&(axes.len() as u16).to_be_bytes());
+    table.extend_from_slice(&axis_size.to_be_bytes());
+    table.extend_from_slice(&0u16.to_be_bytes()); // instance count
+    table.extend_from_slice(&4u16.to_be_bytes()); // instance size
+
+    for (name_id, (tag, min, default, max, flags)) in axes.iter().enumerate() {
+        let start = table.len();
+
+        table.extend_from_slice(*tag);
+        for value in [min, default, max] {
+            table.extend_from_slice(&(i32::from(*value) << 16).to_be_bytes());
+        }
+        table.extend_from_slice(&flags.to_be_bytes());
+        table.extend_from_slice(&(256 + name_id as u16).to_be_bytes());
+
+        table.resize(start + usize::from(axis_size), 0);
+    }
+
+    table
+}
+
+fn parse(font: Vec<u8>) -> Result<Font, vero_type::VeroTypeError> {
+    Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font)))
+}
+
+#[test]
+fn axes_are_parsed() {
+    let fvar = fvar_table(&[(b"wght", 100, 400, 900, 0), (b"opsz", 8, 12, 72, 1)], 20);
+    let font = parse(common::minimal_font(&[(b"fvar", &fvar)])).unwrap();
+
+    assert!(font.is_variable());
+
+    let axes = font.axes();
+    assert_eq!(axes.len(), 2);
+    assert_eq!(font.fvar().unwrap().axis_count(), 2);
+
+    assert_eq!(axes[0].tag(), "wght");
+    assert_eq!(axes[0].min_value(), 100.0);
+    assert_eq!(axes[0].default_value(), 400.0);
+    assert_eq!(axes[0].max_value(), 900.0);
+    assert_eq!(axes[0].axis_name_id(), 256);
+    assert!(!axes[0].is_hidden());
+
+    let opsz = font.fvar().unwrap().axis(b"opsz").unwrap();
+    assert_eq!(opsz.max_value(), 72.0);
+    assert!(opsz.is_hidden());
+}
+
+#[test]
+fn larger_axis_records_are_skipped_over() {
+    let fvar = fvar_table(
+        &[(b"wdth", 75, 100, 125, 0), (b"wght", 200, 400, 800, 0)],
+        24,
+    );
+    let font = parse(common::minimal_font(&[(b"fvar", &fvar)])).unwrap();
+
+    assert_eq!(font.axes()[1].tag(), "wght");
+    assert_eq!(font.axes()[1].min_value(), 200.0);
+}
+
+#[test]
+fn static_fonts_have_no_axes() {
+    let font = parse(common::minimal_font(&[])).unwrap();
+
+    assert!(!font.is_variable());
+    assert!(font.axes().is_empty());
+}
+
+#[test]
+fn undersized_axis_records_are_errors() {
+    let fvar = fvar_table(&[(b"wght", 100, 400, 900, 0)], 16);
+
+    assert!(parse(common::minimal_font(&[(b"fvar", &fvar)])).is_err());
+}
@@ -0,0 +1,521 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{
+    VeroTypeError,
+    buffer::VeroBufReader,
+    font::Font,
+    tables::{
+        TableEncodingError,
+        glyf::{Glyph, Transform},
+    },
+};
+
+/// A glyph with one contour of (x, y, on curve) points, coordinates stored as words
+fn simple_glyph(points: &[(i16, i16, bool)]) -> Vec<u8> {
+    glyph_with_contours(&[points])
+}
+
+/// A glyph with the given contours of (x, y, on curve) points, coordinates stored as words
+fn glyph_with_contours(contours: &[&[(i16, i16, bool)]]) -> Vec<u8> {
+    let points: Vec<_> = contours.iter().flat_map(|contour| contour.iter()).collect();
+
+    let mut glyph = Vec::new();
+    glyph.extend_from_slice(&(contours.len() as i16).to_be_bytes());
+    glyph.extend_from_slice(&[0; 8]); // bounding box
+
+    let mut end_point = 0;
+    for contour in contours {
+        end_point += contour.len() as u16;
+        glyph.extend_from_slice(&(end_point - 1).to_be_bytes());
+    }
+
+    glyph.extend_from_slice(&0u16.to_be_bytes()); // no instructions
+
+    for (_, _, on_curve) in &points {
+        glyph.push(u8::from(*on_curve));
+    }
+
+    let (mut x, mut y) = (0, 0);
+
+    for (px, _, _) in &points {
+        glyph.extend_from_slice(&(px - x).to_be_bytes());
+        x = *px;
+    }
+
+    for (_, py, _) in &points {
+        glyph.extend_from_slice(&(py - y).to_be_bytes());
+        y = *py;
+    }
+
+    glyph
+}
+
+/// A composite glyph out of `(glyph id, flags)` components, the arguments and
+/// transform each flag asks for are zero filled and `MORE_COMPONENTS` is set for
+/// every component but the last
+fn composite_glyph(components: &[(u16, u16)]) -> Vec<u8> {
+    let mut glyph = Vec::new();
+    glyph.extend_from_slice(&(-1i16).to_be_bytes());
+    glyph.extend_from_slice(&[0; 8]); // bounding box
+
+    for (index, (glyph_id, flags)) in components.iter().enumerate() {
+        let more = if index + 1 < components.len() {
+            0x0020
+        } else {
+            0
+        };
+
+        glyph.extend_from_slice(&(flags | more).to_be_bytes());
+        glyph.extend_from_slice(&glyph_id.to_be_bytes());
+
+        let arguments = if flags & 0x0001 != 0 { 4 } else { 2 };
+        let transform = match flags {
+            _ if flags & 0x0008 != 0 => 2,
+            _ if flags & 0x0040 != 0 => 4,
+            _ if flags & 0x0080 != 0 => 8,
+            _ => 0,
+        };
+        glyph.resize(glyph.len() + arguments + transform, 0);
+    }
+
+    glyph
+}
+
+/// The glyf and loca tables holding the given glyphs, padded with empty glyphs
+/// up to the 4 glyphs of the minimal font, long loca offsets
+fn glyf_and_loca(glyphs: &[Vec<u8>]) -> (Vec<u8>, Vec<u8>) {
+    let mut glyf = Vec::new();
+    let mut loca = vec![0u32];
+
+    for index in 0..4 {
+        if let Some(glyph) = glyphs.get(index) {
+            glyf.extend_from_slice(glyph);
+        }
+        loca.push(glyf.len() as u32);
+    }
+
+    let loca = loca
+        .iter()
+        .flat_map(|offset| offset.to_be_bytes())
+        .collect::<Vec<u8>>();
+
+    (glyf, loca)
+}
+
+/// A font with the given glyphs, see `glyf_and_loca`
+fn font_with_glyphs(glyphs: &[Vec<u8>]) -> Font {
+    let (glyf, loca) = glyf_and_loca(glyphs);
+
+    let font = common::minimal_font(&[(b"glyf", &glyf), (b"loca", &loca)]);
+    Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap()
+}
+
+/// Like `font_with_glyphs`, with a version 1.0 maxp table declaring `max_component_depth`
+fn font_with_component_depth(glyphs: &[Vec<u8>], max_component_depth: u16) -> Font {
+    let (glyf, loca) = glyf_and_loca(glyphs);
+
+    let font = common::build_font(&[
+        (b"cmap", &common::cmap_table(&[(0x41, 0x43, 1)])),
+        (b"glyf", &glyf),
+        (b"head", &common::head_table()),
+        (b"hhea", &common::hhea_table(2)),
+        (
+            b"hmtx",
+            &common::hmtx_table(&[(500, 10), (600, 20)], &[30, 40]),
+        ),
+        (b"loca", &loca),
+        (b"maxp", &common::maxp_table_v1(4, max_component_depth)),
+        (b"name", &common::EMPTY_NAME),
+    ]);
+    Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap()
+}
+
+/// A font whose first glyph is empty and second glyph is `glyph`, long loca offsets
+fn font_with_glyph(glyph: &[u8]) -> Font {
+    let end = glyph.len() as u32;
+    let loca = [0u32, 0, end, end, end]
+        .iter()
+        .flat_map(|offset| offset.to_be_bytes())
+        .collect::<Vec<u8>>();
+
+    let font = common::minimal_font(&[(b"glyf", glyph), (b"loca", &loca)]);
+    Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap()
+}
+
+#[test]
+fn glyphs_are_located_through_loca() {
+    let font = font_with_glyph(&simple_glyph(&[(0, 0, true), (10, 0, true), (5, 10, true)]));
+
+    assert!(font.glyph(0).unwrap().unwrap().contours().is_empty());
+    assert_eq!(font.glyph(1).unwrap().unwrap().contours()[0].len(), 3);
+    assert!(font.glyph(4).unwrap().is_none());
+}
+
+#[test]
+fn flattening_starts_at_the_last_point_when_the_first_is_off_curve() {
+    let glyph = simple_glyph(&[(5, 10, false), (10, 0, true), (0, 0, true)]);
+    let glyph = Glyph::from_buffer(&glyph).unwrap();
+
+    assert_eq!(
+        glyph.flatten(100.0),
+        [[(0.0, 0.0), (10.0, 0.0), (0.0, 0.0)]]
+    );
+
+    let polyline = &glyph.flatten(0.5)[0];
+
+    assert_eq!(polyline.first(), polyline.last());
+    assert!(polyline.contains(&(10.0, 0.0)));
+}
+
+/// Returns the distance from `point` to the closest segment of `polyline`
+fn distance_to_polyline(point: (f32, f32), polyline: &[(f32, f32)]) -> f32 {
+    polyline
+        .windows(2)
+        .map(|segment| {
+            let ((ax, ay), (bx, by)) = (segment[0], segment[1]);
+            let (dx, dy) = (bx - ax, by - ay);
+            let length_squared = dx * dx + dy * dy;
+            let t = match length_squared {
+                0.0 => 0.0,
+                _ => (((point.0 - ax) * dx + (point.1 - ay) * dy) / length_squared).clamp(0.0, 1.0),
+            };
+
+            (point.0 - (ax + t * dx)).hypot(point.1 - (ay + t * dy))
+        })
+        .fold(f32::INFINITY, f32::min)
+}
+
+#[test]
+fn flattened_curves_stay_within_the_tolerance() {
+    // a curve from (0, 0) to (10, 0) bulging 5 units out
+    let glyph = simple_glyph(&[(5, 10, false), (10, 0, true), (0, 0, true)]);
+    let glyph = Glyph::from_buffer(&glyph).unwrap();
+
+    for tolerance in [2.0, 1.0, 0.5, 0.25, 0.1, 0.01] {
+        let polyline = &glyph.flatten(tolerance)[0];
+
+        for step in 0..=100 {
+            let t = step as f32 / 100.0;
+            let point = (10.0 * t, 20.0 * t * (1.0 - t));
+
+            assert!(distance_to_polyline(point, polyline) <= tolerance + 1e-4);
+        }
+    }
+}
+
+#[test]
+fn transforms_compose_in_order() {
+    let scale = Transform::uniform_scale(2.0);
+    let translate = Transform::translate(1.0, -1.0);
+
+    assert_eq!(scale.then(translate).apply((3.0, 4.0)), (7.0, 7.0));
+    assert_eq!(translate.then(scale).apply((3.0, 4.0)), (8.0, 6.0));
+    assert_eq!(Transform::default().then(scale), scale);
+    assert_eq!(
+        Transform::scale(1.0, -1.0).matrix(),
+        [1.0, 0.0, 0.0, -1.0, 0.0, 0.0]
+    );
+
+    // a quarter turn counterclockwise
+    let rotate = Transform::new([0.0, 1.0, -1.0, 0.0, 0.0, 0.0]);
+    assert_eq!(rotate.apply((1.0, 0.0)), (0.0, 1.0));
+    assert_eq!(rotate.then(rotate).apply((1.0, 2.0)), (-1.0, -2.0));
+}
+
+#[test]
+fn outlines_are_transformed_point_by_point() {
+    let glyph = simple_glyph(&[(5, 10, false), (10, 0, true), (0, 0, true)]);
+    let glyph = Glyph::from_buffer(&glyph).unwrap();
+    let to_pixels = Transform::pixels_per_em(1000, 100.0).then(Transform::translate(0.0, 2.0));
+
+    // the control point is transformed too
+    assert_eq!(
+        glyph.transformed(to_pixels),
+        [[(0.5, 3.0), (1.0, 2.0), (0.0, 2.0)]]
+    );
+
+    // transforming the polylines matches flattening the transformed outline
+    let flattened: Vec<Vec<_>> = glyph
+        .flatten(100.0)
+        .into_iter()
+        .map(|polyline| {
+            polyline
+                .into_iter()
+                .map(|point| to_pixels.apply(point))
+                .collect()
+        })
+        .collect();
+    assert_eq!(flattened, [[(0.0, 2.0), (1.0, 2.0), (0.0, 2.0)]]);
+
+    assert!(Glyph::default().transformed(to_pixels).is_empty());
+}
+
+#[test]
+fn descending_end_points_are_rejected() {
+    let mut glyph = simple_glyph(&[(0, 0, true), (10, 0, true)]);
+    // declare a second contour ending before the first one
+    glyph[1] = 2;
+    glyph.splice(12..12, [0, 0]);
+
+    assert!(Glyph::from_buffer(&glyph).is_err());
+}
+
+#[test]
+fn contours_and_points_are_counted() {
+    let square: &[(i16, i16, bool)] = &[(0, 0, true), (10, 0, true), (10, 10, true), (0, 10, true)];
+    let triangle: &[(i16, i16, bool)] = &[(2, 2, true), (8, 2, true), (5, 8, false)];
+    let glyphs = [
+        Vec::new(),
+        glyph_with_contours(&[square, triangle]),
+        composite_glyph(&[(1, 0)]),
+    ];
+    let font = font_with_glyphs(&glyphs);
+    let (glyf, loca) = (font.glyf().unwrap(), font.loca().unwrap());
+
+    let empty = font.glyph(0).unwrap().unwrap();
+    assert!(empty.is_empty());
+    assert_eq!((empty.num_contours(), empty.num_points()), (0, 0));
+
+    let simple = font.glyph(1).unwrap().unwrap();
+    assert!(!simple.is_empty());
+    assert_eq!((simple.num_contours(), simple.num_points()), (2, 7));
+
+    let composite = font.glyph(2).unwrap().unwrap();
+    assert!(!composite.is_empty());
+    assert_eq!((composite.num_contours(), composite.num_points()), (0, 0));
+
+    // the header only count agrees without decoding the outlines
+    assert_eq!(glyf.num_points(loca, 0).unwrap(), 0);
+    assert_eq!(glyf.num_points(loca, 1).unwrap(), 7);
+    assert_eq!(glyf.num_points(loca, 2).unwrap(), 0);
+    assert_eq!(glyf.num_points(loca, 9).unwrap(), 0);
+}
+
+#[test]
+fn truncated_end_points_are_rejected_when_counting_points() {
+    let mut glyph = simple_glyph(&[(0, 0, true), (10, 0, true)]);
+    // declare 3 contours, the end points of the last one lie past the glyph
+    glyph[1] = 3;
+    glyph.truncate(14);
+    let font = font_with_glyph(&glyph);
+
+    assert!(
+        font.glyf()
+            .unwrap()
+            .num_points(font.loca().unwrap(), 1)
+            .is_err()
+    );
+}
+
+#[test]
+fn composite_components_are_listed_in_order() {
+    let triangle = simple_glyph(&[(0, 0, true), (10, 0, true), (5, 10, true)]);
+    // word arguments with a scale, then byte arguments with a 2 by 2 matrix,
+    // then byte arguments with separate x and y scales
+    let composite = composite_glyph(&[(1, 0x0001 | 0x0008), (3, 0x0080), (1, 0x0040)]);
+    let font = font_with_glyphs(&[Vec::new(), triangle, composite]);
+
+    assert_eq!(font.component_glyphs(2).unwrap(), [1, 3, 1]);
+    assert!(font.glyph(2).unwrap().unwrap().is_composite());
+}
+
+#[test]
+fn simple_empty_and_missing_glyphs_have_no_components() {
+    let triangle = simple_glyph(&[(0, 0, true), (10, 0, true), (5, 10, true)]);
+    let font = font_with_glyphs(&[Vec::new(), triangle]);
+
+    assert!(font.component_glyphs(0).unwrap().is_empty());
+    assert!(font.component_glyphs(1).unwrap().is_empty());
+    assert!(font.component_glyphs(4).unwrap().is_empty());
+}
+
+#[test]
+fn truncated_component_records_are_rejected() {
+    let mut composite = composite_glyph(&[(1, 0x0001), (2, 0x0080)]);
+    // cut the 2 by 2 matrix of the last component short
+    composite.truncate(composite.len() - 3);
+    let font = font_with_glyphs(&[Vec::new(), composite]);
+
+    assert!(font.component_glyphs(1).is_err());
+}
+
+#[test]
+fn closure_follows_nested_composites() {
+    let triangle = simple_glyph(&[(0, 0, true), (10, 0, true), (5, 10, true)]);
+    let inner = composite_glyph(&[(1, 0)]);
+    let outer = composite_glyph(&[(2, 0), (1, 0)]);
+    let font = font_with_glyphs(&[Vec::new(), triangle, inner, outer]);
+
+    assert_eq!(Vec::from_iter(font.glyph_closure(&[3]).unwrap()), [1, 2, 3]);
+    assert_eq!(Vec::from_iter(font.glyph_closure(&[1, 1]).unwrap()), [1]);
+    assert!(font.glyph_closure(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn closure_survives_component_cycles() {
+    // glyphs 1 and 2 reference each other, glyph 2 also points past the font
+    let first = composite_glyph(&[(2, 0)]);
+    let second = composite_glyph(&[(1, 0), (40, 0)]);
+    let font = font_with_glyphs(&[Vec::new(), first, second]);
+
+    assert_eq!(Vec::from_iter(font.glyph_closure(&[1, 9]).unwrap()), [1, 2]);
+}
+
+#[test]
+fn nesting_past_the_maxp_component_depth_is_an_error() {
+    let triangle = simple_glyph(&[(0, 0, true), (10, 0, true), (5, 10, true)]);
+    let inner = composite_glyph(&[(1, 0)]);
+    let outer = composite_glyph(&[(2, 0)]);
+    let glyphs = [Vec::new(), triangle, inner, outer];
+
+    let font = font_with_component_depth(&glyphs, 2);
+    assert_eq!(font.maxp().max_component_depth(), Some(2));
+    assert_eq!(Vec::from_iter(font.glyph_closure(&[3]).unwrap()), [1, 2, 3]);
+
+    let font = font_with_component_depth(&glyphs, 1);
+    assert!(matches!(
+        font.glyph_closure(&[3]),
+        Err(TableEncodingError::ComponentDepthExceeded(1))
+    ));
+    // the inner composite alone only nests one level
+    assert_eq!(Vec::from_iter(font.glyph_closure(&[2]).unwrap()), [1, 2]);
+}
+
+#[test]
+fn declared_component_depths_are_capped() {
+    // glyph 1 is simple and every following glyph is a composite of the one before it
+    let num_glyphs = 20u16;
+    let mut glyf = simple_glyph(&[(0, 0, true), (10, 0, true), (5, 10, true)]);
+    let mut loca = vec![0u32, 0, glyf.len() as u32];
+
+    for glyph_id in 2..num_glyphs {
+        glyf.extend_from_slice(&composite_glyph(&[(glyph_id - 1, 0)]));
+        loca.push(glyf.len() as u32);
+    }
+
+    let loca = loca
+        .iter()
+        .flat_map(|offset| offset.to_be_bytes())
+        .collect::<Vec<u8>>();
+    let lsbs = vec![0; usize::from(num_glyphs) - 2];
+
+    let font = common::build_font(&[
+        (b"cmap", &common::cmap_table(&[(0x41, 0x43, 1)])),
+        (b"glyf", &glyf),
+        (b"head", &common::head_table()),
+        (b"hhea", &common::hhea_table(2)),
+        (b"hmtx", &common::hmtx_table(&[(500, 10), (600, 20)], &lsbs)),
+        (b"loca", &loca),
+        (b"maxp", &common::maxp_table_v1(num_glyphs, u16::MAX)),
+        (b"name", &common::EMPTY_NAME),
+    ]);
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+
+    assert!(matches!(
+        font.glyph_closure(&[num_glyphs - 1]),
+        Err(TableEncodingError::ComponentDepthExceeded(16))
+    ));
+    assert_eq!(font.glyph_closure(&[17]).unwrap().len(), 17);
+}
+
+#[test]
+fn maxp_before_version_1_has_no_component_depth() {
+    let font = font_with_glyphs(&[]);
+
+    assert_eq!(font.maxp().max_component_depth(), None);
+}
+
+/// A clockwise 30 by 30 square with a 10 by 10 square in it's middle, wound
+/// clockwise as well if `same_direction` and counter-clockwise otherwise
+fn square_with_inner_square(same_direction: bool) -> Glyph {
+    let outer = [(0, 0, true), (0, 30, true), (30, 30, true), (30, 0, true)];
+    let mut inner = [
+        (10, 10, true),
+        (10, 20, true),
+        (20, 20, true),
+        (20, 10, true),
+    ];
+    if !same_direction {
+        inner.reverse();
+    }
+
+    Glyph::from_buffer(&glyph_with_contours(&[&outer, &inner])).unwrap()
+}
+
+#[test]
+fn counters_wound_the_other_way_are_left_empty() {
+    let glyph = square_with_inner_square(false);
+
+    assert_eq!(
+        glyph.rasterize(3, 3),
+        [255, 255, 255, 255, 0, 255, 255, 255, 255]
+    );
+}
+
+#[test]
+fn overlapping_contours_are_filled_with_the_nonzero_rule() {
+    let glyph = square_with_inner_square(true);
+
+    assert_eq!(glyph.rasterize(3, 3), [255; 9]);
+}
+
+#[test]
+fn partially_covered_pixels_are_gray() {
+    // a right triangle splitting the bitmap along it's diagonal
+    let glyph = simple_glyph(&[(0, 0, true), (0, 20, true), (20, 0, true)]);
+    let bitmap = Glyph::from_buffer(&glyph).unwrap().rasterize(2, 2);
+
+    // the top left and bottom right pixels are cut in half by the diagonal
+    assert!(bitmap[0] > 0 && bitmap[0] < 255);
+    assert_eq!(bitmap[1], 0);
+    assert_eq!(bitmap[2], 255);
+    assert!(bitmap[3] > 0 && bitmap[3] < 255);
+}
+
+#[test]
+fn glyphs_without_an_outline_rasterize_blank() {
+    let composite = Glyph::from_buffer(&composite_glyph(&[(1, 0)])).unwrap();
+    let empty = Glyph::from_buffer(&[]).unwrap();
+    let square = square_with_inner_square(true);
+
+    assert_eq!(composite.rasterize(4, 4), [0; 16]);
+    assert_eq!(empty.rasterize(4, 4), [0; 16]);
+    assert!(square.rasterize(0, 4).is_empty());
+}
+
+#[test]
+fn bitmaps_too_large_to_allocate_are_empty() {
+    let square = square_with_inner_square(true);
+
+    assert!(square.rasterize(u32::MAX, u32::MAX).is_empty());
+}
+
+#[test]
+fn loca_must_end_where_glyf_does() {
+    let glyph = simple_glyph(&[(0, 0, true), (10, 0, true), (5, 10, true)]);
+    let end = glyph.len() as u32;
+
+    for last in [end + 4, end - 2] {
+        let loca = [0u32, 0, end, end, last]
+            .iter()
+            .flat_map(|offset| offset.to_be_bytes())
+            .collect::<Vec<u8>>();
+        let font = common::minimal_font(&[(b"glyf", &glyph), (b"loca", &loca)]);
+
+        let from_reader = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(&font)));
+        let from_shared = Font::from_shared(font);
+
+        for result in [from_reader, from_shared] {
+            assert!(matches!(
+                result,
+                Err(VeroTypeError::TableEncodingError(
+                    TableEncodingError::LocaGlyfLengthMismatch { loca, glyf }
+                )) if loca == last && glyf == end
+            ));
+        }
+    }
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{buffer::VeroBufReader, font::Font, glyph_cache::GlyphCache};
+
+/// A triangle outline with one contour, coordinates stored as words
+const TRIANGLE: [u8; 29] = [
+    0, 1, // one contour
+    0, 0, 0, 0, 0, 10, 0, 10, // bounding box
+    0, 2, // end point of the contour
+    0, 0, // no instructions
+    1, 1, 1, // on curve flags
+    0, 0, 0, 10, 255, 251, // x deltas: 0, 10, -5
+    0, 0, 0, 0, 0, 10, // y deltas: 0, 0, 10
+];
+
+/// A font whose first glyph is the triangle and the other 3 glyphs are empty
+fn font() -> Font {
+    let end = TRIANGLE.len() as u32;
+    let loca = [0u32, end, end, end, end]
+        .iter()
+        .flat_map(|offset| offset.to_be_bytes())
+        .collect::<Vec<u8>>();
+
+    let font = common::minimal_font(&[(b"glyf", &TRIANGLE), (b"loca", &loca)]);
+    Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap()
+}
+
+#[test]
+fn hits_match_the_font() {
+    let font = font();
+    let mut cache = GlyphCache::new(&font, 4);
+
+    let decoded = font.glyph(0).unwrap().unwrap();
+    assert_eq!(
+        cache.glyph(0).unwrap().unwrap().contours(),
+        decoded.contours()
+    );
+    assert_eq!(
+        cache.glyph(0).unwrap().unwrap().contours(),
+        decoded.contours()
+    );
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn the_least_recently_used_glyph_is_evicted() {
+    let font = font();
+    let mut cache = GlyphCache::new(&font, 2);
+
+    cache.glyph(0).unwrap();
+    cache.glyph(1).unwrap();
+    // touching 0 leaves 1 as the least recently used glyph
+    cache.glyph(0).unwrap();
+    cache.glyph(2).unwrap();
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.contains(0));
+    assert!(!cache.contains(1));
+    assert!(cache.contains(2));
+}
+
+#[test]
+fn missing_glyphs_arent_cached() {
+    let font = font();
+    let mut cache = GlyphCache::new(&font, 0);
+
+    assert_eq!(cache.capacity(), 1);
+    assert!(cache.glyph(4).unwrap().is_none());
+    assert!(cache.is_empty());
+
+    cache.glyph(3).unwrap();
+    cache.clear();
+    assert!(cache.is_empty());
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{
+    buffer::VeroBufReader,
+    font::Font,
+    tables::{Table, TableEncodingError, gvar::Gvar},
+};
+
+/// Builds a gvar table over a single axis with one shared tuple of 1.0,
+/// `glyphs` holds the glyph variation data of every glyph, empty for none
+fn gvar_table(glyphs: &[&[u8]], long_offsets: bool) -> Vec<u8> {
+    let offset_size = if long_offsets { 4 } else { 2 };
+    let shared_tuples_offset = 20 + (glyphs.len() + 1) * offset_size;
+    let array_offset = shared_tuples_offset + 2;
+
+    let mut table = Vec::new();
+    table.extend_from_slice(&1u16.to_be_bytes());
+    table.extend_from_slice(&0u16.to_be_bytes());
+    table.extend_from_slice(&1u16.to_be_bytes()); // axis count
+    table.extend_from_slice(&1u16.to_be_bytes()); // shared tuple count
+    table.extend_from_slice(&(shared_tuples_offset as u32).to_be_bytes());
+    table.extend_from_slice(&(glyphs.len() as u16).to_be_bytes());
+    table.extend_from_slice(&u16::from(long_offsets).to_be_bytes());
+    table.extend_from_slice(&(array_offset as u32).to_be_bytes());
+
+    let mut offset = 0;
+    for length in core::iter::once(0).chain(glyphs.iter().map(|glyph| glyph.len())) {
+        offset += length;
+        if long_offsets {
+            table.extend_from_slice(&(offset as u32).to_be_bytes());
+        } else {
+            table.extend_from_slice(&((offset / 2) as u16).to_be_bytes());
+        }
+    }
+
+    table.extend_from_slice(&0x4000u16.to_be_bytes()); // the shared tuple, 1.0
+    for glyph in glyphs {
+        table.extend_from_slice(glyph);
+    }
+
+    table
+}
+
+/// Glyph variation data with shared point numbers, a tuple referring to the
+/// shared tuple and a tuple with an embedded peak of -1.0 and an intermediate region
+const VARIED_GLYPH: &[u8] = &[
+    0x80, 0x02, // shared point numbers, two tuples
+    0x00, 0x14, // data offset
+    0x00, 0x04, 0x00, 0x00, // 4 bytes of deltas, shared tuple 0
+    0x00, 0x06, 0xE0, 0x00, // 6 bytes of deltas, embedded peak, intermediate, private points
+    0xC0, 0x00, // peak -1.0
+    0xC0, 0x00, 0xE0, 0x00, // intermediate region from -1.0 to -0.5
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // the serialized data
+];
+
+fn parse(font: Vec<u8>) -> Result<Font, vero_type::VeroTypeError> {
+    Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font)))
+}
+
+#[test]
+fn header_is_parsed() {
+    let gvar = Gvar::parse(&gvar_table(&[&[], VARIED_GLYPH], false)).unwrap();
+
+    assert_eq!(gvar.major_version(), 1);
+    assert_eq!(gvar.minor_version(), 0);
+    assert_eq!(gvar.axis_count(), 1);
+    assert_eq!(gvar.glyph_count(), 2);
+    assert_eq!(gvar.shared_tuple_count(), 1);
+    assert_eq!(gvar.shared_tuples(), &[vec![1.0]]);
+}
+
+#[test]
+fn glyphs_with_variation_data_are_reported() {
+    for long_offsets in [false, true] {
+        let gvar = Gvar::parse(&gvar_table(&[&[], VARIED_GLYPH, &[]], long_offsets)).unwrap();
+
+        assert!(!gvar.has_variation_data(0));
+        assert!(gvar.has_variation_data(1));
+        assert!(!gvar.has_variation_data(2));
+        assert!(!gvar.has_variation_data(3));
+        assert_eq!(gvar.varying_glyphs(), vec![1]);
+    }
+}
+
+#[test]
+fn tuple_variation_headers_are_parsed() {
+    let gvar = Gvar::parse(&gvar_table(&[&[], VARIED_GLYPH], true)).unwrap();
+
+    assert_eq!(gvar.glyph_variation_data(0).unwrap(), None);
+    assert_eq!(gvar.glyph_variation_data(7).unwrap(), None);
+
+    let data = gvar.glyph_variation_data(1).unwrap().unwrap();
+    assert!(data.has_shared_point_numbers());
+    assert_eq!(data.data_offset(), 20);
+    assert_eq!(data.tuple_count(), 2);
+
+    let shared = &data.tuples()[0];
+    assert_eq!(shared.variation_data_size(), 4);
+    assert_eq!(shared.shared_tuple_index(), Some(0));
+    assert_eq!(shared.peak_tuple(), None);
+    assert_eq!(shared.intermediate_region(), None);
+    assert!(!shared.has_private_point_numbers());
+
+    let embedded = &data.tuples()[1];
+    assert_eq!(embedded.variation_data_size(), 6);
+    assert_eq!(embedded.shared_tuple_index(), None);
+    assert_eq!(embedded.peak_tuple(), Some(&[-1.0][..]));
+    assert_eq!(
+        embedded.intermediate_region(),
+        Some((&[-1.0][..], &[-0.5][..]))
+    );
+    assert!(embedded.has_private_point_numbers());
+}
+
+#[test]
+fn truncated_tuple_variation_headers_are_an_error() {
+    let gvar = Gvar::parse(&gvar_table(&[&VARIED_GLYPH[..14]], false)).unwrap();
+
+    assert!(gvar.has_variation_data(0));
+    assert!(matches!(
+        gvar.glyph_variation_data(0),
+        Err(TableEncodingError::InvalidBufferLength(..))
+    ));
+}
+
+#[test]
+fn truncated_offsets_are_an_error() {
+    let mut table = gvar_table(&[VARIED_GLYPH], false);
+    table.truncate(22);
+
+    assert!(Gvar::parse(&table).is_err());
+}
+
+#[test]
+fn gvar_is_loaded_with_fvar() {
+    let fvar = common::wght_fvar_table();
+    let gvar = gvar_table(&[&[], VARIED_GLYPH], false);
+    let font = parse(common::minimal_font(&[(b"fvar", &fvar), (b"gvar", &gvar)])).unwrap();
+
+    assert!(font.gvar().unwrap().has_variation_data(1));
+}
+
+#[test]
+fn gvar_is_ignored_without_fvar() {
+    let gvar = gvar_table(&[&[], VARIED_GLYPH], false);
+    let font = parse(common::minimal_font(&[(b"gvar", &gvar)])).unwrap();
+
+    assert!(!font.is_variable());
+    assert!(font.gvar().is_none());
+}
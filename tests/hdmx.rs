@@ -0,0 +1,107 @@
+mod common;
+
+use vero_type::{
+    font::Font,
+    tables::{TableEncodingError, hdmx::Hdmx},
+};
+
+/// Builds an hdmx table out of `(pixel size, widths)` records, padded to 4 bytes
+fn hdmx_table(records: &[(u8, &[u8])]) -> Vec<u8> {
+    let record_size = records
+        .first()
+        .map_or(0, |(_, widths)| (widths.len() + 2).next_multiple_of(4));
+
+    let mut table = Vec::new();
+    table.extend_from_slice(&0u16.to_be_bytes());
+    table.extend_from_slice(&(records.len() as u16).to_be_bytes());
+    table.extend_from_slice(&(record_size as u32).to_be_bytes());
+
+    for (pixel_size, widths) in records {
+        let start = table.len();
+
+        table.push(*pixel_size);
+        table.push(widths.iter().copied().max().unwrap_or(0));
+        table.extend_from_slice(widths);
+
+        table.resize(start + record_size, 0);
+    }
+
+    table
+}
+
+#[test]
+fn device_records_are_parsed() {
+    let hdmx =
+        Hdmx::from_buffer(&hdmx_table(&[(12, &[6, 7, 5, 9]), (16, &[8, 9, 7, 12])]), 4).unwrap();
+
+    assert_eq!(hdmx.version(), 0);
+    assert_eq!(hdmx.record_size(), 8);
+    assert_eq!(hdmx.records().len(), 2);
+
+    let record = hdmx.record(16).unwrap();
+    assert_eq!(record.pixel_size(), 16);
+    assert_eq!(record.max_width(), 12);
+    assert_eq!(record.widths(), [8, 9, 7, 12]);
+}
+
+#[test]
+fn advances_are_looked_up_by_pixel_size() {
+    let hdmx =
+        Hdmx::from_buffer(&hdmx_table(&[(12, &[6, 7, 5, 9]), (16, &[8, 9, 7, 12])]), 4).unwrap();
+
+    assert_eq!(hdmx.advance_at_ppem(1, 12), Some(7));
+    assert_eq!(hdmx.advance_at_ppem(3, 16), Some(12));
+    // no record for the size and a glyph id out of bounds
+    assert_eq!(hdmx.advance_at_ppem(1, 14), None);
+    assert_eq!(hdmx.advance_at_ppem(4, 12), None);
+}
+
+#[test]
+fn records_too_small_for_the_glyphs_are_an_error() {
+    let table = hdmx_table(&[(12, &[6, 7])]);
+
+    assert!(matches!(
+        Hdmx::from_buffer(&table, 4),
+        Err(TableEncodingError::MalformedHdmx(_))
+    ));
+}
+
+#[test]
+fn negative_record_counts_are_an_error() {
+    let mut table = hdmx_table(&[(12, &[6, 7, 5, 9])]);
+    table[2..4].copy_from_slice(&(-1i16).to_be_bytes());
+
+    assert!(matches!(
+        Hdmx::from_buffer(&table, 4),
+        Err(TableEncodingError::MalformedHdmx(_))
+    ));
+}
+
+#[test]
+fn truncated_records_are_an_error() {
+    let mut table = hdmx_table(&[(12, &[6, 7, 5, 9]), (16, &[8, 9, 7, 12])]);
+    table.truncate(table.len() - 4);
+
+    assert!(Hdmx::from_buffer(&table, 4).is_err());
+}
+
+#[test]
+fn fonts_load_the_hdmx_table() {
+    let hdmx = hdmx_table(&[(12, &[6, 7, 5, 9])]);
+    let font = Font::from_shared(common::minimal_font(&[(b"hdmx", &hdmx)])).unwrap();
+
+    assert_eq!(font.hdmx().unwrap().advance_at_ppem(2, 12), Some(5));
+
+    let font = Font::from_shared(common::minimal_font(&[])).unwrap();
+    assert!(font.hdmx().is_none());
+}
+
+#[test]
+fn malformed_hdmx_tables_are_dropped() {
+    // the records only hold widths for 2 of the 4 glyphs
+    let hdmx = hdmx_table(&[(12, &[6, 7])]);
+    let font = Font::from_shared(common::minimal_font(&[(b"hdmx", &hdmx)])).unwrap();
+
+    assert!(font.hdmx().is_none());
+    assert_eq!(font.maxp().num_glyphs(), 4);
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use vero_type::{
+    font::Font,
+    tables::{
+        Table,
+        head::{FlagSpec, HEAD_VERSION, Head, HeadFlags},
+    },
+};
+
+#[test]
+fn the_common_head_flags_are_named() {
+    let head = Head::parse(&common::head_table()).unwrap();
+
+    assert_eq!(
+        head.flags().active(),
+        ["y_value_zero_is_baseline", "x_pos_leftmost_black_bit_lsb"]
+    );
+}
+
+#[test]
+fn every_defined_bit_has_a_name_of_its_own() {
+    let names: Vec<_> = (0..15)
+        .map(|bit| HeadFlags::from_bits(1 << bit).active())
+        .collect();
+
+    for (bit, name) in names.iter().enumerate() {
+        assert_eq!(name.len(), 1, "bit {bit}");
+        assert_eq!(names.iter().filter(|other| *other == name).count(), 1);
+    }
+
+    assert_eq!(names[5], ["vertical_layout"]);
+    assert_eq!(names[13], ["optimized_for_cleartype"]);
+    assert_eq!(HeadFlags::from_bits(0xFFFF).active().len(), 15);
+    assert!(HeadFlags::from_bits(0).active().is_empty());
+}
+
+#[test]
+fn the_specs_agree_on_the_low_bits_and_the_symbol_bit() {
+    let flags = HeadFlags::from_bits(0x401F);
+    let common = [
+        "y_value_zero_is_baseline",
+        "x_pos_leftmost_black_bit_lsb",
+        "scaled_point_size_differs",
+        "use_integer_scaling",
+        "microsoft_scaler_flag",
+        "generic_symbol_font",
+    ];
+
+    assert_eq!(flags.active_for(FlagSpec::Apple), common);
+    assert_eq!(flags.active_for(FlagSpec::Microsoft), common);
+    assert_eq!(flags.active(), common);
+}
+
+#[test]
+fn apple_bits_are_left_out_of_the_microsoft_reading() {
+    // bits 5 to 10
+    let flags = HeadFlags::from_bits(0x07E0);
+
+    assert_eq!(flags.active_for(FlagSpec::Apple).len(), 6);
+    assert!(flags.active_for(FlagSpec::Microsoft).is_empty());
+}
+
+#[test]
+fn adobe_defined_bits_are_only_named_by_microsoft() {
+    // bits 11 to 14
+    let flags = HeadFlags::from_bits(0x7800);
+
+    assert!(
+        flags.lossless_font_data() && flags.converted_font() && flags.optimized_for_cleartype()
+    );
+    assert_eq!(
+        flags.active_for(FlagSpec::Microsoft),
+        [
+            "lossless_font_data",
+            "converted_font",
+            "optimized_for_cleartype",
+            "generic_symbol_font",
+        ]
+    );
+    assert_eq!(
+        flags.active_for(FlagSpec::Apple),
+        ["adobe_defined", "generic_symbol_font"]
+    );
+    assert_eq!(
+        HeadFlags::from_bits(0x1001).active_for(FlagSpec::Apple),
+        ["y_value_zero_is_baseline", "adobe_defined"]
+    );
+}
+
+#[test]
+fn other_versions_are_flagged_but_still_parsed() {
+    let mut table = common::head_table();
+    let head = Head::parse(&table).unwrap();
+
+    assert_eq!(head.version(), HEAD_VERSION);
+    assert!(head.is_standard_version());
+
+    table[..4].copy_from_slice(&0x0002_0000u32.to_be_bytes());
+    let head = Head::parse(&table).unwrap();
+
+    assert_eq!(head.version(), 0x0002_0000);
+    assert!(!head.is_standard_version());
+    assert_eq!(head.units_per_em(), 1000);
+}
+
+/// A font listing `extra`, with the head flags set to `flags`
+fn font_with_flags(flags: u16, extra: &[(&[u8; 4], &[u8])]) -> Font {
+    let mut font = common::minimal_font(extra);
+
+    let record = (12..)
+        .step_by(16)
+        .find(|&at| &font[at..at + 4] == b"head")
+        .unwrap();
+    let offset = u32::from_be_bytes(font[record + 8..record + 12].try_into().unwrap()) as usize;
+    font[offset + 16..offset + 18].copy_from_slice(&flags.to_be_bytes());
+
+    Font::from_shared(font).unwrap()
+}
+
+#[test]
+fn aat_layout_is_detected_from_the_directory() {
+    assert!(!font_with_flags(0x0003, &[]).has_aat_layout());
+    assert!(font_with_flags(0x0003, &[(b"morx", &[0; 8])]).has_aat_layout());
+    assert!(font_with_flags(0x0003, &[(b"mort", &[0; 8])]).has_aat_layout());
+    assert!(font_with_flags(0x0003, &[(b"kerx", &[0; 8])]).has_aat_layout());
+
+    // the flag alone doesn't make an AAT font
+    assert!(!font_with_flags(0x0103, &[]).has_aat_layout());
+}
+
+#[test]
+fn fonts_with_both_models_follow_the_metamorphosis_flag() {
+    let both: &[(&[u8; 4], &[u8])] = &[(b"GSUB", &[0; 8]), (b"morx", &[0; 8])];
+
+    assert!(!font_with_flags(0x0003, both).has_aat_layout());
+    assert!(font_with_flags(0x0103, both).has_aat_layout());
+}
@@ -0,0 +1,199 @@
+mod common;
+
+use vero_type::{
+    font::Font,
+    tables::{
+        Table, TableEncodingError,
+        kern::{Kern, KernMapping},
+    },
+};
+
+/// An OpenType format 0 subtable with the given coverage flags and `(left, right, value)` pairs,
+/// listed in the given order
+fn format_0_subtable(flags: u8, pairs: &[(u16, u16, i16)]) -> Vec<u8> {
+    let mut subtable = Vec::new();
+    subtable.extend_from_slice(&0u16.to_be_bytes());
+    subtable.extend_from_slice(&(14 + pairs.len() as u16 * 6).to_be_bytes());
+    subtable.extend_from_slice(&[0, flags]);
+    subtable.extend_from_slice(&(pairs.len() as u16).to_be_bytes());
+    subtable.extend_from_slice(&[0; 6]);
+
+    for (left, right, value) in pairs {
+        subtable.extend_from_slice(&left.to_be_bytes());
+        subtable.extend_from_slice(&right.to_be_bytes());
+        subtable.extend_from_slice(&value.to_be_bytes());
+    }
+
+    subtable
+}
+
+/// An OpenType kern table out of it's subtables
+fn kern_table(subtables: &[Vec<u8>]) -> Vec<u8> {
+    let mut kern = vec![0, 0];
+    kern.extend_from_slice(&(subtables.len() as u16).to_be_bytes());
+    kern.extend(subtables.concat());
+    kern
+}
+
+/// The body of a format 2 subtable whose header is `header_len` bytes long. Glyphs 10 and 11
+/// are the left classes and glyphs 20 to 22 the right classes of a 2 by 3 array
+fn format_2_body(header_len: u16) -> Vec<u8> {
+    let left_classes = header_len + 8;
+    let right_classes = left_classes + 8;
+    let array = right_classes + 10;
+    // 3 values per row
+    let row_width = 6u16;
+
+    let mut body = Vec::new();
+    for value in [row_width, left_classes, right_classes, array] {
+        body.extend_from_slice(&value.to_be_bytes());
+    }
+    // the left classes are the offsets of their rows from the start of the subtable
+    for value in [10, 2, array, array + row_width] {
+        body.extend_from_slice(&value.to_be_bytes());
+    }
+    // the right classes are the offsets of their values within a row
+    for value in [20u16, 3, 0, 2, 4] {
+        body.extend_from_slice(&value.to_be_bytes());
+    }
+    for value in [-10i16, -20, -30, 40, 50, 60] {
+        body.extend_from_slice(&value.to_be_bytes());
+    }
+
+    body
+}
+
+/// An Apple kern table with a single horizontal format 2 subtable
+fn apple_format_2_table() -> Vec<u8> {
+    let body = format_2_body(8);
+
+    let mut kern = 0x0001_0000u32.to_be_bytes().to_vec();
+    kern.extend_from_slice(&1u32.to_be_bytes());
+    kern.extend_from_slice(&(8 + body.len() as u32).to_be_bytes());
+    kern.extend_from_slice(&[0x00, 0x02, 0, 0]);
+    kern.extend(body);
+    kern
+}
+
+#[test]
+fn format_0_pairs_are_looked_up() {
+    // the pairs are out of order, the parser sorts them
+    let kern = Kern::parse(&kern_table(&[format_0_subtable(
+        0x01,
+        &[(36, 37, -50), (5, 9, 20), (36, 12, -7)],
+    )]))
+    .unwrap();
+
+    assert_eq!(kern.version(), 0);
+    let subtable = &kern.subtables()[0];
+    assert_eq!(subtable.format(), 0);
+    assert!(subtable.is_horizontal());
+    assert!(matches!(
+        subtable.mapping(),
+        Some(KernMapping::Format0(table)) if table.pairs()[0] == (5, 9, 20)
+    ));
+
+    assert_eq!(kern.kerning(36, 37), -50);
+    assert_eq!(kern.kerning(36, 12), -7);
+    assert_eq!(kern.kerning(5, 9), 20);
+    assert_eq!(kern.kerning(37, 36), 0);
+}
+
+#[test]
+fn format_2_classes_index_the_array() {
+    let kern = Kern::parse(&apple_format_2_table()).unwrap();
+
+    assert_eq!(kern.version(), 0x0001_0000);
+    assert_eq!(kern.subtables()[0].format(), 2);
+    assert!(kern.subtables()[0].is_horizontal());
+
+    assert_eq!(kern.kerning(10, 20), -10);
+    assert_eq!(kern.kerning(10, 21), -20);
+    assert_eq!(kern.kerning(11, 20), 40);
+    assert_eq!(kern.kerning(11, 22), 60);
+    // glyphs outside of the class tables aren't kerned
+    assert_eq!(kern.kerning(9, 20), 0);
+    assert_eq!(kern.kerning(12, 20), 0);
+    assert_eq!(kern.kerning(10, 23), 0);
+}
+
+#[test]
+fn format_2_reads_the_opentype_layout_too() {
+    let body = format_2_body(6);
+    let mut subtable = vec![0, 0];
+    subtable.extend_from_slice(&(6 + body.len() as u16).to_be_bytes());
+    subtable.extend_from_slice(&[0x02, 0x01]);
+    subtable.extend(body);
+
+    let kern = Kern::parse(&kern_table(&[subtable])).unwrap();
+
+    assert_eq!(kern.kerning(10, 22), -30);
+    assert_eq!(kern.kerning(11, 21), 50);
+}
+
+#[test]
+fn subtables_add_up_unless_they_override() {
+    let added = [
+        format_0_subtable(0x01, &[(1, 2, -10)]),
+        format_0_subtable(0x01, &[(1, 2, -5), (3, 4, 7)]),
+        // cross-stream and minimum values don't change the advance
+        format_0_subtable(0x05, &[(1, 2, 100)]),
+        format_0_subtable(0x03, &[(1, 2, 100)]),
+        // neither do vertical subtables
+        format_0_subtable(0x00, &[(1, 2, 100)]),
+    ];
+    let kern = Kern::parse(&kern_table(&added)).unwrap();
+
+    assert_eq!(kern.subtables().len(), 5);
+    assert_eq!(kern.kerning(1, 2), -15);
+    assert_eq!(kern.kerning(3, 4), 7);
+
+    let mut overridden = added.to_vec();
+    overridden.push(format_0_subtable(0x09, &[(1, 2, -1)]));
+    let kern = Kern::parse(&kern_table(&overridden)).unwrap();
+
+    assert!(kern.subtables()[5].is_override());
+    assert_eq!(kern.kerning(1, 2), -1);
+    assert_eq!(kern.kerning(3, 4), 7);
+}
+
+#[test]
+fn unsupported_formats_and_versions() {
+    // a format 3 subtable is kept without a mapping
+    let mut subtable = format_0_subtable(0x01, &[(1, 2, -10)]);
+    subtable[4] = 3;
+    let kern = Kern::parse(&kern_table(&[subtable])).unwrap();
+
+    assert_eq!(kern.subtables()[0].format(), 3);
+    assert!(kern.subtables()[0].mapping().is_none());
+    assert_eq!(kern.kerning(1, 2), 0);
+
+    assert!(matches!(
+        Kern::parse(&[0, 2, 0, 0, 0, 0, 0, 0]),
+        Err(TableEncodingError::UnsupportedKernVersion(0x0002_0000))
+    ));
+}
+
+#[test]
+fn truncated_pairs_are_rejected() {
+    let mut kern = kern_table(&[format_0_subtable(0x01, &[(1, 2, -10), (3, 4, 5)])]);
+    kern.truncate(kern.len() - 1);
+
+    assert!(matches!(
+        Kern::parse(&kern),
+        Err(TableEncodingError::InvalidBufferLength(_, _))
+    ));
+}
+
+#[test]
+fn fonts_kern_through_their_kern_table() {
+    let kern = apple_format_2_table();
+    let font = Font::from_shared(common::minimal_font(&[(b"kern", &kern)])).unwrap();
+
+    assert!(font.kern().is_some());
+    assert_eq!(font.kerning(11, 21), 50);
+
+    let font = Font::from_shared(common::minimal_font(&[])).unwrap();
+    assert!(font.kern().is_none());
+    assert_eq!(font.kerning(11, 21), 0);
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{
+    buffer::VeroBufReader,
+    tables::{ParseOptions, Tables},
+};
+
+/// A font whose head table is truncated to 10 bytes
+fn font_with_broken_head() -> Vec<u8> {
+    common::build_font(&[(b"head", &[0; 10]), (b"name", &common::EMPTY_NAME)])
+}
+
+#[test]
+fn strict_parsing_fails_on_a_broken_table() {
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(font_with_broken_head()));
+
+    assert!(Tables::from_reader(&mut reader).is_err());
+}
+
+#[test]
+fn lenient_parsing_collects_the_error() {
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(font_with_broken_head()));
+    let tables =
+        Tables::from_reader_with_options(&mut reader, ParseOptions { strict: false }).unwrap();
+
+    assert!(tables.head().is_none());
+    assert_eq!(tables.warnings().len(), 1);
+    assert_eq!(tables.headers().tags(), ["head", "name"]);
+}
+
+#[test]
+fn lenient_parsing_reports_missing_tables() {
+    let font = common::build_font(&[(b"name", &common::EMPTY_NAME)]);
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(font));
+    let tables =
+        Tables::from_reader_with_options(&mut reader, ParseOptions { strict: false }).unwrap();
+
+    assert!(tables.head().is_none());
+    assert_eq!(tables.warnings().len(), 1);
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{buffer::VeroBufReader, font::Font};
+
+/// Builds a meta table out of `(tag, data)` maps
+fn meta_table(maps: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+    let mut table = Vec::new();
+    table.extend_from_slice(&1u32.to_be_bytes());
+    table.extend_from_slice(&0u32.to_be_bytes());
+    table.extend_from_slice(&0u32.to_be_bytes());
+    table.extend_from_slice(&(maps.len() as u32).to_be_bytes());
+
+    let mut offset = 16 + maps.len() as u32 * 12;
+
+    for (tag, data) in maps {
+        table.extend_from_slice(*tag);
+        table.extend_from_slice(&offset.to_be_bytes());
+        table.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        offset += data.len() as u32;
+    }
+
+    for (_, data) in maps {
+        table.extend_from_slice(data);
+    }
+
+    table
+}
+
+#[test]
+fn languages_are_split_and_trimmed() {
+    let meta = meta_table(&[(b"dlng", b"Latn, Cyrl"), (b"slng", b"Latn,Grek,Cyrl,")]);
+    let font = common::minimal_font(&[(b"meta", &meta)]);
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+    let meta = font.meta().unwrap();
+
+    assert_eq!(meta.designed_languages(), ["Latn", "Cyrl"]);
+    assert_eq!(meta.supported_languages(), ["Latn", "Grek", "Cyrl"]);
+}
+
+#[test]
+fn missing_maps_yield_no_languages() {
+    let meta = meta_table(&[(b"appl", b"\x01\x02")]);
+    let font = common::minimal_font(&[(b"meta", &meta)]);
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+    let meta = font.meta().unwrap();
+
+    assert!(meta.designed_languages().is_empty());
+    assert_eq!(meta.data(b"appl"), Some(&[1, 2][..]));
+}
@@ -0,0 +1,182 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{
+    VeroTypeError,
+    buffer::VeroBufReader,
+    font::{Font, Metrics},
+    tables::{RequiredTables, TableEncodingError, TablesHeaders, hmtx::Hmtx},
+};
+
+#[test]
+fn glyphs_past_the_long_metrics_share_the_last_advance_width() {
+    let font = common::minimal_font(&[]);
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+    let hmtx = font.hmtx();
+
+    assert_eq!(font.hhea().ascent(), 800);
+    assert_eq!(font.hhea().descent(), -200);
+    assert_eq!(font.hhea().metric_data_format(), 0);
+    assert_eq!(font.hhea().num_of_long_hor_metrics(), 2);
+    assert_eq!(font.hhea().caret_angle_degrees(), 0.0);
+
+    assert_eq!(hmtx.advance_width(0), Some(500));
+    assert_eq!(hmtx.advance_width(3), Some(600));
+    assert_eq!(hmtx.advance_width(4), None);
+    assert_eq!(hmtx.left_side_bearing(1), Some(20));
+    assert_eq!(hmtx.left_side_bearing(3), Some(40));
+    assert_eq!(hmtx.left_side_bearing(4), None);
+}
+
+#[test]
+fn advance_widths_expand_the_shared_advance_width() {
+    let hmtx = common::hmtx_table(&[(500, 10), (600, 20)], &[30, 40]);
+    let hmtx = Hmtx::from_buffer(&hmtx, 2, 4).unwrap();
+
+    let widths = hmtx.advance_widths();
+    assert_eq!(widths, [500, 600, 600, 600]);
+    for (glyph_id, width) in (0..).zip(widths) {
+        assert_eq!(hmtx.advance_width(glyph_id), Some(width));
+    }
+
+    // every glyph has a long metric
+    let hmtx = Hmtx::from_buffer(&common::hmtx_table(&[(500, 10)], &[]), 1, 1).unwrap();
+    assert_eq!(hmtx.advance_widths(), [500]);
+}
+
+#[test]
+fn more_long_metrics_than_glyphs_is_rejected() {
+    let hmtx = common::hmtx_table(&[(500, 0); 5], &[]);
+    let font = common::build_font(&[(b"hmtx", &hmtx)]);
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(font));
+
+    // skip the offset table, the directory follows it
+    reader.seek_to(12).unwrap();
+    let headers = TablesHeaders::from_reader(&mut reader, 1).unwrap();
+    let metadata = headers.get(RequiredTables::Hmtx).unwrap();
+
+    assert!(Hmtx::from_reader(&mut reader, metadata, 5, 5).is_ok());
+    assert!(matches!(
+        Hmtx::from_reader(&mut reader, metadata, 5, 4),
+        Err(VeroTypeError::TableEncodingError(
+            TableEncodingError::InconsistentMetrics(5, 4)
+        ))
+    ));
+}
+
+/// A version 2 OS/2 table with 900/-300/100 typo metrics, 1000/400 Windows
+/// metrics, a 500 x-height and a 700 cap-height
+fn os2_table(fs_selection: u16) -> Vec<u8> {
+    let mut os2 = vec![0; 96];
+    os2[0..2].copy_from_slice(&2u16.to_be_bytes());
+    os2[62..64].copy_from_slice(&fs_selection.to_be_bytes());
+
+    for (at, value) in [
+        (68, 900i16),
+        (70, -300),
+        (72, 100),
+        (74, 1000),
+        (76, 400),
+        (86, 500),
+        (88, 700),
+    ] {
+        os2[at..at + 2].copy_from_slice(&value.to_be_bytes());
+    }
+
+    os2
+}
+
+/// Parses a minimal font with the given hhea ascent/descent and OS/2 table
+fn font_with_metrics(hhea: (i16, i16), os2: Option<&[u8]>) -> Font {
+    let mut hhea_table = common::hhea_table(2);
+    hhea_table[4..6].copy_from_slice(&hhea.0.to_be_bytes());
+    hhea_table[6..8].copy_from_slice(&hhea.1.to_be_bytes());
+
+    let head = common::head_table();
+    let maxp = common::maxp_table(4);
+    let cmap = common::cmap_table(&[]);
+    let hmtx = common::hmtx_table(&[(500, 10), (600, 20)], &[30, 40]);
+
+    let mut tables: Vec<(&[u8; 4], &[u8])> = vec![
+        (b"cmap", &cmap),
+        (b"head", &head),
+        (b"hhea", &hhea_table),
+        (b"hmtx", &hmtx),
+        (b"maxp", &maxp),
+        (b"name", &common::EMPTY_NAME),
+    ];
+    tables.extend(os2.map(|os2| (b"OS/2", os2)));
+
+    let font = common::build_font(&tables);
+    Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap()
+}
+
+/// The metrics of `font_with_metrics`
+fn metrics(hhea: (i16, i16), os2: Option<&[u8]>) -> Metrics {
+    font_with_metrics(hhea, os2).metrics()
+}
+
+#[test]
+fn hhea_metrics_are_used_without_os2() {
+    assert_eq!(
+        metrics((800, -200), None),
+        Metrics {
+            units_per_em: 1000,
+            ascent: 800,
+            descent: -200,
+            line_gap: 0,
+            x_height: None,
+            cap_height: None,
+        }
+    );
+}
+
+#[test]
+fn use_typo_metrics_prefers_the_os2_metrics() {
+    let with_flag = metrics((800, -200), Some(&os2_table(1 << 7)));
+    assert_eq!(
+        (with_flag.ascent, with_flag.descent, with_flag.line_gap),
+        (900, -300, 100)
+    );
+    assert_eq!(with_flag.x_height, Some(500));
+    assert_eq!(with_flag.cap_height, Some(700));
+
+    let without_flag = metrics((800, -200), Some(&os2_table(0)));
+    assert_eq!((without_flag.ascent, without_flag.descent), (800, -200));
+}
+
+#[test]
+fn empty_metrics_fall_back_to_the_next_source() {
+    let typo = metrics((0, 0), Some(&os2_table(0)));
+    assert_eq!((typo.ascent, typo.descent, typo.line_gap), (900, -300, 100));
+
+    let mut os2 = os2_table(0);
+    os2[68..74].fill(0);
+    let win = metrics((0, 0), Some(&os2));
+    assert_eq!((win.ascent, win.descent, win.line_gap), (1000, -400, 0));
+}
+
+#[test]
+fn line_layout_shortcuts_follow_the_metrics_precedence() {
+    let hhea = font_with_metrics((800, -200), None);
+    assert_eq!(
+        (hhea.ascent(), hhea.descent(), hhea.line_height()),
+        (800, -200, 1000)
+    );
+
+    let typo = font_with_metrics((800, -200), Some(&os2_table(1 << 7)));
+    assert_eq!(
+        (typo.ascent(), typo.descent(), typo.line_height()),
+        (900, -300, 1300)
+    );
+}
+
+#[test]
+fn line_height_doesnt_overflow() {
+    let font = font_with_metrics((i16::MAX, i16::MIN), None);
+
+    assert_eq!(font.line_height(), i32::from(u16::MAX));
+}
@@ -0,0 +1,43 @@
+#![cfg(all(feature = "memmap2", target_os = "linux"))]
+
+use std::{fs, path::PathBuf};
+
+use vero_type::font::Font;
+
+/// The path of a sample font, canonicalized as `/proc` lists it
+fn sample_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fonts/DejaVuSansMono-Oblique.ttf")
+        .canonicalize()
+        .unwrap()
+}
+
+/// Counts the open file descriptors and the mappings of `path` in this process
+fn handles_of(path: &PathBuf) -> (usize, usize) {
+    let descriptors = fs::read_dir("/proc/self/fd")
+        .unwrap()
+        .filter_map(|entry| fs::read_link(entry.ok()?.path()).ok())
+        .filter(|target| target == path)
+        .count();
+    let mappings = fs::read_to_string("/proc/self/maps")
+        .unwrap()
+        .lines()
+        .filter(|line| line.ends_with(path.to_str().unwrap()))
+        .count();
+
+    (descriptors, mappings)
+}
+
+#[test]
+fn dropped_fonts_release_their_mapping() {
+    let path = sample_path();
+
+    for _ in 0..1000 {
+        let font = Font::open(&path).unwrap();
+        // the file is closed once mapped, only the mapping is left
+        assert_eq!(handles_of(&path), (0, 1));
+        drop(font);
+    }
+
+    assert_eq!(handles_of(&path), (0, 0));
+}
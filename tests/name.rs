@@ -0,0 +1,205 @@
+use vero_type::tables::{Table, TableEncodingError, name::Name};
+
+/// Builds a format 0 name table out of `(platform, encoding, language, name id, bytes)` records
+fn name_table(records: &[(u16, u16, u16, u16, &[u8])]) -> Vec<u8> {
+    let storage_offset = 6 + records.len() * 12;
+    let mut table = Vec::new();
+    let mut storage = Vec::new();
+
+    table.extend_from_slice(&0u16.to_be_bytes());
+    table.extend_from_slice(&(records.len() as u16).to_be_bytes());
+    table.extend_from_slice(&(storage_offset as u16).to_be_bytes());
+
+    for (platform, encoding, language, name_id, bytes) in records {
+        for field in [*platform, *encoding, *language, *name_id] {
+            table.extend_from_slice(&field.to_be_bytes());
+        }
+
+        table.extend_from_slice(&(bytes.len() as u16).to_be_bytes());
+        table.extend_from_slice(&(storage.len() as u16).to_be_bytes());
+        storage.extend_from_slice(bytes);
+    }
+
+    table.extend_from_slice(&storage);
+    table
+}
+
+fn utf16be(text: &str) -> Vec<u8> {
+    text.encode_utf16().flat_map(u16::to_be_bytes).collect()
+}
+
+#[test]
+fn preferred_records_win_over_physical_order() {
+    let german = utf16be("Schrift");
+    let english = utf16be("Typeface");
+
+    let table = name_table(&[
+        (1, 0, 0, 1, b"Mac Typeface"),
+        (3, 1, 0x0407, 1, &german),
+        (3, 1, 0x0409, 1, &english),
+        (1, 0, 0, 4, b"Mac Full"),
+        (3, 1, 0x0407, 4, &german),
+        (3, 1, 0x0407, 6, &german),
+    ]);
+    let name = Name::from_buffer(&table).unwrap();
+
+    // Windows English beats everything
+    assert_eq!(name.find_preferred(1).as_deref(), Some("Typeface"));
+    // Mac English beats a non English Windows record
+    assert_eq!(name.find_preferred(4).as_deref(), Some("Mac Full"));
+    // Anything is better than nothing
+    assert_eq!(name.find_preferred(6).as_deref(), Some("Schrift"));
+    assert_eq!(name.find_preferred(16), None);
+}
+
+#[test]
+fn truncated_record_arrays_are_errors() {
+    let table = name_table(&[(3, 1, 0x0409, 1, b""), (3, 1, 0x0409, 4, b"")]);
+
+    // the second record ends at byte 30, the table stops in the middle of it
+    assert!(matches!(
+        Name::from_buffer(&table[..24]),
+        Err(TableEncodingError::InvalidBufferLength(24, 18))
+    ));
+
+    // stopping on a record boundary leaves records missing
+    assert!(matches!(
+        Name::from_buffer(&table[..18]),
+        Err(TableEncodingError::RecordCountMismatch {
+            expected: 2,
+            found: 1
+        })
+    ));
+}
+
+#[test]
+fn best_family_name_prefers_the_typographic_family() {
+    let semibold = utf16be("Open Sans SemiBold");
+    let family = utf16be("Open Sans");
+
+    let table = name_table(&[
+        (3, 1, 0x0409, 1, &semibold),
+        (1, 0, 0, 16, b"Mac Open Sans"),
+        (3, 1, 0x0407, 16, &utf16be("Offene Sans")),
+        (3, 1, 0x0409, 16, &family),
+    ]);
+    let name = Name::from_buffer(&table).unwrap();
+
+    assert_eq!(name.best_family_name().as_deref(), Some("Open Sans"));
+
+    // without a Windows English record the Mac English one wins
+    let table = name_table(&[
+        (3, 1, 0x0409, 1, &semibold),
+        (3, 1, 0x0407, 16, &utf16be("Offene Sans")),
+        (1, 0, 0, 16, b"Mac Open Sans"),
+    ]);
+    let name = Name::from_buffer(&table).unwrap();
+
+    assert_eq!(name.best_family_name().as_deref(), Some("Mac Open Sans"));
+}
+
+#[test]
+fn best_family_name_falls_back_to_the_legacy_family() {
+    let table = name_table(&[
+        (3, 1, 0x0407, 1, &utf16be("Schrift")),
+        (1, 0, 0, 1, b"Typeface"),
+    ]);
+    let name = Name::from_buffer(&table).unwrap();
+
+    assert_eq!(name.best_family_name().as_deref(), Some("Typeface"));
+    assert_eq!(
+        Name::from_buffer(&name_table(&[]))
+            .unwrap()
+            .best_family_name(),
+        None
+    );
+}
+
+#[test]
+fn only_unicode_encodings_are_classified_as_unicode() {
+    use vero_type::tables::name::{PlatformId, PlatformSpecificId};
+
+    let unicode = |platform: PlatformId| -> Vec<u16> {
+        (0..16)
+            .filter(|&code| PlatformSpecificId::from_platform(platform, code).is_unicode(platform))
+            .collect()
+    };
+
+    assert_eq!(unicode(PlatformId::Unicode), [0, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(unicode(PlatformId::Microsoft), [1, 10]);
+    assert!(unicode(PlatformId::Macintosh).is_empty());
+    assert!(unicode(PlatformId::Unknown(9)).is_empty());
+}
+
+/// `name_table` with `padding` zero bytes between the records and the storage
+fn padded_name_table(records: &[(u16, u16, u16, u16, &[u8])], padding: usize) -> Vec<u8> {
+    let mut table = name_table(records);
+    let storage = usize::from(u16::from_be_bytes([table[4], table[5]]));
+
+    table.splice(storage..storage, vec![0; padding]);
+    table[4..6].copy_from_slice(&((storage + padding) as u16).to_be_bytes());
+    table
+}
+
+#[test]
+fn the_string_storage_starts_at_the_string_offset() {
+    let english = utf16be("Typeface");
+    let table = padded_name_table(&[(3, 1, 0x0409, 1, &english)], 6);
+
+    for name in [
+        Name::from_buffer(&table).unwrap(),
+        Name::parse_shared(&table[..].into()).unwrap(),
+    ] {
+        assert_eq!(name.string_offset(), 6 + 12 + 6);
+        assert_eq!(name.string_data(), english);
+        assert_eq!(name.find(1).as_deref(), Some("Typeface"));
+    }
+}
+
+#[test]
+fn string_offsets_past_the_table_leave_an_empty_storage() {
+    let mut table = name_table(&[(3, 1, 0x0409, 1, &utf16be("Typeface"))]);
+    table[4..6].copy_from_slice(&0xFFFFu16.to_be_bytes());
+    let name = Name::from_buffer(&table).unwrap();
+
+    assert!(name.string_data().is_empty());
+    assert_eq!(name.find(1), None);
+}
+
+#[test]
+fn sorted_records_order_by_name_id_platform_and_language() {
+    let table = name_table(&[
+        (3, 1, 0x0409, 4, b""),
+        (3, 1, 0x0407, 1, b""),
+        (1, 0, 0, 4, b""),
+        (3, 1, 0x0409, 1, b""),
+        (0, 3, 0, 1, b""),
+    ]);
+    let name = Name::from_buffer(&table).unwrap();
+
+    let order: Vec<_> = name
+        .records_sorted()
+        .iter()
+        .map(|record| {
+            (
+                record.name_id(),
+                record.platform_id().code(),
+                record.language_id(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        order,
+        [
+            (1, 0, 0),
+            (1, 3, 0x0407),
+            (1, 3, 0x0409),
+            (4, 1, 0),
+            (4, 3, 0x0409),
+        ]
+    );
+
+    // the physical order is left as it is
+    assert_eq!(name.records()[0].name_id(), 4);
+    assert!(name.records()[1] < name.records()[0]);
+}
@@ -0,0 +1,107 @@
+//! Throws truncated and random buffers at the name table parser,
+//! every input must produce `Ok` or `Err` and never panic.
+
+use vero_type::tables::name::Name;
+
+/// A tiny xorshift generator, good enough to produce varied garbage
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn bytes(&mut self, len: usize) -> Vec<u8> {
+        (0..len).map(|_| self.next() as u8).collect()
+    }
+}
+
+/// A well formed format 1 name table with two records and one language tag
+fn sample_table() -> Vec<u8> {
+    let family = "Vero".encode_utf16().flat_map(u16::to_be_bytes);
+    let tag = "en-GB".encode_utf16().flat_map(u16::to_be_bytes);
+    let storage = family.chain(tag).collect::<Vec<u8>>();
+
+    let mut table = Vec::new();
+    table.extend_from_slice(&[0, 1, 0, 2, 0, 36]);
+    table.extend_from_slice(&[0, 3, 0, 1, 0x04, 0x09, 0, 1, 0, 8, 0, 0]);
+    table.extend_from_slice(&[0, 3, 0, 1, 0x80, 0x00, 0, 4, 0, 8, 0, 0]);
+    table.extend_from_slice(&[0, 1, 0, 10, 0, 8]);
+    table.extend_from_slice(&storage);
+    table
+}
+
+/// Decodes everything that can be decoded, exercising the bounds checks of the accessors
+fn exercise(name: &Name) {
+    for record in name.records() {
+        let _ = record.raw_bytes(name.string_data());
+        let _ = name.find(record.name_id());
+        let _ = name.language_tag(record.language_id());
+    }
+
+    let _ = name.language_tags();
+}
+
+#[test]
+fn sample_table_parses() {
+    let name = Name::from_buffer(&sample_table()).unwrap();
+
+    assert_eq!(name.records().len(), 2);
+    assert_eq!(name.find(1).as_deref(), Some("Vero"));
+    assert_eq!(
+        name.records()[0].raw_bytes(name.string_data()),
+        Some(&[0, b'V', 0, b'e', 0, b'r', 0, b'o'][..])
+    );
+    assert_eq!(name.language_tag(0x8000).as_deref(), Some("en-GB"));
+}
+
+#[test]
+fn truncated_tables_never_panic() {
+    let table = sample_table();
+
+    for len in 0..=table.len() {
+        if let Ok(name) = Name::from_buffer(&table[..len]) {
+            exercise(&name);
+        }
+    }
+}
+
+#[test]
+fn random_tables_never_panic() {
+    let mut rng = XorShift(0x5EED_F0E7_D00D_CAFE);
+
+    for _ in 0..10_000 {
+        let len = (rng.next() % 256) as usize;
+        let mut buf = rng.bytes(len);
+
+        // keep the format plausible most of the time so the deeper paths are reached
+        if buf.len() >= 2 && !rng.next().is_multiple_of(4) {
+            buf[0] = 0;
+            buf[1] %= 2;
+        }
+
+        if let Ok(name) = Name::from_buffer(&buf) {
+            exercise(&name);
+        }
+    }
+}
+
+#[test]
+fn mutated_tables_never_panic() {
+    let mut rng = XorShift(0xBAD_F0E7);
+    let table = sample_table();
+
+    for _ in 0..10_000 {
+        let mut buf = table.clone();
+        let index = (rng.next() as usize) % buf.len();
+        buf[index] = rng.next() as u8;
+        buf.truncate((rng.next() as usize) % (table.len() + 1));
+
+        if let Ok(name) = Name::from_buffer(&buf) {
+            exercise(&name);
+        }
+    }
+}
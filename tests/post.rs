@@ -0,0 +1,114 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{buffer::VeroBufReader, font::Font};
+
+/// A version 2.0 post table naming the glyphs through `indices` and the custom `names`
+fn post_table(indices: &[u16], names: &[&str]) -> Vec<u8> {
+    let mut post = 0x0002_0000u32.to_be_bytes().to_vec();
+    post.resize(32, 0);
+    post.extend_from_slice(&(indices.len() as u16).to_be_bytes());
+
+    for index in indices {
+        post.extend_from_slice(&index.to_be_bytes());
+    }
+
+    for name in names {
+        post.push(name.len() as u8);
+        post.extend_from_slice(name.as_bytes());
+    }
+
+    post
+}
+
+fn font_with_post(post: &[u8]) -> Font {
+    let font = common::minimal_font(&[(b"post", post)]);
+    Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap()
+}
+
+#[test]
+fn glyphs_are_found_by_standard_and_custom_names() {
+    let font = font_with_post(&post_table(&[0, 36, 258, 259], &["A.alt", "uni0042"]));
+    let post = font.post().unwrap();
+
+    assert_eq!(post.glyph_name(1), Some("A"));
+    assert_eq!(post.glyph_name(2), Some("A.alt"));
+    assert_eq!(font.glyph_id_for_name(".notdef"), Some(0));
+    assert_eq!(font.glyph_id_for_name("uni0042"), Some(3));
+    assert_eq!(font.glyph_id_for_name("B"), None);
+}
+
+#[test]
+fn version_3_has_no_names() {
+    let mut post = 0x0003_0000u32.to_be_bytes().to_vec();
+    post.resize(32, 0);
+    let font = font_with_post(&post);
+
+    assert!(font.post().unwrap().glyph_names().is_empty());
+    assert_eq!(font.glyph_id_for_name(".notdef"), None);
+}
+
+#[test]
+fn version_3_skips_whatever_follows_the_header() {
+    // a version 2.0 name pool tacked onto a version 3.0 header
+    let mut post = post_table(&[0, 36, 258], &["A.alt"]);
+    post[0..4].copy_from_slice(&0x0003_0000u32.to_be_bytes());
+    post[4..8].copy_from_slice(&(-0x000C_0000i32).to_be_bytes());
+    post[8..10].copy_from_slice(&(-75i16).to_be_bytes());
+    post[10..12].copy_from_slice(&50i16.to_be_bytes());
+    post[12..16].copy_from_slice(&1u32.to_be_bytes());
+
+    let font = font_with_post(&post);
+    let post = font.post().unwrap();
+
+    assert_eq!(post.version(), 0x0003_0000);
+    assert_eq!(post.italic_angle_degrees(), -12.0);
+    assert_eq!(post.underline_position(), -75);
+    assert_eq!(post.underline_thickness(), 50);
+    assert!(post.is_fixed_pitch());
+    assert!((0..=u16::MAX).all(|glyph_id| post.glyph_name(glyph_id).is_none()));
+    assert_eq!(post.glyph_id("A.alt"), None);
+}
+
+#[test]
+fn italic_angle_is_converted_to_degrees() {
+    let mut post = 0x0003_0000u32.to_be_bytes().to_vec();
+    // -11.25 degrees as 16.16 fixed point
+    post.extend_from_slice(&(-0x000B_4000i32).to_be_bytes());
+    post.resize(32, 0);
+    let font = font_with_post(&post);
+    let post = font.post().unwrap();
+
+    assert_eq!(post.italic_angle(), -0x000B_4000);
+    assert_eq!(post.italic_angle_degrees(), -11.25);
+}
+
+#[test]
+fn upright_fonts_have_no_italic_angle() {
+    let mut post = 0x0003_0000u32.to_be_bytes().to_vec();
+    post.resize(32, 0);
+
+    assert_eq!(
+        font_with_post(&post).post().unwrap().italic_angle_degrees(),
+        0.0
+    );
+}
+
+#[test]
+fn glyph_labels_fall_back_to_the_glyph_id() {
+    let font = font_with_post(&post_table(&[0, 36, 258, 259], &["A.alt", ""]));
+
+    assert_eq!(font.glyph_label(0), ".notdef");
+    assert_eq!(font.glyph_label(2), "A.alt");
+    // an empty name and a glyph past the names
+    assert_eq!(font.glyph_label(3), "glyph3");
+    assert_eq!(font.glyph_label(42), "glyph42");
+
+    // without a post table every glyph is labeled by it's id
+    let font = common::minimal_font(&[]);
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+    assert_eq!(font.glyph_label(1), "glyph1");
+}
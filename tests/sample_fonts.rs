@@ -0,0 +1,256 @@
+//! Parses the real fonts under `tests/fonts` (see `tests/fonts/LICENSE`) and checks
+//! the values against what the font files actually contain.
+
+#![cfg(feature = "std")]
+
+use std::{fs::File, path::PathBuf};
+
+use vero_type::{
+    VeroTypeError,
+    buffer::{VeroBufReader, VeroBufReaderError},
+    font::Font,
+};
+
+/// The values a sample font is expected to parse into
+struct Expected {
+    file: &'static str,
+    family_name: &'static str,
+    full_name: &'static str,
+    postscript_name: &'static str,
+    units_per_em: u16,
+    num_glyphs: u16,
+    glyph_a: u16,
+    glyph_e_acute: u16,
+    bbox: (i16, i16, i16, i16),
+    ascent: i16,
+    descent: i16,
+}
+
+const SAMPLES: [Expected; 2] = [
+    Expected {
+        file: "DejaVuSansMono-Oblique.ttf",
+        family_name: "DejaVu Sans Mono",
+        full_name: "DejaVu Sans Mono Oblique",
+        postscript_name: "DejaVuSansMono-Oblique",
+        units_per_em: 2048,
+        num_glyphs: 2710,
+        glyph_a: 36,
+        glyph_e_acute: 171,
+        bbox: (-863, -767, 1528, 2043),
+        ascent: 1901,
+        descent: -483,
+    },
+    Expected {
+        file: "DejaVuSerifCondensed-Bold.ttf",
+        family_name: "DejaVu Serif Condensed",
+        full_name: "DejaVu Serif Condensed Bold",
+        postscript_name: "DejaVuSerifCondensed-Bold",
+        units_per_em: 2048,
+        num_glyphs: 3506,
+        glyph_a: 36,
+        glyph_e_acute: 171,
+        bbox: (-1541, -797, 3418, 2345),
+        ascent: 1923,
+        descent: -483,
+    },
+];
+
+fn sample_path(file: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fonts")
+        .join(file)
+}
+
+fn check(font: &Font, expected: &Expected) {
+    let file = expected.file;
+
+    assert_eq!(
+        font.family_name().as_deref(),
+        Some(expected.family_name),
+        "{file}"
+    );
+    assert_eq!(
+        font.full_name().as_deref(),
+        Some(expected.full_name),
+        "{file}"
+    );
+    assert_eq!(
+        font.postscript_name().as_deref(),
+        Some(expected.postscript_name),
+        "{file}"
+    );
+    assert_eq!(font.units_per_em(), expected.units_per_em, "{file}");
+    assert_eq!(font.maxp().num_glyphs(), expected.num_glyphs, "{file}");
+    assert_eq!(font.maxp().max_component_depth(), Some(4), "{file}");
+    assert_eq!(
+        font.cmap().glyph_index('A'),
+        Some(expected.glyph_a),
+        "{file}"
+    );
+    assert_eq!(
+        font.cmap().glyph_index('é'),
+        Some(expected.glyph_e_acute),
+        "{file}"
+    );
+
+    let head = font.head();
+    assert_eq!(
+        (head.x_min(), head.y_min(), head.x_max(), head.y_max()),
+        expected.bbox,
+        "{file}"
+    );
+
+    let metrics = font.metrics();
+    assert_eq!(metrics.ascent, expected.ascent, "{file}");
+    assert_eq!(metrics.descent, expected.descent, "{file}");
+
+    assert!(font.tables().warnings().is_empty(), "{file}");
+}
+
+#[test]
+fn sample_fonts_parse_from_a_reader() {
+    for expected in &SAMPLES {
+        let file = File::open(sample_path(expected.file)).unwrap();
+        let mut reader = VeroBufReader::from_buffer(file);
+        let font = Font::from_reader(&mut reader).unwrap();
+
+        check(&font, expected);
+    }
+}
+
+#[test]
+fn sample_fonts_parse_from_a_shared_buffer() {
+    for expected in &SAMPLES {
+        let data = std::fs::read(sample_path(expected.file)).unwrap();
+        let font = Font::from_shared(data).unwrap();
+
+        check(&font, expected);
+    }
+}
+
+#[test]
+fn sample_fonts_parse_from_bytes() {
+    for expected in &SAMPLES {
+        let data = std::fs::read(sample_path(expected.file)).unwrap();
+        let font = Font::from_bytes(&data).unwrap();
+
+        check(&font, expected);
+    }
+}
+
+#[test]
+fn sample_fonts_open_from_a_path() {
+    // mapped with the `memmap2` feature, read into memory without it
+    for expected in &SAMPLES {
+        let font = Font::open(sample_path(expected.file)).unwrap();
+
+        check(&font, expected);
+    }
+}
+
+#[test]
+fn opening_a_missing_font_is_an_error() {
+    let missing = Font::open(sample_path("Missing.ttf"));
+
+    assert!(matches!(
+        missing,
+        Err(VeroTypeError::VeroBufReaderError(
+            VeroBufReaderError::ReadError(_)
+        ))
+    ));
+}
+
+#[test]
+fn sample_fonts_outline_the_letter_a() {
+    for expected in &SAMPLES {
+        let data = std::fs::read(sample_path(expected.file)).unwrap();
+        let font = Font::from_shared(data).unwrap();
+
+        let glyph = font.glyph(expected.glyph_a).unwrap().unwrap();
+
+        // An 'A' is the outer outline plus the counter
+        assert_eq!(glyph.number_of_contours(), 2, "{}", expected.file);
+        assert_eq!(glyph.y_min(), 0, "{}", expected.file);
+        assert_eq!(glyph.y_max(), 1493, "{}", expected.file);
+    }
+}
+
+#[test]
+fn oblique_caret_leans_right() {
+    let data = std::fs::read(sample_path("DejaVuSansMono-Oblique.ttf")).unwrap();
+    let font = Font::from_shared(data).unwrap();
+    let hhea = font.hhea();
+
+    assert_eq!((hhea.caret_slope_rise(), hhea.caret_slope_run()), (100, 19));
+    assert!((hhea.caret_angle_degrees() + 10.758).abs() < 0.001);
+    assert_eq!(font.post().unwrap().italic_angle_degrees(), -11.0);
+}
+
+#[test]
+fn accented_letters_are_composites() {
+    let data = std::fs::read(sample_path("DejaVuSansMono-Oblique.ttf")).unwrap();
+    let font = Font::from_shared(data).unwrap();
+
+    // 'é' is an 'e' with an acute accent on top
+    let e_acute = font.cmap().glyph_index('é').unwrap();
+    let e = font.cmap().glyph_index('e').unwrap();
+
+    assert_eq!(font.component_glyphs(e_acute).unwrap(), [e, 118]);
+    assert!(font.component_glyphs(e).unwrap().is_empty());
+}
+
+#[test]
+fn closure_of_a_word_pulls_in_the_accents() {
+    let data = std::fs::read(sample_path("DejaVuSerifCondensed-Bold.ttf")).unwrap();
+    let font = Font::from_shared(data).unwrap();
+
+    let seed = font.cmap().glyph_indices("café");
+    let closure = font.glyph_closure(&seed).unwrap();
+
+    assert!(seed.iter().all(|glyph_id| closure.contains(glyph_id)));
+    // the components of 'é', the 'e' isn't in the seed
+    for component in font.component_glyphs(seed[3]).unwrap() {
+        assert!(closure.contains(&component));
+    }
+    assert_eq!(closure.len(), seed.len() + 2);
+}
+
+#[test]
+fn letters_have_ink_and_spaces_dont() {
+    let data = std::fs::read(sample_path("DejaVuSansMono-Oblique.ttf")).unwrap();
+    let font = Font::from_shared(data).unwrap();
+
+    let a = font
+        .glyph(font.cmap().glyph_index('A').unwrap())
+        .unwrap()
+        .unwrap();
+    let space = font
+        .glyph(font.cmap().glyph_index(' ').unwrap())
+        .unwrap()
+        .unwrap();
+
+    let bitmap = a.rasterize(32, 32);
+    assert!(bitmap.contains(&255));
+    // the apex of an 'A' leaves the top corners empty
+    assert_eq!((bitmap[0], bitmap[31]), (0, 0));
+
+    assert!(
+        space
+            .rasterize(32, 32)
+            .iter()
+            .all(|&coverage| coverage == 0)
+    );
+}
+
+#[test]
+fn sample_fonts_parse_with_any_read_buffer_size() {
+    for capacity in [1, 7, 1024 * 1024] {
+        for expected in &SAMPLES {
+            let file = File::open(sample_path(expected.file)).unwrap();
+            let mut reader = VeroBufReader::with_capacity(file, capacity);
+            let font = Font::from_reader(&mut reader).unwrap();
+
+            check(&font, expected);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::{io::Cursor, sync::Arc};
+
+use vero_type::{
+    VeroTypeError,
+    buffer::VeroBufReader,
+    font::Font,
+    shared::SharedBytes,
+    tables::{TableEncodingError, Tables, name::Name},
+};
+
+/// The common font with a name record and a single empty glyph outline
+fn font_data() -> Arc<[u8]> {
+    let mut name = vec![0, 0, 0, 1, 0, 18];
+    name.extend_from_slice(&[0, 3, 0, 1, 0x04, 0x09, 0, 1, 0, 8, 0, 0]);
+    name.extend_from_slice(&[0, b'V', 0, b'e', 0, b'r', 0, b'o']);
+
+    let glyf = [0u8; 12];
+    let loca = [0u32, 12, 12, 12, 12]
+        .iter()
+        .flat_map(|offset| offset.to_be_bytes())
+        .collect::<Vec<u8>>();
+
+    let font = common::build_font(&[
+        (b"cmap", &common::cmap_table(&[(0x41, 0x43, 1)])),
+        (b"glyf", &glyf),
+        (b"head", &common::head_table()),
+        (b"hhea", &common::hhea_table(2)),
+        (
+            b"hmtx",
+            &common::hmtx_table(&[(500, 10), (600, 20)], &[30, 40]),
+        ),
+        (b"loca", &loca),
+        (b"maxp", &common::maxp_table(4)),
+        (b"name", &name),
+    ]);
+
+    font.into()
+}
+
+/// Checks if `bytes` points into `buffer` rather than into a copy of it
+fn is_within(bytes: &[u8], buffer: &[u8]) -> bool {
+    buffer.as_ptr_range().contains(&bytes.as_ptr())
+}
+
+#[test]
+fn shared_fonts_match_the_reader_path() {
+    let data = font_data();
+    let shared = Font::from_shared(Arc::clone(&data)).unwrap();
+    let read = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(&data[..]))).unwrap();
+
+    assert_eq!(shared.summary(), read.summary());
+    assert_eq!(shared.family_name(), Some("Vero".to_string()));
+    assert_eq!(shared.glyph(0).unwrap().unwrap().number_of_contours(), 0);
+}
+
+#[test]
+fn tables_hold_views_into_the_buffer() {
+    let data = font_data();
+    let font = Font::from_shared(Arc::clone(&data)).unwrap();
+
+    assert!(is_within(font.name().string_data(), &data));
+    assert!(is_within(font.glyf().unwrap().data(), &data));
+
+    let tables = Tables::from_shared(&SharedBytes::new(Arc::clone(&data))).unwrap();
+    let name = tables
+        .load_shared::<Name>(&SharedBytes::new(Arc::clone(&data)))
+        .unwrap();
+    assert!(is_within(name.string_data(), &data));
+}
+
+#[test]
+fn tables_past_the_end_of_the_buffer_are_errors() {
+    let data = font_data();
+    let truncated: Arc<[u8]> = data[..data.len() - 4].into();
+
+    assert!(matches!(
+        Font::from_shared(truncated),
+        Err(VeroTypeError::TableEncodingError(
+            TableEncodingError::InvalidBufferLength(..)
+        ))
+    ));
+    assert!(Font::from_shared(&data[..20]).is_err());
+}
+
+/// Compiles only if `T` can be shared between threads
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn fonts_can_be_shared_between_threads() {
+    assert_send_sync::<Font>();
+    assert_send_sync::<Tables>();
+    assert_send_sync::<vero_type::tables::post::Post>();
+
+    let font = Arc::new(Font::from_shared(font_data()).unwrap());
+    let shared = Arc::clone(&font);
+    let glyph = std::thread::spawn(move || shared.glyph_for_char('B'))
+        .join()
+        .unwrap();
+
+    assert_eq!(glyph, font.glyph_for_char('B'));
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use vero_type::font::{Font, FontStyle};
+
+/// An OS/2 table of the given version, weight class and fsSelection
+fn os2_table(version: u16, weight_class: u16, fs_selection: u16) -> Vec<u8> {
+    let mut os2 = vec![0; 96];
+    os2[0..2].copy_from_slice(&version.to_be_bytes());
+    os2[4..6].copy_from_slice(&weight_class.to_be_bytes());
+    os2[62..64].copy_from_slice(&fs_selection.to_be_bytes());
+    os2
+}
+
+/// The style of a minimal font with the given head mac style and OS/2 table
+fn style(mac_style: u16, os2: Option<&[u8]>) -> FontStyle {
+    let mut head = common::head_table();
+    head[44..46].copy_from_slice(&mac_style.to_be_bytes());
+
+    let maxp = common::maxp_table(4);
+    let hhea = common::hhea_table(2);
+    let cmap = common::cmap_table(&[]);
+    let hmtx = common::hmtx_table(&[(500, 10), (600, 20)], &[30, 40]);
+
+    let mut tables: Vec<(&[u8; 4], &[u8])> = vec![
+        (b"cmap", &cmap),
+        (b"head", &head),
+        (b"hhea", &hhea),
+        (b"hmtx", &hmtx),
+        (b"maxp", &maxp),
+        (b"name", &common::EMPTY_NAME),
+    ];
+    tables.extend(os2.map(|os2| (b"OS/2", os2)));
+
+    Font::from_shared(common::build_font(&tables))
+        .unwrap()
+        .style()
+}
+
+fn font_style(weight: u16, italic: bool, oblique: bool) -> FontStyle {
+    FontStyle {
+        weight,
+        italic,
+        oblique,
+    }
+}
+
+#[test]
+fn mac_style_is_used_without_os2() {
+    assert_eq!(style(0, None), font_style(400, false, false));
+    assert_eq!(style(0x0001, None), font_style(700, false, false));
+    assert_eq!(style(0x0002, None), font_style(400, true, false));
+    assert_eq!(style(0x0003, None), font_style(700, true, false));
+}
+
+#[test]
+fn os2_wins_over_mac_style() {
+    // a bold italic mac style, but a regular OS/2 table
+    assert_eq!(
+        style(0x0003, Some(&os2_table(4, 400, 0x0040))),
+        font_style(400, false, false)
+    );
+    // and the other way around
+    assert_eq!(
+        style(0, Some(&os2_table(4, 0, 0x0021))),
+        font_style(700, true, false)
+    );
+}
+
+#[test]
+fn weight_class_wins_over_the_bold_bits() {
+    assert_eq!(
+        style(0x0001, Some(&os2_table(4, 600, 0x0020))),
+        font_style(600, false, false)
+    );
+    assert_eq!(
+        style(0, Some(&os2_table(4, 300, 0))),
+        font_style(300, false, false)
+    );
+    // out of range weight classes are ignored
+    assert_eq!(
+        style(0, Some(&os2_table(4, 1001, 0x0020))),
+        font_style(700, false, false)
+    );
+}
+
+#[test]
+fn regular_clears_bold_and_italic() {
+    assert_eq!(
+        style(0, Some(&os2_table(4, 0, 0x0061))),
+        font_style(400, false, false)
+    );
+}
+
+#[test]
+fn oblique_needs_os2_version_4() {
+    assert_eq!(
+        style(0, Some(&os2_table(4, 400, 0x0201))),
+        font_style(400, true, true)
+    );
+    assert_eq!(
+        style(0, Some(&os2_table(3, 400, 0x0201))),
+        font_style(400, true, false)
+    );
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use miniz_oxide::deflate::compress_to_vec;
+use vero_type::{
+    buffer::VeroBufReader,
+    font::Font,
+    tables::{
+        Table, TableEncodingError,
+        svg::{MAX_INFLATED_DOCUMENT_SIZE, Svg},
+    },
+};
+
+const SMILE: &str = r#"<svg xmlns="http://www.w3.org/2000/svg"><g id="glyph1"/></svg>"#;
+const FROWN: &str =
+    r#"<svg xmlns="http://www.w3.org/2000/svg"><g id="glyph3"/><g id="glyph4"/></svg>"#;
+
+/// Builds an SVG table out of `(start, end, document)` records
+fn svg_table(records: &[(u16, u16, &[u8])]) -> Vec<u8> {
+    let mut table = Vec::new();
+    table.extend_from_slice(&0u16.to_be_bytes()); // version
+    table.extend_from_slice(&10u32.to_be_bytes()); // document list offset
+    table.extend_from_slice(&0u32.to_be_bytes()); // reserved
+
+    table.extend_from_slice(&(records.len() as u16).to_be_bytes());
+    let mut offset = 2 + records.len() as u32 * 12;
+
+    for (start, end, document) in records {
+        table.extend_from_slice(&start.to_be_bytes());
+        table.extend_from_slice(&end.to_be_bytes());
+        table.extend_from_slice(&offset.to_be_bytes());
+        table.extend_from_slice(&(document.len() as u32).to_be_bytes());
+        offset += document.len() as u32;
+    }
+
+    for (_, _, document) in records {
+        table.extend_from_slice(document);
+    }
+
+    table
+}
+
+/// Wraps a document in a gzip stream carrying a file name, the crc isn't checked
+fn gzip(document: &str) -> Vec<u8> {
+    let mut stream = vec![0x1F, 0x8B, 8, 0x08, 0, 0, 0, 0, 0, 0xFF];
+    stream.extend_from_slice(b"glyph.svg\0");
+    stream.extend_from_slice(&compress_to_vec(document.as_bytes(), 6));
+    stream.extend_from_slice(&[0; 4]); // crc32
+    stream.extend_from_slice(&(document.len() as u32).to_le_bytes());
+    stream
+}
+
+#[test]
+fn documents_are_looked_up_by_glyph_range() {
+    let svg = svg_table(&[(1, 1, SMILE.as_bytes()), (3, 4, FROWN.as_bytes())]);
+    let font = common::minimal_font(&[(b"SVG ", &svg)]);
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+    let svg = font.svg().unwrap();
+
+    assert_eq!(svg.version(), 0);
+    assert_eq!(svg.records().len(), 2);
+    assert_eq!(svg.svg_document(0).unwrap().as_deref(), None);
+    assert_eq!(svg.svg_document(1).unwrap().as_deref(), Some(SMILE));
+    assert_eq!(svg.svg_document(2).unwrap().as_deref(), None);
+    assert_eq!(svg.svg_document(3).unwrap().as_deref(), Some(FROWN));
+    assert_eq!(svg.svg_document(4).unwrap().as_deref(), Some(FROWN));
+    assert_eq!(svg.svg_document(5).unwrap().as_deref(), None);
+}
+
+#[test]
+fn gzip_compressed_documents_are_inflated() {
+    let compressed = gzip(FROWN);
+    let svg = Svg::parse(&svg_table(&[(3, 4, &compressed)])).unwrap();
+
+    assert_eq!(svg.svg_document(4).unwrap().as_deref(), Some(FROWN));
+}
+
+#[test]
+fn fonts_without_the_table_have_no_svg() {
+    let font = common::minimal_font(&[]);
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+
+    assert!(font.svg().is_none());
+}
+
+#[test]
+fn malformed_documents_fail_only_their_own_lookup() {
+    let mut corrupt = gzip(SMILE);
+    corrupt.truncate(24);
+
+    for document in [&b"\xFF\xFE"[..], &corrupt, &[0x1F, 0x8B, 8]] {
+        let svg = Svg::parse(&svg_table(&[(1, 1, document), (3, 4, FROWN.as_bytes())])).unwrap();
+
+        assert!(matches!(
+            svg.svg_document(1),
+            Err(TableEncodingError::MalformedSvgDocument(_))
+        ));
+        assert_eq!(svg.svg_document(3).unwrap().as_deref(), Some(FROWN));
+    }
+
+    // a font with a bad document still loads
+    let table = svg_table(&[(1, 1, b"\xFF\xFE")]);
+    let font = Font::from_shared(common::minimal_font(&[(b"SVG ", &table)])).unwrap();
+    assert!(font.svg().unwrap().svg_document(1).is_err());
+
+    let mut truncated = svg_table(&[(1, 1, SMILE.as_bytes())]);
+    truncated.pop();
+    let svg = Svg::parse(&truncated).unwrap();
+    assert!(matches!(
+        svg.svg_document(1),
+        Err(TableEncodingError::InvalidBufferLength(..))
+    ));
+
+    // the records themselves being truncated fails the table
+    assert!(matches!(
+        Svg::parse(&truncated[..20]),
+        Err(TableEncodingError::InvalidBufferLength(..))
+    ));
+}
+
+#[test]
+fn documents_inflating_past_the_limit_are_rejected() {
+    let bomb = "\n".repeat(MAX_INFLATED_DOCUMENT_SIZE + 1);
+    let compressed = gzip(&bomb);
+    let svg = Svg::parse(&svg_table(&[(1, 1, &compressed), (2, 2, &gzip(SMILE))])).unwrap();
+
+    assert!(compressed.len() < 64 * 1024);
+    assert!(matches!(
+        svg.svg_document(1),
+        Err(TableEncodingError::MalformedSvgDocument(
+            "the document inflates past the size limit"
+        ))
+    ));
+    assert_eq!(svg.svg_document(2).unwrap().as_deref(), Some(SMILE));
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use vero_type::{
+    VeroTypeError,
+    buffer::VeroBufReader,
+    tables::{TableEncodingError, Tables},
+};
+
+fn font() -> Vec<u8> {
+    common::build_font(&[
+        (b"CFF ", b"compact"),
+        (b"name", &common::EMPTY_NAME),
+        (b"zzzz", b"neighbour"),
+    ])
+}
+
+fn directory(font: &[u8]) -> Tables {
+    Tables::directory_from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap()
+}
+
+#[test]
+fn reads_stop_at_the_end_of_the_table() {
+    let font = font();
+    let tables = directory(&font);
+
+    let mut table = tables.table_reader(b"CFF ", Cursor::new(&font)).unwrap();
+    let mut bytes = Vec::new();
+    table.read_to_end(&mut bytes).unwrap();
+
+    assert_eq!(table.len(), 7);
+    assert_eq!(bytes, b"compact");
+}
+
+#[test]
+fn seeking_is_relative_to_the_table() {
+    let font = font();
+    let tables = directory(&font);
+    let mut table = tables.table_reader(b"zzzz", Cursor::new(&font)).unwrap();
+
+    assert_eq!(table.seek(SeekFrom::Start(3)).unwrap(), 3);
+    let mut bytes = [0; 3];
+    table.read_exact(&mut bytes).unwrap();
+    assert_eq!(&bytes, b"ghb");
+
+    assert_eq!(table.seek(SeekFrom::End(-4)).unwrap(), 5);
+    assert_eq!(table.seek(SeekFrom::Current(1)).unwrap(), 6);
+    assert!(table.seek(SeekFrom::Current(-7)).is_err());
+
+    // Past the end behaves like a `Cursor`, the read is simply empty
+    table.seek(SeekFrom::Start(100)).unwrap();
+    assert_eq!(table.read(&mut bytes).unwrap(), 0);
+}
+
+#[test]
+fn a_shared_reader_is_repositioned_on_every_read() {
+    let font = font();
+    let tables = directory(&font);
+    let mut cursor = Cursor::new(&font);
+
+    let mut bytes = [0; 4];
+    tables
+        .table_reader(b"CFF ", &mut cursor)
+        .unwrap()
+        .read_exact(&mut bytes)
+        .unwrap();
+    assert_eq!(&bytes, b"comp");
+
+    cursor.set_position(0);
+    tables
+        .table_reader(b"zzzz", &mut cursor)
+        .unwrap()
+        .read_exact(&mut bytes)
+        .unwrap();
+    assert_eq!(&bytes, b"neig");
+}
+
+#[test]
+fn a_missing_table_is_an_error() {
+    let font = font();
+    let tables = directory(&font);
+
+    assert!(matches!(
+        tables.table_reader(b"SVG ", Cursor::new(&font)),
+        Err(VeroTypeError::TableEncodingError(
+            TableEncodingError::MissingTable(tag)
+        )) if tag == "SVG "
+    ));
+}
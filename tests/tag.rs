@@ -0,0 +1,44 @@
+use vero_type::tag::{Tag, TagError};
+
+#[test]
+fn short_tags_are_padded_with_spaces() {
+    assert_eq!("cvt".parse::<Tag>(), Ok(Tag::new(*b"cvt ")));
+    assert_eq!("a".parse::<Tag>(), Ok(Tag::new(*b"a   ")));
+    assert_eq!("OS/2".parse::<Tag>(), Ok(Tag::new(*b"OS/2")));
+}
+
+#[test]
+fn invalid_strings_are_rejected() {
+    assert_eq!("".parse::<Tag>(), Err(TagError::InvalidLength(0)));
+    assert_eq!("glyph".parse::<Tag>(), Err(TagError::InvalidLength(5)));
+    assert_eq!("é".parse::<Tag>(), Err(TagError::NotAscii));
+    assert_eq!("a\tb".parse::<Tag>(), Err(TagError::NotAscii));
+}
+
+#[test]
+fn tags_compare_with_strings() {
+    let tag = Tag::from(*b"cvt ");
+
+    assert_eq!(tag, "cvt ");
+    assert_eq!(tag, "cvt");
+    assert_eq!("cvt", tag);
+    assert_ne!(tag, "CVT");
+    assert_ne!(tag, "too long");
+}
+
+#[test]
+fn unprintable_bytes_are_escaped() {
+    let tag = Tag::new([0xFF, b'a', 0x00, b' ']);
+
+    assert_eq!(tag.to_string(), "\\xFFa\\x00 ");
+    assert_eq!(format!("{tag:?}"), "Tag(\"\\xFFa\\x00 \")");
+    assert_eq!(<[u8; 4]>::from(tag), [0xFF, b'a', 0x00, b' ']);
+}
+
+#[test]
+fn tags_sort_by_their_bytes() {
+    let mut tags = ["name", "OS/2", "cmap", "CFF "].map(|tag| tag.parse::<Tag>().unwrap());
+    tags.sort();
+
+    assert_eq!(tags, ["CFF ", "OS/2", "cmap", "name"]);
+}
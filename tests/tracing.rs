@@ -0,0 +1,182 @@
+#![cfg(all(feature = "tracing", feature = "std"))]
+
+mod common;
+
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
+use common::{
+    EMPTY_NAME, build_font, cmap_table, head_table, hhea_table, hmtx_table, maxp_table,
+    minimal_font,
+};
+use tracing::{
+    Event, Id, Metadata, Subscriber,
+    field::{Field, Visit},
+    span::{Attributes, Record},
+};
+use vero_type::font::Font;
+
+/// The fields of a span or an event as `(name, value)` pairs
+#[derive(Debug, Default, Clone)]
+struct Fields(Vec<(String, String)>);
+
+impl Fields {
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .push((field.name().to_string(), format!("{value:?}")));
+    }
+}
+
+#[derive(Debug, Default)]
+struct Recorded {
+    /// The name and fields of every span, the span id is the index plus one
+    spans: Vec<(&'static str, Fields)>,
+
+    /// The indices of the spans currently entered
+    entered: Vec<usize>,
+
+    /// The fields of every event with the index of the span it happened in
+    events: Vec<(Fields, Option<usize>)>,
+}
+
+/// A subscriber which records every span and event
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Recorded>>);
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields::default();
+        span.record(&mut fields);
+
+        let mut recorded = self.0.lock().unwrap();
+        recorded.spans.push((span.metadata().name(), fields));
+        Id::from_u64(recorded.spans.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+
+        let mut recorded = self.0.lock().unwrap();
+        let span = recorded.entered.last().copied();
+        recorded.events.push((fields, span));
+    }
+
+    fn enter(&self, span: &Id) {
+        self.0
+            .lock()
+            .unwrap()
+            .entered
+            .push(span.into_u64() as usize - 1);
+    }
+
+    fn exit(&self, _: &Id) {
+        self.0.lock().unwrap().entered.pop();
+    }
+}
+
+#[test]
+fn every_table_parse_runs_in_a_span() {
+    let recorder = Recorder::default();
+    let font = minimal_font(&[]);
+
+    tracing::subscriber::with_default(recorder.clone(), || Font::from_shared(font).unwrap());
+
+    let recorded = recorder.0.lock().unwrap();
+    let mut tags: Vec<_> = recorded
+        .spans
+        .iter()
+        .map(|(name, fields)| {
+            assert_eq!(*name, "table");
+            fields.get("tag").unwrap()
+        })
+        .collect();
+    tags.sort();
+
+    assert_eq!(tags, ["cmap", "head", "hhea", "hmtx", "maxp", "name"]);
+    assert!(recorded.events.is_empty());
+}
+
+#[test]
+fn failed_parses_are_logged_within_the_table_span() {
+    let recorder = Recorder::default();
+    let head = head_table();
+    let maxp = maxp_table(4);
+    let cmap = cmap_table(&[(0x41, 0x43, 1)]);
+    let hmtx = hmtx_table(&[(500, 10), (600, 20)], &[30, 40]);
+    // an hhea table cut off before it's number of metrics
+    let hhea = &hhea_table(2)[..20];
+
+    let font = build_font(&[
+        (b"cmap", &cmap),
+        (b"head", &head),
+        (b"hhea", hhea),
+        (b"hmtx", &hmtx),
+        (b"maxp", &maxp),
+        (b"name", &EMPTY_NAME),
+    ]);
+
+    tracing::subscriber::with_default(recorder.clone(), || {
+        assert!(Font::from_shared(font).is_err());
+    });
+
+    let recorded = recorder.0.lock().unwrap();
+    let [(fields, Some(span))] = recorded.events.as_slice() else {
+        panic!("expected a single event within a span, got {recorded:?}");
+    };
+    let (_, span) = &recorded.spans[*span];
+
+    assert!(fields.get("error").unwrap().contains("36"));
+    assert_eq!(span.get("tag"), Some("hhea"));
+    assert_eq!(span.get("length"), Some("20"));
+    // the directory and the padded cmap and head tables come before it
+    let offset = 12 + 6 * 16 + cmap.len().next_multiple_of(4) + head.len().next_multiple_of(4);
+    assert_eq!(span.get("offset"), Some(offset.to_string().as_str()));
+}
+
+#[test]
+fn unexpected_head_versions_are_logged_within_the_table_span() {
+    let recorder = Recorder::default();
+    let mut head = head_table();
+    head[..4].copy_from_slice(&0x0002_0000u32.to_be_bytes());
+
+    let font = build_font(&[
+        (b"cmap", &cmap_table(&[(0x41, 0x43, 1)])),
+        (b"head", &head),
+        (b"hhea", &hhea_table(2)),
+        (b"hmtx", &hmtx_table(&[(500, 10), (600, 20)], &[30, 40])),
+        (b"maxp", &maxp_table(4)),
+        (b"name", &EMPTY_NAME),
+    ]);
+
+    // the font still parses, the version is only logged
+    tracing::subscriber::with_default(recorder.clone(), || Font::from_shared(font).unwrap());
+
+    let recorded = recorder.0.lock().unwrap();
+    let [(fields, Some(span))] = recorded.events.as_slice() else {
+        panic!("expected a single event within a span, got {recorded:?}");
+    };
+    let (_, span) = &recorded.spans[*span];
+
+    assert_eq!(fields.get("version"), Some("0x00020000"));
+    assert_eq!(span.get("tag"), Some("head"));
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{
+    VeroTypeError,
+    buffer::VeroBufReader,
+    font::Font,
+    tables::{ParseOptions, Table, TableEncodingError, Tables, head::Head},
+};
+
+/// The common head table with it's units per em replaced
+fn head_with_units_per_em(units_per_em: u16) -> Vec<u8> {
+    let mut head = common::head_table();
+    head[18..20].copy_from_slice(&units_per_em.to_be_bytes());
+    head
+}
+
+#[test]
+fn the_font_exposes_the_units_per_em() {
+    let font = common::minimal_font(&[]);
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+
+    assert_eq!(font.units_per_em(), 1000);
+    assert_eq!(font.units_per_em(), font.head().units_per_em());
+}
+
+#[test]
+fn the_range_bounds_are_accepted() {
+    assert_eq!(
+        Head::parse(&head_with_units_per_em(16))
+            .unwrap()
+            .units_per_em(),
+        16
+    );
+    assert_eq!(
+        Head::parse(&head_with_units_per_em(16384))
+            .unwrap()
+            .units_per_em(),
+        16384
+    );
+}
+
+#[test]
+fn out_of_range_values_are_rejected() {
+    for units_per_em in [0, 15, 16385, u16::MAX] {
+        assert!(matches!(
+            Head::parse(&head_with_units_per_em(units_per_em)),
+            Err(TableEncodingError::InvalidUnitsPerEm(value)) if value == units_per_em
+        ));
+    }
+}
+
+#[test]
+fn a_zero_units_per_em_fails_the_font() {
+    let head = head_with_units_per_em(0);
+    let font = common::build_font(&[(b"head", &head), (b"name", &common::EMPTY_NAME)]);
+
+    assert!(matches!(
+        Tables::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font.clone()))),
+        Err(VeroTypeError::TableEncodingError(
+            TableEncodingError::InvalidUnitsPerEm(0)
+        ))
+    ));
+
+    let tables = Tables::from_reader_with_options(
+        &mut VeroBufReader::from_buffer(Cursor::new(font)),
+        ParseOptions { strict: false },
+    )
+    .unwrap();
+
+    assert!(tables.head().is_none());
+    assert_eq!(tables.warnings().len(), 1);
+}
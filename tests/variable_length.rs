@@ -0,0 +1,122 @@
+#![cfg(feature = "std")]
+
+use std::io::Cursor;
+
+use vero_type::buffer::{VeroBufReader, VeroBufReaderError};
+
+fn reader(bytes: &[u8]) -> VeroBufReader<Cursor<Vec<u8>>> {
+    VeroBufReader::from_buffer(Cursor::new(bytes.to_vec()))
+}
+
+#[test]
+fn uint_base128_decodes_up_to_32_bits() {
+    assert_eq!(reader(&[0x00]).read_uint_base128().unwrap(), 0);
+    assert_eq!(reader(&[0x7F]).read_uint_base128().unwrap(), 127);
+    assert_eq!(
+        reader(&[0x82, 0x80, 0x00]).read_uint_base128().unwrap(),
+        1 << 15
+    );
+    assert_eq!(
+        reader(&[0x8F, 0xFF, 0xFF, 0xFF, 0x7F])
+            .read_uint_base128()
+            .unwrap(),
+        u32::MAX
+    );
+}
+
+#[test]
+fn uint_base128_rejects_what_the_spec_rejects() {
+    let malformed = |bytes: &[u8]| {
+        matches!(
+            reader(bytes).read_uint_base128(),
+            Err(VeroBufReaderError::MalformedUintBase128(_))
+        )
+    };
+
+    // leading zero group
+    assert!(malformed(&[0x80, 0x01]));
+    // 33 bits
+    assert!(malformed(&[0x90, 0x80, 0x80, 0x80, 0x00]));
+    // still continuing after 5 bytes
+    assert!(malformed(&[0x81, 0x80, 0x80, 0x80, 0x80, 0x00]));
+    // a truncated value is a read error instead
+    assert!(matches!(
+        reader(&[0x81]).read_uint_base128(),
+        Err(VeroBufReaderError::ReadError(_))
+    ));
+}
+
+#[test]
+fn uint_base128_leaves_the_reader_after_the_value() {
+    let mut reader = reader(&[0x81, 0x00, 0x05]);
+
+    assert_eq!(reader.read_uint_base128().unwrap(), 128);
+    assert_eq!(reader.read_u8().unwrap(), 5);
+}
+
+#[test]
+fn uint16_255_covers_every_code() {
+    assert_eq!(reader(&[0]).read_255_uint16().unwrap(), 0);
+    assert_eq!(reader(&[252]).read_255_uint16().unwrap(), 252);
+    assert_eq!(reader(&[255, 0]).read_255_uint16().unwrap(), 253);
+    assert_eq!(reader(&[255, 252]).read_255_uint16().unwrap(), 505);
+    assert_eq!(reader(&[254, 255]).read_255_uint16().unwrap(), 761);
+    assert_eq!(
+        reader(&[253, 0xFF, 0xFF]).read_255_uint16().unwrap(),
+        u16::MAX
+    );
+    assert!(reader(&[253, 0xFF]).read_255_uint16().is_err());
+}
+
+#[test]
+fn utf16be_strings_decode_surrogate_pairs() {
+    // "A", then U+1F600 as a surrogate pair, then "B"
+    let bytes = [0x00, 0x41, 0xD8, 0x3D, 0xDE, 0x00, 0x00, 0x42, 0xFF];
+    let mut reader = reader(&bytes);
+
+    assert_eq!(reader.read_string_utf16be(8).unwrap(), "A\u{1F600}B");
+    assert_eq!(reader.read_u8().unwrap(), 0xFF);
+    assert_eq!(reader.read_string_utf16be(0).unwrap(), "");
+}
+
+#[test]
+fn utf16be_strings_replace_unpaired_surrogates() {
+    // a lone low surrogate, then a high surrogate followed by "A"
+    let bytes = [0xDC, 0x00, 0xD8, 0x00, 0x00, 0x41];
+
+    assert_eq!(
+        reader(&bytes).read_string_utf16be(6).unwrap(),
+        "\u{FFFD}\u{FFFD}A"
+    );
+    // a trailing odd byte is ignored
+    assert_eq!(reader(&bytes[4..]).read_string_utf16be(1).unwrap(), "");
+    assert!(matches!(
+        reader(&bytes).read_string_utf16be(7),
+        Err(VeroBufReaderError::ReadError(_))
+    ));
+}
+
+#[test]
+fn length_prefixed_blobs_read_exactly_their_length() {
+    let mut reader = reader(&[0, 2, 0xAB, 0xCD, 0, 0, 0x12]);
+
+    assert_eq!(reader.read_vec_u16_len().unwrap(), [0xAB, 0xCD]);
+    assert!(reader.read_vec_u16_len().unwrap().is_empty());
+    assert_eq!(reader.read_u8().unwrap(), 0x12);
+}
+
+#[test]
+fn length_prefixed_blobs_past_the_end_are_errors() {
+    let mut bytes = vec![0xFF, 0xFF];
+    bytes.extend_from_slice(&[0; 100]);
+
+    assert!(matches!(
+        reader(&bytes).read_vec_u16_len(),
+        Err(VeroBufReaderError::ReadError(_))
+    ));
+    // even the length can be cut off
+    assert!(matches!(
+        reader(&[0]).read_vec_u16_len(),
+        Err(VeroBufReaderError::ReadError(_))
+    ));
+}
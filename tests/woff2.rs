@@ -0,0 +1,271 @@
+#![cfg(feature = "woff2")]
+
+mod common;
+
+use common::{EMPTY_NAME, cmap_table, head_table, hhea_table, maxp_table};
+use vero_type::{
+    font::Font,
+    tag::Tag,
+    woff2::{self, Woff2Error},
+};
+
+/// The WOFF2 samples under `tests/fonts` with their family name and number of glyphs
+const SAMPLES: [(&str, &str, u16); 2] = [
+    ("OpenSans-Regular.woff2", "Open Sans", 902),
+    ("SourceCodePro-Medium.woff2", "Source Code Pro Medium", 1451),
+];
+
+fn sample(file: &str) -> Vec<u8> {
+    std::fs::read(format!("{}/tests/fonts/{file}", env!("CARGO_MANIFEST_DIR"))).unwrap()
+}
+
+/// A Brotli stream holding `data` in a single uncompressed meta-block
+fn stored_brotli(data: &[u8]) -> Vec<u8> {
+    // WBITS 16, not the last meta-block, 4 nibbles of length, uncompressed
+    let header = ((data.len() as u32 - 1) << 4) | (1 << 20);
+
+    let mut stream = header.to_le_bytes()[..3].to_vec();
+    stream.extend_from_slice(data);
+    // the last meta-block, empty
+    stream.push(0x03);
+    stream
+}
+
+fn uint_base128(mut value: u32) -> Vec<u8> {
+    let mut bytes = vec![(value & 0x7F) as u8];
+    value >>= 7;
+
+    while value != 0 {
+        bytes.insert(0, (value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+
+    bytes
+}
+
+/// Assembles a WOFF2 file out of `(flags, original length, stored bytes, transformed)` entries
+fn woff2_file(entries: &[(u8, u32, &[u8], bool)], compressed: Option<Vec<u8>>) -> Vec<u8> {
+    let mut directory = Vec::new();
+    let mut data = Vec::new();
+
+    for (flags, orig_length, bytes, transformed) in entries {
+        directory.push(*flags);
+        directory.extend(uint_base128(*orig_length));
+        if *transformed {
+            directory.extend(uint_base128(bytes.len() as u32));
+        }
+        data.extend_from_slice(bytes);
+    }
+
+    let compressed = compressed.unwrap_or_else(|| stored_brotli(&data));
+
+    let mut file = Vec::new();
+    file.extend_from_slice(b"wOF2");
+    file.extend_from_slice(&0x0001_0000u32.to_be_bytes());
+    file.extend_from_slice(&0u32.to_be_bytes()); // length
+    file.extend_from_slice(&(entries.len() as u16).to_be_bytes());
+    file.extend_from_slice(&[0; 6]); // reserved, total sfnt size
+    file.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+    file.extend_from_slice(&[0; 24]); // versions, metadata and private data
+    file.extend_from_slice(&directory);
+    file.extend_from_slice(&compressed);
+    file
+}
+
+/// A transformed glyf table with an empty glyph, a triangle and a composite
+/// placing the triangle 200 units to the right
+fn transformed_glyf() -> Vec<u8> {
+    let n_contour: Vec<u8> = [0i16, 1, -1].iter().flat_map(|n| n.to_be_bytes()).collect();
+    let n_points = [3];
+    // (10, 0) and (110, 0) on the curve, then (60, 100) off the curve
+    let flag = [11, 11, 0x80 | 86];
+    let glyph = [10, 100, 49, 99, 0];
+    let composite = [0x00, 0x03, 0x00, 0x01, 0x00, 0xC8, 0x00, 0x00];
+    let mut bbox = vec![0x20, 0, 0, 0];
+    bbox.extend([210i16, 0, 310, 100].iter().flat_map(|v| v.to_be_bytes()));
+
+    let streams: [&[u8]; 7] = [&n_contour, &n_points, &flag, &glyph, &composite, &bbox, &[]];
+
+    let mut glyf = Vec::new();
+    glyf.extend_from_slice(&0u16.to_be_bytes()); // reserved
+    glyf.extend_from_slice(&0u16.to_be_bytes()); // option flags
+    glyf.extend_from_slice(&3u16.to_be_bytes()); // number of glyphs
+    glyf.extend_from_slice(&1u16.to_be_bytes()); // long loca offsets
+    for stream in streams {
+        glyf.extend_from_slice(&(stream.len() as u32).to_be_bytes());
+    }
+    for stream in streams {
+        glyf.extend_from_slice(stream);
+    }
+    glyf
+}
+
+fn synthetic_woff2(hmtx_flags: u8) -> Vec<u8> {
+    let cmap = cmap_table(&[(0x41, 0x41, 1)]);
+    let head = head_table();
+    let hhea = hhea_table(2);
+    let maxp = maxp_table(3);
+    let glyf = transformed_glyf();
+
+    let mut hmtx = vec![hmtx_flags];
+    hmtx.extend_from_slice(&500u16.to_be_bytes());
+    hmtx.extend_from_slice(&600u16.to_be_bytes());
+
+    woff2_file(
+        &[
+            (0, cmap.len() as u32, &cmap, false),
+            (1, head.len() as u32, &head, false),
+            (2, hhea.len() as u32, &hhea, false),
+            (0x40 | 3, 12, &hmtx, true),
+            (4, maxp.len() as u32, &maxp, false),
+            (5, EMPTY_NAME.len() as u32, &EMPTY_NAME, false),
+            (10, 0, &glyf, true),
+            (11, 16, &[], true),
+        ],
+        None,
+    )
+}
+
+#[test]
+fn sample_woff2_fonts_decode() {
+    for (file, family_name, num_glyphs) in SAMPLES {
+        let font = Font::from_shared(woff2::decode(&sample(file)).unwrap()).unwrap();
+
+        assert_eq!(font.family_name().as_deref(), Some(family_name), "{file}");
+        assert_eq!(font.maxp().num_glyphs(), num_glyphs, "{file}");
+        assert_eq!(font.tables_present().len(), 17, "{file}");
+        assert!(font.tables().warnings().is_empty(), "{file}");
+
+        let head = font.head();
+        for glyph_id in 0..num_glyphs {
+            let Some(glyph) = font.glyph(glyph_id).unwrap() else {
+                continue;
+            };
+
+            // the rebuilt bounding boxes stay within the font's
+            assert!(glyph.x_min() >= head.x_min() && glyph.x_max() <= head.x_max());
+            assert!(glyph.y_min() >= head.y_min() && glyph.y_max() <= head.y_max());
+
+            if glyph.number_of_contours() > 0 {
+                let points = glyph.contours().iter().flatten();
+                assert_eq!(points.clone().map(|p| p.x).min(), Some(glyph.x_min()));
+                assert_eq!(points.map(|p| p.y).max(), Some(glyph.y_max()));
+            }
+        }
+    }
+}
+
+#[test]
+fn sample_woff2_outlines_the_letter_a() {
+    let data = woff2::decode(&sample("OpenSans-Regular.woff2")).unwrap();
+    let font = Font::from_shared(data).unwrap();
+
+    let glyph = font
+        .glyph(font.cmap().glyph_index('A').unwrap())
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(glyph.number_of_contours(), 2);
+    assert_eq!(
+        (glyph.x_min(), glyph.y_min(), glyph.x_max(), glyph.y_max()),
+        (0, 0, 1296, 1468)
+    );
+}
+
+#[test]
+fn transformed_glyf_and_hmtx_are_rebuilt() {
+    let font = Font::from_shared(woff2::decode(&synthetic_woff2(0x03)).unwrap()).unwrap();
+
+    assert_eq!(font.glyph(0).unwrap().unwrap().number_of_contours(), 0);
+
+    let triangle = font.glyph(1).unwrap().unwrap();
+    let points: Vec<_> = triangle.contours()[0]
+        .iter()
+        .map(|point| (point.x, point.y, point.on_curve))
+        .collect();
+    assert_eq!(points, [(10, 0, true), (110, 0, true), (60, 100, false)]);
+    assert_eq!(
+        (
+            triangle.x_min(),
+            triangle.y_min(),
+            triangle.x_max(),
+            triangle.y_max()
+        ),
+        (10, 0, 110, 100)
+    );
+
+    let composite = font.glyph(2).unwrap().unwrap();
+    assert!(composite.is_composite());
+    assert_eq!(composite.x_min(), 210);
+    assert_eq!(font.component_glyphs(2).unwrap(), [1]);
+
+    // the left side bearings are the x_min of each glyph
+    let hmtx = font.hmtx();
+    assert_eq!(hmtx.advance_width(1), Some(600));
+    assert_eq!(hmtx.left_side_bearing(0), Some(0));
+    assert_eq!(hmtx.left_side_bearing(1), Some(10));
+    assert_eq!(hmtx.left_side_bearing(2), Some(210));
+}
+
+#[test]
+fn hmtx_with_reserved_flags_is_rejected() {
+    assert!(matches!(
+        woff2::decode(&synthetic_woff2(0x07)),
+        Err(Woff2Error::MalformedTransform(tag, _)) if tag == "hmtx"
+    ));
+}
+
+#[test]
+fn signature_is_checked() {
+    let mut data = sample("OpenSans-Regular.woff2");
+    data[..4].copy_from_slice(b"wOFF");
+
+    assert!(matches!(
+        woff2::decode(&data),
+        Err(Woff2Error::InvalidSignature(0x774F4646))
+    ));
+}
+
+#[test]
+fn collections_are_unsupported() {
+    let mut data = woff2_file(&[(5, 6, &EMPTY_NAME, false)], None);
+    data[4..8].copy_from_slice(b"ttcf");
+
+    assert!(matches!(
+        woff2::decode(&data),
+        Err(Woff2Error::UnsupportedCollection)
+    ));
+}
+
+#[test]
+fn transforms_of_other_tables_are_unsupported() {
+    let data = woff2_file(&[(0x40 | 5, 6, &EMPTY_NAME, true)], None);
+
+    assert!(matches!(
+        woff2::decode(&data),
+        Err(Woff2Error::UnsupportedTransform(tag, 1)) if tag == Tag::new(*b"name")
+    ));
+}
+
+#[test]
+fn decompressed_size_must_match_the_directory() {
+    // an empty Brotli stream where the directory expects the 6 bytes of the name table
+    let data = woff2_file(&[(5, 6, &EMPTY_NAME, false)], Some(vec![0x06]));
+
+    assert!(matches!(
+        woff2::decode(&data),
+        Err(Woff2Error::DecompressedSizeMismatch {
+            expected: 6,
+            found: 0
+        })
+    ));
+}
+
+#[test]
+fn tables_are_copied_as_is() {
+    let data = woff2_file(&[(5, 6, &EMPTY_NAME, false)], None);
+    let font = woff2::decode(&data).unwrap();
+
+    assert_eq!(&font[12..16], b"name");
+    assert_eq!(&font[28..34], EMPTY_NAME);
+}
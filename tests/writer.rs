@@ -0,0 +1,222 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use std::io::Cursor;
+
+use vero_type::{
+    buffer::VeroBufReader,
+    font::Font,
+    tables::{RequiredTables, TableEncodingError, TableMetadata, Tables},
+    tag::Tag,
+    writer::FontWriter,
+};
+
+/// A writer holding the tables of a minimal font
+fn writer() -> FontWriter {
+    let mut writer = FontWriter::new(0x0001_0000);
+    writer
+        .table(*b"name", common::EMPTY_NAME.to_vec())
+        .table(*b"maxp", common::maxp_table(4))
+        .table(
+            *b"hmtx",
+            common::hmtx_table(&[(500, 10), (600, 20)], &[30, 40]),
+        )
+        .table(*b"hhea", common::hhea_table(2))
+        .table(*b"head", common::head_table())
+        .table(*b"cmap", common::cmap_table(&[(0x41, 0x43, 1)]));
+
+    writer
+}
+
+fn written_font() -> Vec<u8> {
+    writer().to_bytes().unwrap()
+}
+
+#[test]
+fn written_fonts_parse_back() {
+    let font = written_font();
+    let font = Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(font))).unwrap();
+    let offset = font.tables().offset();
+
+    assert_eq!(
+        font.tables_present(),
+        ["cmap", "head", "hhea", "hmtx", "maxp", "name"]
+    );
+    assert_eq!(offset.num_tables(), 6);
+    assert_eq!(offset.search_range(), 64);
+    assert_eq!(offset.entry_selector(), 2);
+    assert_eq!(offset.range_shift(), 32);
+    assert_eq!(font.maxp().num_glyphs(), 4);
+    assert_eq!(font.hmtx().advance_width(3), Some(600));
+}
+
+#[test]
+fn checksums_are_computed() {
+    let bytes = written_font();
+    let font =
+        Font::from_reader(&mut VeroBufReader::from_buffer(Cursor::new(bytes.clone()))).unwrap();
+
+    // the whole file sums to the magic number thanks to checksum_adjustment
+    assert_eq!(TableMetadata::recompute_checksum(&bytes), 0xB1B0AFBA);
+    assert_ne!(font.head().checksum_adjustment(), 0);
+
+    let cmap = font.tables().headers().get(RequiredTables::Cmap).unwrap();
+    let start = cmap.offset() as usize;
+    let end = start + cmap.length() as usize;
+    assert_eq!(
+        cmap.checksum(),
+        TableMetadata::recompute_checksum(&bytes[start..end])
+    );
+
+    // the head checksum ignores checksum_adjustment
+    let head = font.tables().headers().get(RequiredTables::Head).unwrap();
+    assert_eq!(
+        head.checksum(),
+        TableMetadata::recompute_checksum(&common::head_table())
+    );
+}
+
+#[test]
+fn removed_tables_are_left_out_of_the_rewritten_font() {
+    let hinted = writer()
+        .table(*b"fpgm", vec![0xB0, 0x01, 0x2C])
+        .table(*b"prep", vec![0xB0, 0x00])
+        .to_bytes()
+        .unwrap();
+
+    let mut tables =
+        Tables::directory_from_reader(&mut VeroBufReader::from_buffer(Cursor::new(&hinted)))
+            .unwrap();
+    let headers = tables.headers_mut();
+    assert!(headers.remove(*b"fpgm").is_some());
+    assert!(headers.remove(*b"prep").is_some());
+    assert!(headers.remove(*b"gasp").is_none());
+    assert!(headers.get_by_tag(*b"fpgm").is_none());
+
+    let stripped = FontWriter::from_tables(&tables, &hinted)
+        .unwrap()
+        .to_bytes()
+        .unwrap();
+    assert_eq!(stripped, written_font());
+
+    let font = Font::from_shared(stripped).unwrap();
+    assert_eq!(font.tables().offset().num_tables(), 6);
+    assert_eq!(font.maxp().num_glyphs(), 4);
+}
+
+#[test]
+fn required_tables_can_be_removed_from_the_directory() {
+    let font = written_font();
+    let mut tables =
+        Tables::directory_from_reader(&mut VeroBufReader::from_buffer(Cursor::new(&font))).unwrap();
+
+    let removed = tables.headers_mut().remove(*b"cmap").unwrap();
+    assert_eq!(removed.offset(), 12 + 6 * 16);
+    assert!(tables.headers().get(RequiredTables::Cmap).is_none());
+    assert!(!tables.headers().tags().contains(&Tag::new(*b"cmap")));
+
+    // the writer drops the table too, so the font no longer parses
+    let mut writer = FontWriter::from_tables(&tables, &font).unwrap();
+    assert!(writer.tags().all(|&tag| tag != "cmap"));
+    assert!(Font::from_shared(writer.to_bytes().unwrap()).is_err());
+    assert!(writer.remove(*b"name").is_some());
+    assert!(writer.remove(*b"name").is_none());
+}
+
+#[test]
+fn tables_past_the_end_of_the_data_are_an_error() {
+    let font = written_font();
+    let tables =
+        Tables::directory_from_reader(&mut VeroBufReader::from_buffer(Cursor::new(&font))).unwrap();
+
+    assert!(matches!(
+        FontWriter::from_tables(&tables, &font[..font.len() - 8]),
+        Err(TableEncodingError::InvalidBufferLength(_, _))
+    ));
+}
+
+#[test]
+fn written_fonts_pass_the_checksum_report() {
+    let font = written_font();
+    let mut reader = VeroBufReader::from_buffer(Cursor::new(&font));
+    let tables = Tables::from_reader(&mut reader).unwrap();
+
+    // the head table's checksum adjustment was filled in after it's checksum
+    assert_ne!(tables.head().unwrap().checksum_adjustment(), 0);
+
+    let report = tables.checksum_report(&mut reader);
+    let tags: Vec<_> = report.iter().map(|(tag, _)| tag.to_string()).collect();
+    assert_eq!(tags, ["cmap", "head", "hhea", "hmtx", "maxp", "name"]);
+    assert!(report.iter().all(|&(_, valid)| valid));
+
+    assert_eq!(tables.checksum_report_shared(&font[..].into()), report);
+}
+
+#[test]
+fn corrupted_and_unreadable_tables_fail_the_checksum_report() {
+    let mut font = written_font();
+    let tables = Tables::from_shared(&font[..].into()).unwrap();
+
+    // flip a byte of the hhea table
+    let hhea = tables.headers().get(RequiredTables::Hhea).unwrap().offset() as usize;
+    font[hhea + 4] ^= 0xFF;
+    // and cut the last table, name, off
+    font.truncate(tables.headers().get(RequiredTables::Name).unwrap().offset() as usize);
+
+    let failed: Vec<_> = tables
+        .checksum_report_shared(&font[..].into())
+        .into_iter()
+        .filter(|&(_, valid)| !valid)
+        .map(|(tag, _)| tag.to_string())
+        .collect();
+
+    assert_eq!(failed, ["hhea", "name"]);
+}
+
+#[test]
+fn checksum_adjustments_are_streamed_over_the_whole_file() {
+    // a table spanning several read chunks, of an odd length so the file gets padded
+    let large: Vec<u8> = (0..200_001u32).map(|byte| byte as u8).collect();
+    let mut writer = writer();
+    writer.table(*b"zzzz", large);
+
+    for font in [written_font(), writer.to_bytes().unwrap()] {
+        let mut reader = VeroBufReader::from_buffer(Cursor::new(&font));
+        let tables = Tables::from_reader(&mut reader).unwrap();
+        let stored = tables.head().unwrap().checksum_adjustment();
+
+        assert_eq!(tables.checksum_adjustment(&mut reader).unwrap(), stored);
+    }
+}
+
+#[test]
+fn checksum_adjustments_ignore_the_stored_field_only() {
+    let mut font = written_font();
+    let tables = Tables::from_shared(&font[..].into()).unwrap();
+    let stored = tables.head().unwrap().checksum_adjustment();
+    let head = tables.headers().get(RequiredTables::Head).unwrap().offset() as usize;
+
+    // overwriting the field itself doesn't change the computed value
+    font[head + 8..head + 12].fill(0xAA);
+    let computed = tables.checksum_adjustment(&mut VeroBufReader::from_buffer(Cursor::new(&font)));
+    assert_eq!(computed.unwrap(), stored);
+
+    // any other byte does
+    font[head + 12] ^= 0x01;
+    let computed = tables.checksum_adjustment(&mut VeroBufReader::from_buffer(Cursor::new(&font)));
+    assert_ne!(computed.unwrap(), stored);
+}
+
+#[test]
+fn directories_past_65535_tables_are_an_error() {
+    let mut writer = FontWriter::new(0x0001_0000);
+    for index in 0..=u32::from(u16::MAX) {
+        writer.table(index.to_be_bytes(), Vec::new());
+    }
+
+    assert!(matches!(
+        writer.to_bytes(),
+        Err(TableEncodingError::TooManyTables(65536))
+    ));
+}